@@ -5,16 +5,19 @@ pub(crate) mod stream;
 pub(crate) mod core {
     use garde::Validate;
     use ntex::web::{
-        types::{Json, Path, State},
+        types::{Json, Path, Query, State},
         HttpRequest, HttpResponse,
     };
 
-    use entities::{Role, UserExtension};
+    use entities::{ChampionshipRole, Role, UserExtension};
     use error::{AppResult, ChampionshipError, CommonError};
-    use intelli_core::services::ChampionshipServiceOperations;
+    use intelli_core::services::{ChampionshipServiceOperations, DriverServiceOperations};
     use structs::{
-        ChampionshipAndUserId, ChampionshipCreationData, ChampionshipData, ChampionshipId,
-        ChampionshipUpdateData, ChampionshipUserAddForm,
+        ChampionshipAndUserId, ChampionshipCreationData, ChampionshipData, ChampionshipDivisionId,
+        ChampionshipId, ChampionshipRulesForm, ChampionshipSeasonId, ChampionshipTeamId,
+        ChampionshipTrack, ChampionshipUpdateData, ChampionshipUserAddForm, DamageThresholdsForm,
+        DivisionForm, DriverClaimReviewForm, LeagueTeamForm, RaceScheduleForm, SeasonRolloverForm,
+        StandingsQuery, TeamTransferForm, TelemetryGrantsForm,
     };
 
     use crate::states::AppState;
@@ -114,6 +117,83 @@ pub(crate) mod core {
         Ok(HttpResponse::Ok().finish())
     }
 
+    #[inline]
+    pub async fn telemetry_grants(
+        state: State<AppState>,
+        path: Path<ChampionshipAndUserId>,
+    ) -> AppResult<HttpResponse> {
+        path.validate().map_err(|_| CommonError::ValidationFailed)?;
+
+        let team_ids = state
+            .championship_repo
+            .telemetry_grants(path.championship_id, path.user_id)
+            .await?;
+
+        Ok(HttpResponse::Ok().json(&team_ids))
+    }
+
+    #[inline]
+    pub async fn update_telemetry_grants(
+        req: HttpRequest,
+        state: State<AppState>,
+        Json(form): Json<TelemetryGrantsForm>,
+        path: Path<ChampionshipAndUserId>,
+    ) -> AppResult<HttpResponse> {
+        if form.validate().is_err() || path.validate().is_err() {
+            Err(CommonError::ValidationFailed)?
+        }
+
+        let user_id = req.user_id()?;
+        state
+            .championship_svc
+            .update_telemetry_grants(path.championship_id, user_id, path.user_id, &form)
+            .await?;
+
+        Ok(HttpResponse::Ok().finish())
+    }
+
+    #[inline]
+    pub async fn driver_claims(
+        req: HttpRequest,
+        state: State<AppState>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        path.validate().map_err(|_| CommonError::ValidationFailed)?;
+
+        let user_id = req.user_id()?;
+        let relation = state
+            .championship_repo
+            .user_relation(path.0, user_id)
+            .await?;
+
+        if !relation.is_some_and(|relation| relation.role == ChampionshipRole::Admin) {
+            Err(ChampionshipError::NotAdmin)?
+        }
+
+        let claims = state.driver_repo.pending_claims(path.0).await?;
+        Ok(HttpResponse::Ok().json(&claims))
+    }
+
+    #[inline]
+    pub async fn review_driver_claim(
+        req: HttpRequest,
+        state: State<AppState>,
+        Json(form): Json<DriverClaimReviewForm>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        if form.validate().is_err() || path.validate().is_err() {
+            Err(CommonError::ValidationFailed)?
+        }
+
+        let user_id = req.user_id()?;
+        state
+            .driver_svc
+            .review_claim(path.0, user_id, &form)
+            .await?;
+
+        Ok(HttpResponse::Ok().finish())
+    }
+
     #[inline]
     pub async fn get(
         state: State<AppState>,
@@ -131,4 +211,399 @@ pub(crate) mod core {
             races,
         }))
     }
+
+    #[inline]
+    pub async fn rules(
+        state: State<AppState>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        path.validate().map_err(|_| CommonError::ValidationFailed)?;
+
+        if state.championship_repo.find(path.0).await?.is_none() {
+            Err(ChampionshipError::NotFound)?
+        }
+
+        let rules = state.championship_repo.rules(path.0).await?;
+        Ok(HttpResponse::Ok().json(&rules))
+    }
+
+    #[inline]
+    pub async fn update_rules(
+        req: HttpRequest,
+        state: State<AppState>,
+        Json(form): Json<ChampionshipRulesForm>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        if form.validate().is_err() || path.validate().is_err() {
+            Err(CommonError::ValidationFailed)?
+        }
+
+        let user_id = req.user_id()?;
+        state
+            .championship_svc
+            .update_rules(path.0, user_id, &form)
+            .await?;
+
+        Ok(HttpResponse::Ok().finish())
+    }
+
+    #[inline]
+    pub async fn damage_thresholds(
+        state: State<AppState>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        path.validate().map_err(|_| CommonError::ValidationFailed)?;
+
+        if state.championship_repo.find(path.0).await?.is_none() {
+            Err(ChampionshipError::NotFound)?
+        }
+
+        let thresholds = state.championship_repo.damage_thresholds(path.0).await?;
+        Ok(HttpResponse::Ok().json(&thresholds))
+    }
+
+    #[inline]
+    pub async fn update_damage_thresholds(
+        req: HttpRequest,
+        state: State<AppState>,
+        Json(form): Json<DamageThresholdsForm>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        if form.validate().is_err() || path.validate().is_err() {
+            Err(CommonError::ValidationFailed)?
+        }
+
+        let user_id = req.user_id()?;
+        state
+            .championship_svc
+            .update_damage_thresholds(path.0, user_id, &form)
+            .await?;
+
+        Ok(HttpResponse::Ok().finish())
+    }
+
+    #[inline]
+    pub async fn teams(
+        state: State<AppState>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        path.validate().map_err(|_| CommonError::ValidationFailed)?;
+
+        if state.championship_repo.find(path.0).await?.is_none() {
+            Err(ChampionshipError::NotFound)?
+        }
+
+        let teams = state.championship_repo.teams(path.0).await?;
+        Ok(HttpResponse::Ok().json(&teams))
+    }
+
+    #[inline]
+    pub async fn schedule_race(
+        req: HttpRequest,
+        state: State<AppState>,
+        Json(form): Json<RaceScheduleForm>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        if form.validate().is_err() || path.validate().is_err() {
+            Err(CommonError::ValidationFailed)?
+        }
+
+        let user_id = req.user_id()?;
+        let race_id = state
+            .championship_svc
+            .schedule_race(path.0, user_id, &form)
+            .await?;
+
+        Ok(HttpResponse::Created().json(&race_id))
+    }
+
+    #[inline]
+    pub async fn create_team(
+        req: HttpRequest,
+        state: State<AppState>,
+        Json(form): Json<LeagueTeamForm>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        if form.validate().is_err() || path.validate().is_err() {
+            Err(CommonError::ValidationFailed)?
+        }
+
+        let user_id = req.user_id()?;
+        let team_id = state
+            .championship_svc
+            .create_team(path.0, user_id, &form)
+            .await?;
+
+        Ok(HttpResponse::Created().json(&team_id))
+    }
+
+    #[inline]
+    pub async fn update_team(
+        req: HttpRequest,
+        state: State<AppState>,
+        Json(form): Json<LeagueTeamForm>,
+        path: Path<ChampionshipTeamId>,
+    ) -> AppResult<HttpResponse> {
+        if form.validate().is_err() || path.validate().is_err() {
+            Err(CommonError::ValidationFailed)?
+        }
+
+        let user_id = req.user_id()?;
+        state
+            .championship_svc
+            .update_team(path.id, user_id, path.team_id, &form)
+            .await?;
+
+        Ok(HttpResponse::Ok().finish())
+    }
+
+    #[inline]
+    pub async fn delete_team(
+        req: HttpRequest,
+        state: State<AppState>,
+        path: Path<ChampionshipTeamId>,
+    ) -> AppResult<HttpResponse> {
+        path.validate().map_err(|_| CommonError::ValidationFailed)?;
+
+        let user_id = req.user_id()?;
+        state
+            .championship_svc
+            .delete_team(path.id, user_id, path.team_id)
+            .await?;
+
+        Ok(HttpResponse::Ok().finish())
+    }
+
+    #[inline]
+    pub async fn transfer_driver(
+        req: HttpRequest,
+        state: State<AppState>,
+        Json(form): Json<TeamTransferForm>,
+        path: Path<ChampionshipTeamId>,
+    ) -> AppResult<HttpResponse> {
+        if form.validate().is_err() || path.validate().is_err() {
+            Err(CommonError::ValidationFailed)?
+        }
+
+        let user_id = req.user_id()?;
+        state
+            .championship_svc
+            .transfer_driver(path.id, user_id, path.team_id, &form)
+            .await?;
+
+        Ok(HttpResponse::Ok().finish())
+    }
+
+    #[inline]
+    pub async fn lineups(
+        state: State<AppState>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        path.validate().map_err(|_| CommonError::ValidationFailed)?;
+
+        if state.championship_repo.find(path.0).await?.is_none() {
+            Err(ChampionshipError::NotFound)?
+        }
+
+        let lineups = state.championship_repo.lineups(path.0).await?;
+        Ok(HttpResponse::Ok().json(&lineups))
+    }
+
+    #[inline]
+    pub async fn engine_usage(
+        state: State<AppState>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        path.validate().map_err(|_| CommonError::ValidationFailed)?;
+
+        let usage = state.championship_svc.engine_usage(path.0).await?;
+        Ok(HttpResponse::Ok().json(&usage))
+    }
+
+    #[inline]
+    pub async fn seasons(
+        state: State<AppState>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        path.validate().map_err(|_| CommonError::ValidationFailed)?;
+
+        if state.championship_repo.find(path.0).await?.is_none() {
+            Err(ChampionshipError::NotFound)?
+        }
+
+        let seasons = state.championship_repo.seasons(path.0).await?;
+        Ok(HttpResponse::Ok().json(&seasons))
+    }
+
+    #[inline]
+    pub async fn rollover_season(
+        req: HttpRequest,
+        state: State<AppState>,
+        Json(form): Json<SeasonRolloverForm>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        if form.validate().is_err() || path.validate().is_err() {
+            Err(CommonError::ValidationFailed)?
+        }
+
+        let user_id = req.user_id()?;
+        let season = state
+            .championship_svc
+            .rollover_season(path.0, user_id, &form)
+            .await?;
+
+        Ok(HttpResponse::Created().json(&season))
+    }
+
+    #[inline]
+    pub async fn season_drivers(
+        state: State<AppState>,
+        path: Path<ChampionshipSeasonId>,
+    ) -> AppResult<HttpResponse> {
+        path.validate().map_err(|_| CommonError::ValidationFailed)?;
+
+        let Some(current_season) = state.championship_repo.current_season(path.id).await? else {
+            Err(ChampionshipError::NotFound)?
+        };
+
+        // The roster of the current season is only archived on rollover
+        let drivers = match path.season {
+            season if season == current_season => state.championship_repo.drivers(path.id).await?,
+            season if season < current_season => {
+                state
+                    .championship_repo
+                    .season_drivers(path.id, season)
+                    .await?
+            }
+            _ => Err(ChampionshipError::SeasonNotFound)?,
+        };
+
+        Ok(HttpResponse::Ok().json(&drivers))
+    }
+
+    #[inline]
+    pub async fn divisions(
+        state: State<AppState>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        path.validate().map_err(|_| CommonError::ValidationFailed)?;
+
+        if state.championship_repo.find(path.0).await?.is_none() {
+            Err(ChampionshipError::NotFound)?
+        }
+
+        let divisions = state.championship_repo.divisions(path.0).await?;
+        Ok(HttpResponse::Ok().json(&divisions))
+    }
+
+    #[inline]
+    pub async fn create_division(
+        req: HttpRequest,
+        state: State<AppState>,
+        Json(form): Json<DivisionForm>,
+        path: Path<ChampionshipId>,
+    ) -> AppResult<HttpResponse> {
+        if form.validate().is_err() || path.validate().is_err() {
+            Err(CommonError::ValidationFailed)?
+        }
+
+        let user_id = req.user_id()?;
+        let division_id = state
+            .championship_svc
+            .create_division(path.0, user_id, &form)
+            .await?;
+
+        Ok(HttpResponse::Created().json(&division_id))
+    }
+
+    #[inline]
+    pub async fn assign_division(
+        req: HttpRequest,
+        state: State<AppState>,
+        Json(form): Json<TeamTransferForm>,
+        path: Path<ChampionshipDivisionId>,
+    ) -> AppResult<HttpResponse> {
+        if form.validate().is_err() || path.validate().is_err() {
+            Err(CommonError::ValidationFailed)?
+        }
+
+        let user_id = req.user_id()?;
+        state
+            .championship_svc
+            .assign_division(path.id, user_id, path.division_id, &form)
+            .await?;
+
+        Ok(HttpResponse::Ok().finish())
+    }
+
+    #[inline]
+    pub async fn standings(
+        state: State<AppState>,
+        path: Path<ChampionshipId>,
+        query: Query<StandingsQuery>,
+    ) -> AppResult<HttpResponse> {
+        if path.validate().is_err() || query.validate().is_err() {
+            Err(CommonError::ValidationFailed)?
+        }
+
+        let season = season_or_current(&state, path.0, query.season).await?;
+        let standings = state
+            .result_repo
+            .standings(path.0, season, query.division_id)
+            .await?;
+
+        Ok(HttpResponse::Ok().json(&standings))
+    }
+
+    #[inline]
+    pub async fn team_standings(
+        state: State<AppState>,
+        path: Path<ChampionshipId>,
+        query: Query<StandingsQuery>,
+    ) -> AppResult<HttpResponse> {
+        if path.validate().is_err() || query.validate().is_err() {
+            Err(CommonError::ValidationFailed)?
+        }
+
+        let season = season_or_current(&state, path.0, query.season).await?;
+        let standings = state
+            .result_repo
+            .team_standings(path.0, season, query.division_id)
+            .await?;
+
+        Ok(HttpResponse::Ok().json(&standings))
+    }
+
+    /// Resolves the season a standings query refers to, defaulting to the current one.
+    #[inline]
+    async fn season_or_current(state: &AppState, id: i32, season: Option<i16>) -> AppResult<i16> {
+        let Some(current_season) = state.championship_repo.current_season(id).await? else {
+            Err(ChampionshipError::NotFound)?
+        };
+
+        match season {
+            None => Ok(current_season),
+            Some(season) if season <= current_season => Ok(season),
+            Some(_) => Err(ChampionshipError::SeasonNotFound)?,
+        }
+    }
+
+    #[inline]
+    pub async fn hotlaps(
+        state: State<AppState>,
+        path: Path<ChampionshipTrack>,
+    ) -> AppResult<HttpResponse> {
+        path.validate().map_err(|_| CommonError::ValidationFailed)?;
+
+        if state.championship_repo.find(path.id).await?.is_none() {
+            Err(ChampionshipError::NotFound)?
+        }
+
+        let leaderboard = state
+            .championship_repo
+            .hotlap_leaderboard(path.id, path.track_id)
+            .await?;
+
+        Ok(HttpResponse::Ok().json(&leaderboard))
+    }
 }
@@ -11,17 +11,21 @@ use ntex::{
         HttpRequest, HttpResponse,
     },
 };
-use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
+use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt, StreamMap};
 
 use entities::{ChampionshipRole, UserExtension};
-use error::{AppResult, ChampionshipError, CommonError, F1ServiceError};
+use error::{AppResult, ChampionshipError, CommonError, DriverError, F1ServiceError};
 use structs::ChampionshipId;
 
 use crate::states::AppState;
 
 enum StreamType {
     Normal,
-    Engineer(u8),
+    Engineer(Vec<u8>),
+    Driver(Box<str>),
+    Steward,
+    Positions,
+    Director,
 }
 
 struct CleanupStream<S> {
@@ -47,10 +51,34 @@ impl<S> Drop for CleanupStream<S> {
                 self.state.f1_svc.unsubscribe(&self.championship_id);
             }
 
-            StreamType::Engineer(team_id) => {
+            StreamType::Engineer(team_ids) => {
+                for team_id in team_ids {
+                    self.state
+                        .f1_svc
+                        .unsubscribe_team(&self.championship_id, *team_id);
+                }
+            }
+
+            StreamType::Driver(steam_name) => {
                 self.state
                     .f1_svc
-                    .unsubscribe_team(&self.championship_id, *team_id);
+                    .unsubscribe_driver(&self.championship_id, steam_name);
+            }
+
+            StreamType::Steward => {
+                self.state.f1_svc.unsubscribe_steward(&self.championship_id);
+            }
+
+            StreamType::Positions => {
+                self.state
+                    .f1_svc
+                    .unsubscribe_positions(&self.championship_id);
+            }
+
+            StreamType::Director => {
+                self.state
+                    .f1_svc
+                    .unsubscribe_director(&self.championship_id);
             }
         }
     }
@@ -102,36 +130,221 @@ pub async fn stream_telemetry_session(
 
     let user_id = req.user_id()?;
 
+    let (relation, grants) = tokio::try_join!(
+        state.championship_repo.user_relation(path.0, user_id),
+        state.championship_repo.telemetry_grants(path.0, user_id)
+    )?;
+
+    let Some(relation) = relation else {
+        Err(ChampionshipError::InvalidTeamId)?
+    };
+
+    // Engineers follow their own team, grants add any other team
+    let mut team_ids: Vec<u8> = grants.into_iter().map(|team_id| team_id as u8).collect();
+
+    if let (ChampionshipRole::Engineer, Some(team_id)) = (relation.role, relation.team_id) {
+        team_ids.push(team_id as u8);
+    }
+
+    team_ids.sort_unstable();
+    team_ids.dedup();
+
+    if team_ids.is_empty() {
+        Err(ChampionshipError::NotAnEngineer)?
+    }
+
+    let mut streams = StreamMap::with_capacity(team_ids.len());
+
+    for team_id in team_ids {
+        if let Some(rx) = state.f1_svc.subscribe_team(&path.0, team_id) {
+            streams.insert(team_id, BroadcastStream::new(rx));
+        }
+    }
+
+    if streams.is_empty() {
+        Err(F1ServiceError::NotActive)?
+    }
+
+    let stream = CleanupStream {
+        stream_type: StreamType::Engineer(streams.keys().copied().collect()),
+        inner: streams.map(|(_, update)| update),
+        state: state.clone(),
+        championship_id: path.0,
+    };
+
+    let mut response = HttpResponse::Ok();
+    response.content_type(HeaderValue::from_static("application/octet-stream"));
+
+    Ok(response.streaming(stream))
+}
+
+pub async fn stream_personal_telemetry(
+    req: HttpRequest,
+    state: State<AppState>,
+    path: Path<ChampionshipId>,
+) -> AppResult<HttpResponse> {
+    if path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    let user_id = req.user_id()?;
+
+    let Some(driver) = state.driver_repo.find_by_user(user_id).await? else {
+        Err(DriverError::NotLinked)?
+    };
+
+    let drivers = state.championship_repo.drivers(path.0).await?;
+
+    if !drivers
+        .iter()
+        .any(|championship_driver| championship_driver.steam_name == driver.steam_name)
+    {
+        Err(DriverError::NotInChampionship)?
+    }
+
+    let rx = state.f1_svc.subscribe_driver(&path.0, &driver.steam_name)?;
+
+    let stream = CleanupStream {
+        inner: BroadcastStream::new(rx),
+        state: state.clone(),
+        championship_id: path.0,
+        stream_type: StreamType::Driver(driver.steam_name.clone()),
+    };
+
+    let mut response = HttpResponse::Ok();
+    response.content_type(HeaderValue::from_static("application/octet-stream"));
+
+    Ok(response.streaming(stream))
+}
+
+pub async fn stream_stewarding(
+    req: HttpRequest,
+    state: State<AppState>,
+    path: Path<ChampionshipId>,
+) -> AppResult<HttpResponse> {
+    if path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    let user_id = req.user_id()?;
+
     let relation = state
         .championship_repo
         .user_relation(path.0, user_id)
         .await?;
 
-    match relation {
-        Some(relation) => {
-            if relation.role != ChampionshipRole::Engineer || relation.team_id.is_none() {
-                Err(ChampionshipError::NotAnEngineer)?
-            }
+    if !relation.is_some_and(|relation| relation.role == ChampionshipRole::Admin) {
+        Err(ChampionshipError::NotAdmin)?
+    }
 
-            let team_id = relation.team_id.unwrap() as u8;
+    let Some((cached_data, rx)) = state.f1_svc.steward_cache_and_subscribe(&path.0) else {
+        Err(F1ServiceError::NotActive)?
+    };
 
-            let Some(rx) = state.f1_svc.subscribe_team(&path.0, team_id) else {
-                Err(F1ServiceError::NotActive)?
-            };
+    let stream = CleanupStream {
+        inner: BroadcastStream::new(rx),
+        state: state.clone(),
+        championship_id: path.0,
+        stream_type: StreamType::Steward,
+    };
+
+    let mut response = HttpResponse::Ok();
+    response.content_type(HeaderValue::from_static("application/octet-stream"));
+
+    match cached_data {
+        None => Ok(response.streaming(stream)),
+
+        Some(data) => {
+            let cache_steam = tokio_stream::once(Ok(data));
+            let combined_stream = cache_steam.chain(stream);
+
+            Ok(response.streaming(combined_stream))
+        }
+    }
+}
+
+pub async fn stream_director(
+    req: HttpRequest,
+    state: State<AppState>,
+    path: Path<ChampionshipId>,
+) -> AppResult<HttpResponse> {
+    if path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    let user_id = req.user_id()?;
+
+    let relation = state
+        .championship_repo
+        .user_relation(path.0, user_id)
+        .await?;
+
+    if !relation.is_some_and(|relation| {
+        matches!(
+            relation.role,
+            ChampionshipRole::RaceDirector | ChampionshipRole::Admin
+        )
+    }) {
+        Err(ChampionshipError::NotRaceDirector)?
+    }
+
+    let Some((cached_data, rx)) = state.f1_svc.director_cache_and_subscribe(&path.0) else {
+        Err(F1ServiceError::NotActive)?
+    };
+
+    let stream = CleanupStream {
+        inner: BroadcastStream::new(rx),
+        state: state.clone(),
+        championship_id: path.0,
+        stream_type: StreamType::Director,
+    };
 
-            let stream = CleanupStream {
-                inner: BroadcastStream::new(rx),
-                state: state.clone(),
-                championship_id: path.0,
-                stream_type: StreamType::Engineer(team_id),
-            };
+    let mut response = HttpResponse::Ok();
+    response.content_type(HeaderValue::from_static("application/octet-stream"));
 
-            let mut response = HttpResponse::Ok();
-            response.content_type(HeaderValue::from_static("application/octet-stream"));
+    match cached_data {
+        None => Ok(response.streaming(stream)),
 
-            Ok(response.streaming(stream))
+        Some(data) => {
+            let cache_steam = tokio_stream::once(Ok(data));
+            let combined_stream = cache_steam.chain(stream);
+
+            Ok(response.streaming(combined_stream))
         }
+    }
+}
+
+#[inline]
+pub async fn stream_positions(
+    state: State<AppState>,
+    path: Path<ChampionshipId>,
+) -> AppResult<HttpResponse> {
+    if path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
 
-        None => Err(ChampionshipError::InvalidTeamId)?,
+    let Some((cached_data, rx)) = state.f1_svc.positions_cache_and_subscribe(&path.0) else {
+        Err(F1ServiceError::NotActive)?
+    };
+
+    let stream = CleanupStream {
+        inner: BroadcastStream::new(rx),
+        state: state.clone(),
+        championship_id: path.0,
+        stream_type: StreamType::Positions,
+    };
+
+    let mut response = HttpResponse::Ok();
+    response.content_type(HeaderValue::from_static("application/octet-stream"));
+
+    match cached_data {
+        None => Ok(response.streaming(stream)),
+
+        Some(data) => {
+            let cache_steam = tokio_stream::once(Ok(data));
+            let combined_stream = cache_steam.chain(stream);
+
+            Ok(response.streaming(combined_stream))
+        }
     }
 }
@@ -0,0 +1,43 @@
+use garde::Validate;
+use ntex::web::{
+    types::{Json, Path, State},
+    HttpRequest, HttpResponse,
+};
+
+use entities::UserExtension;
+use error::{AppResult, CommonError};
+use intelli_core::services::DriverServiceOperations;
+use structs::{DriverClaimCode, DriverClaimForm, SteamName};
+
+use crate::states::AppState;
+
+#[inline]
+pub(crate) async fn profile(
+    state: State<AppState>,
+    path: Path<SteamName>,
+) -> AppResult<HttpResponse> {
+    path.validate().map_err(|_| CommonError::ValidationFailed)?;
+
+    let profile = state.driver_svc.profile(&path.0).await?;
+    Ok(HttpResponse::Ok().json(&profile))
+}
+
+#[inline]
+pub(crate) async fn claim(
+    req: HttpRequest,
+    state: State<AppState>,
+    Json(form): Json<DriverClaimForm>,
+    path: Path<SteamName>,
+) -> AppResult<HttpResponse> {
+    if form.validate().is_err() || path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    let user_id = req.user_id()?;
+    let verification_code = state
+        .driver_svc
+        .claim(&path.0, user_id, form.championship_id)
+        .await?;
+
+    Ok(HttpResponse::Created().json(&DriverClaimCode { verification_code }))
+}
@@ -0,0 +1,32 @@
+use garde::Validate;
+use ntex::web::{
+    types::{Json, Path, State},
+    HttpRequest, HttpResponse,
+};
+
+use entities::UserExtension;
+use error::{AppResult, CommonError};
+use intelli_core::services::StewardServiceOperations;
+use structs::{IncidentId, IncidentRulingForm};
+
+use crate::states::AppState;
+
+#[inline]
+pub(crate) async fn rule(
+    req: HttpRequest,
+    state: State<AppState>,
+    Json(form): Json<IncidentRulingForm>,
+    path: Path<IncidentId>,
+) -> AppResult<HttpResponse> {
+    if form.validate().is_err() || path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    let user_id = req.user_id()?;
+    state
+        .steward_svc
+        .rule_incident(path.0, user_id, &form)
+        .await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
@@ -1,6 +1,10 @@
 pub(crate) mod admin;
 pub(crate) mod auth;
 pub(crate) mod championships;
+pub(crate) mod drivers;
+pub(crate) mod incidents;
+pub(crate) mod races;
+pub(crate) mod tracks;
 pub(crate) mod user;
 
 use ntex::web::HttpResponse;
@@ -0,0 +1,235 @@
+use garde::Validate;
+use ntex::web::{
+    types::{Json, Path, Query, State},
+    HttpRequest, HttpResponse,
+};
+
+use entities::UserExtension;
+use error::{AppResult, CommonError, RaceError, StewardError};
+use intelli_core::services::{RaceServiceOperations, StewardServiceOperations};
+use structs::{
+    AssistEvidenceReport, LapComparisonQuery, RaceDivisionForm, RaceDriver, RaceId, RaceResultData,
+    RaceSession, ResultPenaltyForm, SubstitutionForm,
+};
+
+use crate::states::AppState;
+
+#[inline]
+pub(crate) async fn weather(state: State<AppState>, path: Path<RaceId>) -> AppResult<HttpResponse> {
+    if path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    if state.race_repo.find(path.0).await?.is_none() {
+        Err(RaceError::NotFound)?
+    }
+
+    let samples = state.race_repo.weather_history(path.0).await?;
+    Ok(HttpResponse::Ok().json(&samples))
+}
+
+#[inline]
+pub(crate) async fn yellow_flags(
+    state: State<AppState>,
+    path: Path<RaceId>,
+) -> AppResult<HttpResponse> {
+    if path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    if state.race_repo.find(path.0).await?.is_none() {
+        Err(RaceError::NotFound)?
+    }
+
+    let yellow_flags = state.steward_repo.yellow_flags(path.0).await?;
+    Ok(HttpResponse::Ok().json(&yellow_flags))
+}
+
+#[inline]
+pub(crate) async fn incidents(
+    state: State<AppState>,
+    path: Path<RaceId>,
+) -> AppResult<HttpResponse> {
+    if path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    if state.race_repo.find(path.0).await?.is_none() {
+        Err(RaceError::NotFound)?
+    }
+
+    let incidents = state.steward_repo.incidents(path.0).await?;
+    Ok(HttpResponse::Ok().json(&incidents))
+}
+
+#[inline]
+pub(crate) async fn assists(state: State<AppState>, path: Path<RaceId>) -> AppResult<HttpResponse> {
+    if path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    if state.race_repo.find(path.0).await?.is_none() {
+        Err(RaceError::NotFound)?
+    }
+
+    let (hints, reported) = state
+        .steward_repo
+        .assist_evidence(path.0)
+        .await?
+        .into_iter()
+        .partition(|evidence| evidence.inferred);
+
+    Ok(HttpResponse::Ok().json(&AssistEvidenceReport { reported, hints }))
+}
+
+#[inline]
+pub(crate) async fn parc_ferme(
+    state: State<AppState>,
+    path: Path<RaceId>,
+) -> AppResult<HttpResponse> {
+    if path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    let breaches = state.steward_svc.parc_ferme_breaches(path.0).await?;
+    Ok(HttpResponse::Ok().json(&breaches))
+}
+
+#[inline]
+pub(crate) async fn result(
+    state: State<AppState>,
+    path: Path<RaceSession>,
+) -> AppResult<HttpResponse> {
+    if path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    let (entries, penalties, deviations) = tokio::try_join!(
+        state.result_repo.entries(path.id, path.session_type),
+        state.result_repo.penalties(path.id, path.session_type),
+        state
+            .steward_repo
+            .rule_deviations(path.id, path.session_type)
+    )?;
+
+    if entries.is_empty() {
+        Err(StewardError::ResultNotFound)?
+    }
+
+    Ok(HttpResponse::Ok().json(&RaceResultData {
+        entries,
+        penalties,
+        deviations,
+    }))
+}
+
+#[inline]
+pub(crate) async fn compare_laps(
+    state: State<AppState>,
+    path: Path<RaceSession>,
+    Query(query): Query<LapComparisonQuery>,
+) -> AppResult<HttpResponse> {
+    if path.validate().is_err() || query.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    let comparison = state
+        .race_svc
+        .compare_laps(path.id, path.session_type, &query)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(&comparison))
+}
+
+#[inline]
+pub(crate) async fn add_penalty(
+    req: HttpRequest,
+    state: State<AppState>,
+    Json(form): Json<ResultPenaltyForm>,
+    path: Path<RaceSession>,
+) -> AppResult<HttpResponse> {
+    if form.validate().is_err() || path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    let user_id = req.user_id()?;
+    state
+        .steward_svc
+        .apply_result_penalty(path.id, path.session_type, user_id, &form)
+        .await?;
+
+    Ok(HttpResponse::Created().finish())
+}
+
+#[inline]
+pub(crate) async fn entries(state: State<AppState>, path: Path<RaceId>) -> AppResult<HttpResponse> {
+    if path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    if state.race_repo.find(path.0).await?.is_none() {
+        Err(RaceError::NotFound)?
+    }
+
+    let entries = state.race_repo.entries(path.0).await?;
+    Ok(HttpResponse::Ok().json(&entries))
+}
+
+#[inline]
+pub(crate) async fn add_substitution(
+    req: HttpRequest,
+    state: State<AppState>,
+    Json(form): Json<SubstitutionForm>,
+    path: Path<RaceId>,
+) -> AppResult<HttpResponse> {
+    if form.validate().is_err() || path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    let user_id = req.user_id()?;
+    state
+        .race_svc
+        .add_substitution(path.0, user_id, &form)
+        .await?;
+
+    Ok(HttpResponse::Created().finish())
+}
+
+#[inline]
+pub(crate) async fn remove_substitution(
+    req: HttpRequest,
+    state: State<AppState>,
+    path: Path<RaceDriver>,
+) -> AppResult<HttpResponse> {
+    if path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    let user_id = req.user_id()?;
+    state
+        .race_svc
+        .remove_substitution(path.id, user_id, &path.steam_name)
+        .await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[inline]
+pub(crate) async fn update_division(
+    req: HttpRequest,
+    state: State<AppState>,
+    Json(form): Json<RaceDivisionForm>,
+    path: Path<RaceId>,
+) -> AppResult<HttpResponse> {
+    if form.validate().is_err() || path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    let user_id = req.user_id()?;
+    state
+        .race_svc
+        .update_division(path.0, user_id, &form)
+        .await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
@@ -0,0 +1,26 @@
+use garde::Validate;
+use ntex::web::{
+    types::{Path, State},
+    HttpResponse,
+};
+
+use error::{AppResult, CommonError, RaceError};
+use structs::TrackId;
+
+use crate::states::AppState;
+
+#[inline]
+pub(crate) async fn outline(
+    state: State<AppState>,
+    path: Path<TrackId>,
+) -> AppResult<HttpResponse> {
+    if path.validate().is_err() {
+        Err(CommonError::ValidationFailed)?
+    }
+
+    let Some(outline) = state.race_repo.track_outline(path.0).await? else {
+        Err(RaceError::TrackOutlineNotFound)?
+    };
+
+    Ok(HttpResponse::Ok().json(&outline))
+}
@@ -4,7 +4,7 @@ use dashmap::DashMap;
 use ntex::web::{self, delete, get, post, put, resource, scope, ServiceConfig};
 
 use crate::{
-    handlers::{auth, championships, system_health_check, user},
+    handlers::{auth, championships, drivers, incidents, races, system_health_check, tracks, user},
     middlewares::{Authentication, LoginLimit, VisitorData},
 };
 
@@ -48,15 +48,130 @@ pub(crate) fn api_routes(cfg: &mut ServiceConfig, visitors: &'static DashMap<IpA
                 scope("/{id}")
                     .route("", get().to(championships::core::get))
                     .route("", put().to(championships::core::update))
+                    .route("/standings", get().to(championships::core::standings))
+                    .route(
+                        "/standings/teams",
+                        get().to(championships::core::team_standings),
+                    )
+                    .route(
+                        "/hotlaps/{track_id}",
+                        get().to(championships::core::hotlaps),
+                    )
+                    .route("/rules", get().to(championships::core::rules))
+                    .route("/rules", put().to(championships::core::update_rules))
+                    .route(
+                        "/damage-thresholds",
+                        get().to(championships::core::damage_thresholds),
+                    )
+                    .route(
+                        "/damage-thresholds",
+                        put().to(championships::core::update_damage_thresholds),
+                    )
+                    .route("/engine-usage", get().to(championships::core::engine_usage))
+                    .route("/lineups", get().to(championships::core::lineups))
+                    .route("/races", post().to(championships::core::schedule_race))
+                    .service(
+                        scope("/teams")
+                            .route("", get().to(championships::core::teams))
+                            .route("", post().to(championships::core::create_team))
+                            .route("/{team_id}", put().to(championships::core::update_team))
+                            .route("/{team_id}", delete().to(championships::core::delete_team))
+                            .route(
+                                "/{team_id}/drivers",
+                                put().to(championships::core::transfer_driver),
+                            ),
+                    )
+                    .service(
+                        scope("/seasons")
+                            .route("", get().to(championships::core::seasons))
+                            .route("/rollover", post().to(championships::core::rollover_season))
+                            .route(
+                                "/{season}/drivers",
+                                get().to(championships::core::season_drivers),
+                            ),
+                    )
+                    .service(
+                        scope("/divisions")
+                            .route("", get().to(championships::core::divisions))
+                            .route("", post().to(championships::core::create_division))
+                            .route(
+                                "/{division_id}/drivers",
+                                put().to(championships::core::assign_division),
+                            ),
+                    )
+                    .route(
+                        "/driver-claims",
+                        get().to(championships::core::driver_claims),
+                    )
+                    .route(
+                        "/driver-claims",
+                        put().to(championships::core::review_driver_claim),
+                    )
                     .service(
                         scope("/users")
                             .route("", put().to(championships::core::add_user))
-                            .route("/{user_id}", delete().to(championships::core::remove_user)),
+                            .route("/{user_id}", delete().to(championships::core::remove_user))
+                            .route(
+                                "/{user_id}/telemetry-grants",
+                                get().to(championships::core::telemetry_grants),
+                            )
+                            .route(
+                                "/{user_id}/telemetry-grants",
+                                put().to(championships::core::update_telemetry_grants),
+                            ),
+                    ),
+            )
+            .wrap(Authentication),
+    );
+
+    cfg.service(
+        scope("/drivers")
+            .route("/{steam_name}", get().to(drivers::profile))
+            .route("/{steam_name}/claim", post().to(drivers::claim))
+            .wrap(Authentication),
+    );
+
+    cfg.service(
+        scope("/races")
+            .service(
+                scope("/{id}")
+                    .route("/weather", get().to(races::weather))
+                    .route("/yellow-flags", get().to(races::yellow_flags))
+                    .route("/incidents", get().to(races::incidents))
+                    .route("/assists", get().to(races::assists))
+                    .route("/parc-ferme", get().to(races::parc_ferme))
+                    .route("/entries", get().to(races::entries))
+                    .route("/division", put().to(races::update_division))
+                    .route("/substitutions", post().to(races::add_substitution))
+                    .route(
+                        "/substitutions/{steam_name}",
+                        delete().to(races::remove_substitution),
+                    )
+                    .route(
+                        "/laps/{session_type}/compare",
+                        get().to(races::compare_laps),
+                    )
+                    .service(
+                        scope("/results/{session_type}")
+                            .route("", get().to(races::result))
+                            .route("/penalties", post().to(races::add_penalty)),
                     ),
             )
             .wrap(Authentication),
     );
 
+    cfg.service(
+        scope("/tracks")
+            .route("/{track_id}/outline", get().to(tracks::outline))
+            .wrap(Authentication),
+    );
+
+    cfg.service(
+        scope("/incidents")
+            .route("/{id}/ruling", put().to(incidents::rule))
+            .wrap(Authentication),
+    );
+
     cfg.service(
         scope("/services")
             .service(
@@ -74,10 +189,29 @@ pub(crate) fn api_routes(cfg: &mut ServiceConfig, visitors: &'static DashMap<IpA
         scope("/stream").service(
             scope("/championships/{championship_id}")
                 .route("", get().to(championships::stream::stream_live_session))
+                .route(
+                    "/positions",
+                    get().to(championships::stream::stream_positions),
+                )
                 .service(
                     web::resource("/telemetry")
                         .wrap(Authentication)
                         .route(get().to(championships::stream::stream_telemetry_session)),
+                )
+                .service(
+                    web::resource("/telemetry/me")
+                        .wrap(Authentication)
+                        .route(get().to(championships::stream::stream_personal_telemetry)),
+                )
+                .service(
+                    web::resource("/stewarding")
+                        .wrap(Authentication)
+                        .route(get().to(championships::stream::stream_stewarding)),
+                )
+                .service(
+                    web::resource("/director")
+                        .wrap(Authentication)
+                        .route(get().to(championships::stream::stream_director)),
                 ),
         ),
     );
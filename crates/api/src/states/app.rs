@@ -4,10 +4,12 @@ use error::AppResult;
 use f1_telemetry::{F1ChampionshipManager, F1State, FirewallService};
 use intelli_core::{
     repositories::{
-        ChampionshipRepository, DiscordRepository, DriverRepository, ServerRepository,
-        UserRepository,
+        ChampionshipRepository, DiscordRepository, DriverRepository, RaceRepository,
+        ResultRepository, ServerRepository, StewardRepository, UserRepository,
+    },
+    services::{
+        ChampionshipService, DriverService, EmailService, RaceService, StewardService, UserService,
     },
-    services::{ChampionshipService, DriverService, EmailService, UserService},
 };
 use token_manager::TokenManager;
 
@@ -18,7 +20,11 @@ pub struct AppState {
     pub token_mgr: &'static TokenManager,
     pub championship_svc: &'static ChampionshipService,
     pub championship_repo: &'static ChampionshipRepository,
-    #[allow(unused)]
+    pub race_repo: &'static RaceRepository,
+    pub race_svc: &'static RaceService,
+    pub result_repo: &'static ResultRepository,
+    pub steward_repo: &'static StewardRepository,
+    pub steward_svc: &'static StewardService,
     pub driver_repo: &'static DriverRepository,
     #[allow(unused)]
     pub driver_svc: &'static DriverService,
@@ -35,13 +41,22 @@ impl AppState {
         let discord_repo = Box::leak(Box::new(DiscordRepository::new()));
         let championship_repo = Box::leak(Box::new(ChampionshipRepository::new(db)));
         let driver_repo = Box::leak(Box::new(DriverRepository::new(db)));
+        let race_repo = Box::leak(Box::new(RaceRepository::new(db)));
+        let steward_repo = Box::leak(Box::new(StewardRepository::new(db)));
+        let result_repo = Box::leak(Box::new(ResultRepository::new(db)));
 
         // Services
         let token_mgr = Box::leak(Box::from(TokenManager::load_from_file().unwrap()));
-        let driver_svc = Box::leak(Box::new(DriverService::new(db, driver_repo).await));
+        let driver_svc = Box::leak(Box::new(
+            DriverService::new(db, driver_repo, championship_repo, result_repo).await,
+        ));
         let user_svc = Box::leak(Box::from(UserService::new(db, user_repo, token_mgr).await));
         let championship_svc = Box::leak(Box::from(
-            ChampionshipService::new(db, user_repo, championship_repo).await?,
+            ChampionshipService::new(db, user_repo, championship_repo, race_repo).await?,
+        ));
+        let race_svc = Box::leak(Box::new(RaceService::new(db, race_repo, championship_repo)));
+        let steward_svc = Box::leak(Box::new(
+            StewardService::new(db, steward_repo, championship_repo, race_repo).await?,
         ));
 
         token_mgr.start_purge_thread();
@@ -52,6 +67,9 @@ impl AppState {
             driver_repo,
             championship_repo,
             championship_svc,
+            race_repo,
+            race_svc,
+            steward_svc,
             firewall,
         )));
 
@@ -62,6 +80,11 @@ impl AppState {
             token_mgr,
             championship_svc,
             championship_repo,
+            race_repo,
+            race_svc,
+            result_repo,
+            steward_repo,
+            steward_svc,
             driver_repo,
             driver_svc,
             email_svc: EmailService::new(),
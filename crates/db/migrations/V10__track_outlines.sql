@@ -0,0 +1,12 @@
+CREATE TABLE track_outlines (
+    track_id SMALLINT PRIMARY KEY,
+    track_length SMALLINT NOT NULL,
+    origin_x REAL NOT NULL,
+    origin_z REAL NOT NULL,
+    scale REAL NOT NULL,
+    points REAL[] NOT NULL,
+    pit_lane REAL[] NOT NULL,
+    sector2_index SMALLINT NOT NULL,
+    sector3_index SMALLINT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,19 @@
+CREATE TABLE lap_traces (
+    race_id INTEGER NOT NULL REFERENCES races(id) ON DELETE CASCADE,
+    session_type SMALLINT NOT NULL,
+    steam_name VARCHAR(100) NOT NULL,
+    lap_number SMALLINT NOT NULL,
+    lap_time INTEGER NOT NULL,
+    valid BOOLEAN NOT NULL,
+    step SMALLINT NOT NULL,
+    time INTEGER[] NOT NULL,
+    speed SMALLINT[] NOT NULL,
+    throttle SMALLINT[] NOT NULL,
+    brake SMALLINT[] NOT NULL,
+    gear SMALLINT[] NOT NULL,
+    steer SMALLINT[] NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (race_id, session_type, steam_name, lap_number)
+);
+
+CREATE INDEX idx_lap_traces_fastest ON lap_traces (race_id, session_type, lap_time) WHERE valid;
@@ -0,0 +1,27 @@
+CREATE TYPE damage_component AS ENUM (
+    'FrontWing',
+    'RearWing',
+    'Floor',
+    'Diffuser',
+    'Sidepod',
+    'Gearbox',
+    'Engine',
+    'EngineIce',
+    'EngineMguh',
+    'EngineMguk',
+    'EngineEs',
+    'EngineCe',
+    'EngineTc',
+    'Brakes',
+    'TyreWear',
+    'TyreDamageSpike',
+    'DrsFault',
+    'ErsFault'
+);
+
+CREATE TABLE damage_thresholds (
+    championship_id INTEGER NOT NULL REFERENCES championships(id) ON DELETE CASCADE,
+    component damage_component NOT NULL,
+    value SMALLINT NOT NULL,
+    PRIMARY KEY (championship_id, component)
+);
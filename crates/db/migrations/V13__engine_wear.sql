@@ -0,0 +1,13 @@
+CREATE TABLE engine_wear (
+    race_id INTEGER NOT NULL REFERENCES races(id) ON DELETE CASCADE,
+    session_type SMALLINT NOT NULL,
+    steam_name VARCHAR(100) NOT NULL,
+    ice SMALLINT NOT NULL,
+    mguh SMALLINT NOT NULL,
+    mguk SMALLINT NOT NULL,
+    es SMALLINT NOT NULL,
+    ce SMALLINT NOT NULL,
+    tc SMALLINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (race_id, session_type, steam_name)
+);
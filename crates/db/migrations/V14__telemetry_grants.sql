@@ -0,0 +1,9 @@
+CREATE TABLE telemetry_grants (
+    championship_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    team_id SMALLINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (championship_id, user_id, team_id),
+    FOREIGN KEY (user_id, championship_id)
+        REFERENCES championship_users(user_id, championship_id) ON DELETE CASCADE
+);
@@ -0,0 +1 @@
+ALTER TYPE championship_role ADD VALUE 'RaceDirector';
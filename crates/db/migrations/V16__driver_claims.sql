@@ -0,0 +1,14 @@
+CREATE TYPE driver_claim_status AS ENUM ('Pending', 'Approved', 'Rejected');
+
+CREATE TABLE driver_claims (
+    steam_name VARCHAR(100) NOT NULL REFERENCES drivers(steam_name) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    championship_id INTEGER NOT NULL REFERENCES championships(id) ON DELETE CASCADE,
+    verification_code SMALLINT NOT NULL,
+    status driver_claim_status NOT NULL DEFAULT 'Pending',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ,
+    PRIMARY KEY (steam_name, user_id)
+);
+
+CREATE INDEX idx_driver_claims_pending ON driver_claims (championship_id) WHERE status = 'Pending';
@@ -0,0 +1,25 @@
+CREATE TABLE league_teams (
+    championship_id INTEGER NOT NULL REFERENCES championships(id) ON DELETE CASCADE,
+    id SMALLINT NOT NULL,
+    game_team_id SMALLINT NOT NULL,
+    name VARCHAR(30) NOT NULL,
+    color CHAR(7),
+    logo VARCHAR(100),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ,
+    PRIMARY KEY (championship_id, id),
+    UNIQUE (championship_id, name)
+);
+
+CREATE TABLE team_lineups (
+    championship_id INTEGER NOT NULL,
+    team_id SMALLINT NOT NULL,
+    steam_name VARCHAR(100) NOT NULL REFERENCES drivers(steam_name) ON DELETE CASCADE,
+    joined_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    left_at TIMESTAMPTZ,
+    PRIMARY KEY (championship_id, steam_name, joined_at),
+    FOREIGN KEY (championship_id, team_id)
+        REFERENCES league_teams(championship_id, id) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX idx_team_lineups_current ON team_lineups (championship_id, steam_name) WHERE left_at IS NULL;
@@ -0,0 +1,8 @@
+CREATE TABLE race_substitutions (
+    race_id INTEGER NOT NULL REFERENCES races(id) ON DELETE CASCADE,
+    steam_name VARCHAR(100) NOT NULL,
+    replaces VARCHAR(100) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (race_id, steam_name),
+    UNIQUE (race_id, replaces)
+);
@@ -0,0 +1,50 @@
+CREATE TABLE championship_seasons (
+    championship_id INTEGER NOT NULL REFERENCES championships(id) ON DELETE CASCADE,
+    number SMALLINT NOT NULL,
+    started_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    ended_at TIMESTAMPTZ,
+    PRIMARY KEY (championship_id, number)
+);
+
+CREATE UNIQUE INDEX idx_championship_seasons_current ON championship_seasons (championship_id) WHERE ended_at IS NULL;
+
+INSERT INTO championship_seasons (championship_id, number, started_at)
+SELECT id, 1, created_at FROM championships;
+
+CREATE TABLE championship_divisions (
+    championship_id INTEGER NOT NULL REFERENCES championships(id) ON DELETE CASCADE,
+    id SMALLINT NOT NULL,
+    name VARCHAR(30) NOT NULL,
+    tier SMALLINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ,
+    PRIMARY KEY (championship_id, id),
+    UNIQUE (championship_id, name)
+);
+
+ALTER TABLE races
+    ADD COLUMN season SMALLINT NOT NULL DEFAULT 1,
+    ADD COLUMN division_id SMALLINT,
+    ADD FOREIGN KEY (championship_id, season)
+        REFERENCES championship_seasons(championship_id, number) ON DELETE CASCADE,
+    ADD FOREIGN KEY (championship_id, division_id)
+        REFERENCES championship_divisions(championship_id, id) ON DELETE SET NULL (division_id);
+
+ALTER TABLE championship_drivers
+    ADD COLUMN division_id SMALLINT,
+    ADD FOREIGN KEY (championship_id, division_id)
+        REFERENCES championship_divisions(championship_id, id) ON DELETE SET NULL (division_id);
+
+CREATE TABLE season_drivers (
+    championship_id INTEGER NOT NULL,
+    season SMALLINT NOT NULL,
+    steam_name VARCHAR(100) NOT NULL REFERENCES drivers(steam_name) ON DELETE CASCADE,
+    team_id SMALLINT NOT NULL,
+    number SMALLINT NOT NULL,
+    division_id SMALLINT,
+    PRIMARY KEY (championship_id, season, steam_name),
+    FOREIGN KEY (championship_id, season)
+        REFERENCES championship_seasons(championship_id, number) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_races_season ON races (championship_id, season);
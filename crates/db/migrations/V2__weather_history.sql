@@ -0,0 +1,12 @@
+CREATE TABLE weather_samples (
+    race_id INTEGER NOT NULL REFERENCES races(id) ON DELETE CASCADE,
+    session_type SMALLINT NOT NULL,
+    weather SMALLINT NOT NULL,
+    track_temperature SMALLINT NOT NULL,
+    air_temperature SMALLINT NOT NULL,
+    rain_percentage SMALLINT,
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (race_id, session_type, recorded_at)
+);
+
+CREATE INDEX idx_weather_samples_race ON weather_samples (race_id, recorded_at);
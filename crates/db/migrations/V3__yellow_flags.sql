@@ -0,0 +1,12 @@
+CREATE TABLE yellow_flags (
+    race_id INTEGER NOT NULL REFERENCES races(id) ON DELETE CASCADE,
+    session_type SMALLINT NOT NULL,
+    zone_index SMALLINT NOT NULL,
+    zone_start REAL NOT NULL,
+    started_session_time REAL NOT NULL,
+    ended_session_time REAL NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (race_id, session_type, zone_index, started_session_time)
+);
+
+CREATE INDEX idx_yellow_flags_race ON yellow_flags (race_id, session_type);
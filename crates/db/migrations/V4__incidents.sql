@@ -0,0 +1,37 @@
+CREATE TYPE incident_kind AS ENUM ('Penalty', 'Collision', 'SafetyCar');
+CREATE TYPE ruling_decision AS ENUM (
+    'NoFurtherAction',
+    'Warning',
+    'TimePenalty',
+    'GridPenalty',
+    'Disqualification'
+);
+
+CREATE TABLE incidents (
+    id INT PRIMARY KEY,
+    race_id INTEGER NOT NULL REFERENCES races(id) ON DELETE CASCADE,
+    session_type SMALLINT NOT NULL,
+    kind incident_kind NOT NULL,
+    session_time REAL NOT NULL,
+    lap SMALLINT,
+    steam_name VARCHAR(100),
+    other_steam_name VARCHAR(100),
+    penalty_type SMALLINT,
+    infringement_type SMALLINT,
+    penalty_time SMALLINT,
+    safety_car_type SMALLINT,
+    safety_car_event SMALLINT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE incident_rulings (
+    incident_id INTEGER PRIMARY KEY REFERENCES incidents(id) ON DELETE CASCADE,
+    steward_id INTEGER NOT NULL REFERENCES users(id),
+    decision ruling_decision NOT NULL,
+    penalty_time SMALLINT,
+    notes VARCHAR(500),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_incidents_race ON incidents (race_id, session_type, session_time);
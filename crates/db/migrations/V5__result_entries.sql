@@ -0,0 +1,42 @@
+CREATE TYPE result_penalty_kind AS ENUM ('TimePenalty', 'Disqualification');
+
+CREATE TABLE result_entries (
+    race_id INTEGER NOT NULL,
+    session_type SMALLINT NOT NULL,
+    steam_name VARCHAR(100) NOT NULL,
+    team_id SMALLINT NOT NULL,
+    grid_position SMALLINT NOT NULL,
+    laps SMALLINT NOT NULL,
+    race_time DOUBLE PRECISION NOT NULL,
+    penalties_time SMALLINT NOT NULL,
+    best_lap_time INTEGER NOT NULL,
+    result_status SMALLINT NOT NULL,
+    original_position SMALLINT NOT NULL,
+    original_points SMALLINT NOT NULL,
+    position SMALLINT NOT NULL,
+    points SMALLINT NOT NULL,
+    steward_time SMALLINT NOT NULL DEFAULT 0,
+    disqualified BOOLEAN NOT NULL DEFAULT FALSE,
+    updated_at TIMESTAMPTZ,
+    PRIMARY KEY (race_id, session_type, steam_name),
+    FOREIGN KEY (race_id, session_type) REFERENCES results(race_id, session_type) ON DELETE CASCADE
+);
+
+CREATE TABLE result_penalties (
+    race_id INTEGER NOT NULL,
+    session_type SMALLINT NOT NULL,
+    steam_name VARCHAR(100) NOT NULL,
+    kind result_penalty_kind NOT NULL,
+    seconds SMALLINT,
+    reason VARCHAR(500),
+    incident_id INTEGER REFERENCES incidents(id) ON DELETE SET NULL,
+    steward_id INTEGER NOT NULL REFERENCES users(id),
+    previous_position SMALLINT NOT NULL,
+    new_position SMALLINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (race_id, session_type, steam_name, created_at),
+    FOREIGN KEY (race_id, session_type, steam_name)
+        REFERENCES result_entries(race_id, session_type, steam_name) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_result_entries_steam_name ON result_entries (steam_name);
@@ -0,0 +1,46 @@
+CREATE TYPE session_rule AS ENUM (
+    'SteeringAssist',
+    'BrakingAssist',
+    'GearboxAssist',
+    'PitAssist',
+    'PitReleaseAssist',
+    'ErsAssist',
+    'DrsAssist',
+    'DynamicRacingLine',
+    'EqualCarPerformance',
+    'RecoveryMode',
+    'FlashbackLimit',
+    'SurfaceType',
+    'LowFuelMode',
+    'RaceStarts',
+    'TyreTemperature',
+    'PitLaneTyreSim',
+    'CarDamage',
+    'CarDamageRate',
+    'Collisions',
+    'CollisionsOffForFirstLapOnly',
+    'UnsafePitRelease',
+    'OffForGriefing',
+    'CornerCuttingStringency',
+    'ParcFermeRules',
+    'SafetyCar',
+    'FormationLap',
+    'RedFlags'
+);
+
+CREATE TABLE championship_rules (
+    championship_id INTEGER NOT NULL REFERENCES championships(id) ON DELETE CASCADE,
+    rule session_rule NOT NULL,
+    value SMALLINT NOT NULL,
+    PRIMARY KEY (championship_id, rule)
+);
+
+CREATE TABLE rule_deviations (
+    race_id INTEGER NOT NULL REFERENCES races(id) ON DELETE CASCADE,
+    session_type SMALLINT NOT NULL,
+    rule session_rule NOT NULL,
+    expected SMALLINT NOT NULL,
+    actual SMALLINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (race_id, session_type, rule)
+);
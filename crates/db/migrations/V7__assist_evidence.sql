@@ -0,0 +1,20 @@
+CREATE TYPE driver_assist AS ENUM (
+    'TractionControl',
+    'AntiLockBrakes',
+    'SteeringAssist',
+    'BrakingAssist',
+    'AutomaticGearbox'
+);
+
+CREATE TABLE assist_evidence (
+    race_id INTEGER NOT NULL REFERENCES races(id) ON DELETE CASCADE,
+    session_type SMALLINT NOT NULL,
+    steam_name VARCHAR(100) NOT NULL,
+    assist driver_assist NOT NULL,
+    inferred BOOLEAN NOT NULL,
+    samples INTEGER NOT NULL,
+    total_samples INTEGER NOT NULL,
+    level SMALLINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (race_id, session_type, steam_name, assist)
+);
@@ -0,0 +1,30 @@
+CREATE TABLE setup_snapshots (
+    race_id INTEGER NOT NULL REFERENCES races(id) ON DELETE CASCADE,
+    session_type SMALLINT NOT NULL,
+    steam_name VARCHAR(100) NOT NULL,
+    front_wing SMALLINT NOT NULL,
+    rear_wing SMALLINT NOT NULL,
+    on_throttle SMALLINT NOT NULL,
+    off_throttle SMALLINT NOT NULL,
+    front_camber REAL NOT NULL,
+    rear_camber REAL NOT NULL,
+    front_toe REAL NOT NULL,
+    rear_toe REAL NOT NULL,
+    front_suspension SMALLINT NOT NULL,
+    rear_suspension SMALLINT NOT NULL,
+    front_anti_roll_bar SMALLINT NOT NULL,
+    rear_anti_roll_bar SMALLINT NOT NULL,
+    front_suspension_height SMALLINT NOT NULL,
+    rear_suspension_height SMALLINT NOT NULL,
+    brake_pressure SMALLINT NOT NULL,
+    brake_bias SMALLINT NOT NULL,
+    engine_braking SMALLINT NOT NULL,
+    rear_left_tyre_pressure REAL NOT NULL,
+    rear_right_tyre_pressure REAL NOT NULL,
+    front_left_tyre_pressure REAL NOT NULL,
+    front_right_tyre_pressure REAL NOT NULL,
+    ballast SMALLINT NOT NULL,
+    fuel_load REAL NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (race_id, session_type, steam_name)
+);
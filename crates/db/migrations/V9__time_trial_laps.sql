@@ -0,0 +1,26 @@
+CREATE TYPE time_trial_lap_kind AS ENUM (
+    'SessionBest',
+    'PersonalBest'
+);
+
+CREATE TABLE time_trial_laps (
+    championship_id INTEGER NOT NULL REFERENCES championships(id) ON DELETE CASCADE,
+    steam_name VARCHAR(100) NOT NULL,
+    track_id SMALLINT NOT NULL,
+    kind time_trial_lap_kind NOT NULL,
+    team_id SMALLINT NOT NULL,
+    lap_time INTEGER NOT NULL,
+    sector1_time INTEGER NOT NULL,
+    sector2_time INTEGER NOT NULL,
+    sector3_time INTEGER NOT NULL,
+    traction_control SMALLINT NOT NULL,
+    gearbox_assist SMALLINT NOT NULL,
+    anti_lock_brakes BOOLEAN NOT NULL,
+    equal_car_performance BOOLEAN NOT NULL,
+    custom_setup BOOLEAN NOT NULL,
+    valid BOOLEAN NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (championship_id, steam_name, track_id, kind)
+);
+
+CREATE INDEX idx_time_trial_laps_leaderboard ON time_trial_laps (championship_id, track_id, lap_time);
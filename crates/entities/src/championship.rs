@@ -22,6 +22,8 @@ pub enum ChampionshipRole {
     Engineer,
     #[postgres(name = "Admin")]
     Admin,
+    #[postgres(name = "RaceDirector")]
+    RaceDirector,
 }
 
 /// Championship categories
@@ -34,6 +36,318 @@ pub enum Category {
     F2,
 }
 
+/// Session settings that a championship can require
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, FromSql, ToSql, PartialEq)]
+#[postgres(name = "session_rule")]
+pub enum SessionRule {
+    #[postgres(name = "SteeringAssist")]
+    SteeringAssist,
+    #[postgres(name = "BrakingAssist")]
+    BrakingAssist,
+    #[postgres(name = "GearboxAssist")]
+    GearboxAssist,
+    #[postgres(name = "PitAssist")]
+    PitAssist,
+    #[postgres(name = "PitReleaseAssist")]
+    PitReleaseAssist,
+    #[postgres(name = "ErsAssist")]
+    ErsAssist,
+    #[postgres(name = "DrsAssist")]
+    DrsAssist,
+    #[postgres(name = "DynamicRacingLine")]
+    DynamicRacingLine,
+    #[postgres(name = "EqualCarPerformance")]
+    EqualCarPerformance,
+    #[postgres(name = "RecoveryMode")]
+    RecoveryMode,
+    #[postgres(name = "FlashbackLimit")]
+    FlashbackLimit,
+    #[postgres(name = "SurfaceType")]
+    SurfaceType,
+    #[postgres(name = "LowFuelMode")]
+    LowFuelMode,
+    #[postgres(name = "RaceStarts")]
+    RaceStarts,
+    #[postgres(name = "TyreTemperature")]
+    TyreTemperature,
+    #[postgres(name = "PitLaneTyreSim")]
+    PitLaneTyreSim,
+    #[postgres(name = "CarDamage")]
+    CarDamage,
+    #[postgres(name = "CarDamageRate")]
+    CarDamageRate,
+    #[postgres(name = "Collisions")]
+    Collisions,
+    #[postgres(name = "CollisionsOffForFirstLapOnly")]
+    CollisionsOffForFirstLapOnly,
+    #[postgres(name = "UnsafePitRelease")]
+    UnsafePitRelease,
+    #[postgres(name = "OffForGriefing")]
+    OffForGriefing,
+    #[postgres(name = "CornerCuttingStringency")]
+    CornerCuttingStringency,
+    #[postgres(name = "ParcFermeRules")]
+    ParcFermeRules,
+    #[postgres(name = "SafetyCar")]
+    SafetyCar,
+    #[postgres(name = "FormationLap")]
+    FormationLap,
+    #[postgres(name = "RedFlags")]
+    RedFlags,
+}
+
+impl SessionRule {
+    /// Returns the name of the rule
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            SessionRule::SteeringAssist => "SteeringAssist",
+            SessionRule::BrakingAssist => "BrakingAssist",
+            SessionRule::GearboxAssist => "GearboxAssist",
+            SessionRule::PitAssist => "PitAssist",
+            SessionRule::PitReleaseAssist => "PitReleaseAssist",
+            SessionRule::ErsAssist => "ErsAssist",
+            SessionRule::DrsAssist => "DrsAssist",
+            SessionRule::DynamicRacingLine => "DynamicRacingLine",
+            SessionRule::EqualCarPerformance => "EqualCarPerformance",
+            SessionRule::RecoveryMode => "RecoveryMode",
+            SessionRule::FlashbackLimit => "FlashbackLimit",
+            SessionRule::SurfaceType => "SurfaceType",
+            SessionRule::LowFuelMode => "LowFuelMode",
+            SessionRule::RaceStarts => "RaceStarts",
+            SessionRule::TyreTemperature => "TyreTemperature",
+            SessionRule::PitLaneTyreSim => "PitLaneTyreSim",
+            SessionRule::CarDamage => "CarDamage",
+            SessionRule::CarDamageRate => "CarDamageRate",
+            SessionRule::Collisions => "Collisions",
+            SessionRule::CollisionsOffForFirstLapOnly => "CollisionsOffForFirstLapOnly",
+            SessionRule::UnsafePitRelease => "UnsafePitRelease",
+            SessionRule::OffForGriefing => "OffForGriefing",
+            SessionRule::CornerCuttingStringency => "CornerCuttingStringency",
+            SessionRule::ParcFermeRules => "ParcFermeRules",
+            SessionRule::SafetyCar => "SafetyCar",
+            SessionRule::FormationLap => "FormationLap",
+            SessionRule::RedFlags => "RedFlags",
+        }
+    }
+}
+
+/// Represents a session setting required by a championship
+#[derive(Debug, Serialize)]
+pub struct ChampionshipRule {
+    pub rule: SessionRule,
+    pub value: i16,
+}
+
+impl ChampionshipRule {
+    /// Creates a ChampionshipRule from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        ChampionshipRule {
+            rule: row.get(0),
+            value: row.get(1),
+        }
+    }
+}
+
+/// Car components an engineer can be alerted about
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, FromSql, ToSql, PartialEq)]
+#[postgres(name = "damage_component")]
+pub enum DamageComponent {
+    #[postgres(name = "FrontWing")]
+    FrontWing,
+    #[postgres(name = "RearWing")]
+    RearWing,
+    #[postgres(name = "Floor")]
+    Floor,
+    #[postgres(name = "Diffuser")]
+    Diffuser,
+    #[postgres(name = "Sidepod")]
+    Sidepod,
+    #[postgres(name = "Gearbox")]
+    Gearbox,
+    #[postgres(name = "Engine")]
+    Engine,
+    #[postgres(name = "EngineIce")]
+    EngineIce,
+    #[postgres(name = "EngineMguh")]
+    EngineMguh,
+    #[postgres(name = "EngineMguk")]
+    EngineMguk,
+    #[postgres(name = "EngineEs")]
+    EngineEs,
+    #[postgres(name = "EngineCe")]
+    EngineCe,
+    #[postgres(name = "EngineTc")]
+    EngineTc,
+    #[postgres(name = "Brakes")]
+    Brakes,
+    #[postgres(name = "TyreWear")]
+    TyreWear,
+    #[postgres(name = "TyreDamageSpike")]
+    TyreDamageSpike,
+    #[postgres(name = "DrsFault")]
+    DrsFault,
+    #[postgres(name = "ErsFault")]
+    ErsFault,
+}
+
+impl DamageComponent {
+    /// Returns the name of the component
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            DamageComponent::FrontWing => "FrontWing",
+            DamageComponent::RearWing => "RearWing",
+            DamageComponent::Floor => "Floor",
+            DamageComponent::Diffuser => "Diffuser",
+            DamageComponent::Sidepod => "Sidepod",
+            DamageComponent::Gearbox => "Gearbox",
+            DamageComponent::Engine => "Engine",
+            DamageComponent::EngineIce => "EngineIce",
+            DamageComponent::EngineMguh => "EngineMguh",
+            DamageComponent::EngineMguk => "EngineMguk",
+            DamageComponent::EngineEs => "EngineEs",
+            DamageComponent::EngineCe => "EngineCe",
+            DamageComponent::EngineTc => "EngineTc",
+            DamageComponent::Brakes => "Brakes",
+            DamageComponent::TyreWear => "TyreWear",
+            DamageComponent::TyreDamageSpike => "TyreDamageSpike",
+            DamageComponent::DrsFault => "DrsFault",
+            DamageComponent::ErsFault => "ErsFault",
+        }
+    }
+}
+
+/// Represents the damage or wear of a component at which engineers get alerted
+#[derive(Debug, Serialize)]
+pub struct DamageThreshold {
+    pub component: DamageComponent,
+    pub value: i16,
+}
+
+impl DamageThreshold {
+    /// Creates a DamageThreshold from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        DamageThreshold {
+            component: row.get(0),
+            value: row.get(1),
+        }
+    }
+}
+
+/// Represents a season of a championship, open until the league rolls over to the next one
+#[derive(Debug, Serialize)]
+pub struct ChampionshipSeason {
+    pub number: i16,
+    pub started_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+impl ChampionshipSeason {
+    /// Creates a ChampionshipSeason from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        ChampionshipSeason {
+            number: row.get(0),
+            started_at: row.get(1),
+            ended_at: row.get(2),
+        }
+    }
+}
+
+/// Represents a tier of a championship, the lower the tier the higher the division
+#[derive(Debug, Serialize)]
+pub struct Division {
+    pub id: i16,
+    pub name: Box<str>,
+    pub tier: i16,
+}
+
+impl Division {
+    /// Creates a Division from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        Division {
+            id: row.get(0),
+            name: row.get(1),
+            tier: row.get(2),
+        }
+    }
+}
+
+/// Represents a team of a league, mapped to the game team its drivers race with
+#[derive(Debug, Serialize)]
+pub struct LeagueTeam {
+    pub id: i16,
+    pub game_team_id: i16,
+    pub name: Box<str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo: Option<Box<str>>,
+}
+
+impl LeagueTeam {
+    /// Creates a LeagueTeam from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        LeagueTeam {
+            id: row.get(0),
+            game_team_id: row.get(1),
+            name: row.get(2),
+            color: row.get(3),
+            logo: row.get(4),
+        }
+    }
+}
+
+/// Represents a spell of a driver in a league team, open while they still race for it
+#[derive(Debug, Serialize)]
+pub struct TeamLineup {
+    pub team_id: i16,
+    pub steam_name: Box<str>,
+    pub joined_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub left_at: Option<DateTime<Utc>>,
+}
+
+impl TeamLineup {
+    /// Creates a TeamLineup from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        TeamLineup {
+            team_id: row.get(0),
+            steam_name: row.get(1),
+            joined_at: row.get(2),
+            left_at: row.get(3),
+        }
+    }
+}
+
+/// Represents a driver registered in a championship
+#[derive(Debug, Serialize)]
+pub struct ChampionshipDriver {
+    pub steam_name: Box<str>,
+    pub team_id: i16,
+    pub number: i16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub division_id: Option<i16>,
+}
+
+impl ChampionshipDriver {
+    /// Creates a ChampionshipDriver from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        ChampionshipDriver {
+            steam_name: row.get(0),
+            team_id: row.get(1),
+            number: row.get(2),
+            division_id: row.get(3),
+        }
+    }
+}
+
 pub struct ChampionshipRelation {
     pub role: ChampionshipRole,
     pub team_id: Option<i16>,
@@ -2,6 +2,8 @@ use std::{num::NonZeroI32, sync::Arc};
 
 use chrono::{DateTime, Utc};
 use deadpool_postgres::tokio_postgres::Row;
+use postgres_derive::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
 
 pub type SharedDriver = Arc<Driver>;
 
@@ -34,3 +36,45 @@ impl Driver {
         Arc::new(Driver::from_row(row))
     }
 }
+
+/// State of a request to link a driver to a user
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, FromSql, ToSql, PartialEq)]
+#[postgres(name = "driver_claim_status")]
+pub enum DriverClaimStatus {
+    #[postgres(name = "Pending")]
+    Pending,
+    #[postgres(name = "Approved")]
+    Approved,
+    #[postgres(name = "Rejected")]
+    Rejected,
+}
+
+/// Represents a request of a user to be linked to a driver, approved by a championship
+/// admin or by racing with the verification code as race number
+#[derive(Debug, Serialize)]
+pub struct DriverClaim {
+    pub steam_name: Box<str>,
+    pub user_id: i32,
+    pub championship_id: i32,
+    pub verification_code: i16,
+    pub status: DriverClaimStatus,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl DriverClaim {
+    /// Creates a DriverClaim from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        DriverClaim {
+            steam_name: row.get(0),
+            user_id: row.get(1),
+            championship_id: row.get(2),
+            verification_code: row.get(3),
+            status: row.get(4),
+            created_at: row.get(5),
+            updated_at: row.get(6),
+        }
+    }
+}
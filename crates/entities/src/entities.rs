@@ -2,10 +2,14 @@ mod championship;
 mod driver;
 mod race;
 mod result;
+mod steward;
+mod time_trial;
 mod user;
 
 pub use championship::*;
 pub use driver::*;
 pub use race::*;
 pub use result::*;
+pub use steward::*;
+pub use time_trial::*;
 pub use user::*;
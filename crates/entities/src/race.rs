@@ -10,13 +10,16 @@ pub type SharedRace = Arc<Race>;
 /// Represents a race in a championship
 #[derive(Debug, Serialize)]
 pub struct Race {
-    id: i32,
-    championship_id: i32,
-    track_id: i16,
-    date: DateTime<Utc>,
-    created_at: DateTime<Utc>,
+    pub id: i32,
+    pub championship_id: i32,
+    pub track_id: i16,
+    pub date: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    updated_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub season: i16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub division_id: Option<i16>,
 }
 
 impl Race {
@@ -30,6 +33,8 @@ impl Race {
             date: row.get(3),
             created_at: row.get(4),
             updated_at: row.get(5),
+            season: row.get(6),
+            division_id: row.get(7),
         }
     }
 
@@ -38,3 +43,164 @@ impl Race {
         Arc::new(Self::from_row(row))
     }
 }
+
+/// Represents the weather conditions recorded at a point of a race session
+#[derive(Debug, Serialize)]
+pub struct WeatherSample {
+    pub session_type: i16,
+    pub weather: i16,
+    pub track_temperature: i16,
+    pub air_temperature: i16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rain_percentage: Option<i16>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl WeatherSample {
+    /// Creates a WeatherSample from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        WeatherSample {
+            session_type: row.get(0),
+            weather: row.get(1),
+            track_temperature: row.get(2),
+            air_temperature: row.get(3),
+            rain_percentage: row.get(4),
+            recorded_at: row.get(5),
+        }
+    }
+}
+
+/// Represents the outline of a track scaled to fit a unit square. Points are stored as
+/// x and z pairs, world positions are `origin + point * scale`
+#[derive(Debug, Serialize)]
+pub struct TrackOutline {
+    pub track_id: i16,
+    pub track_length: i16,
+    pub origin_x: f32,
+    pub origin_z: f32,
+    pub scale: f32,
+    pub points: Vec<f32>,
+    pub pit_lane: Vec<f32>,
+    pub sector2_index: i16,
+    pub sector3_index: i16,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TrackOutline {
+    /// Creates a TrackOutline from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        TrackOutline {
+            track_id: row.get(0),
+            track_length: row.get(1),
+            origin_x: row.get(2),
+            origin_z: row.get(3),
+            scale: row.get(4),
+            points: row.get(5),
+            pit_lane: row.get(6),
+            sector2_index: row.get(7),
+            sector3_index: row.get(8),
+            updated_at: row.get(9),
+        }
+    }
+}
+
+/// Represents the telemetry of a lap sampled every `step` metres of lap distance
+#[derive(Debug, Serialize)]
+pub struct LapTrace {
+    pub session_type: i16,
+    pub steam_name: Box<str>,
+    pub lap_number: i16,
+    pub lap_time: i32,
+    pub valid: bool,
+    pub step: i16,
+    pub time: Vec<i32>,
+    pub speed: Vec<i16>,
+    pub throttle: Vec<i16>,
+    pub brake: Vec<i16>,
+    pub gear: Vec<i16>,
+    pub steer: Vec<i16>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LapTrace {
+    /// Creates a LapTrace from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        LapTrace {
+            session_type: row.get(0),
+            steam_name: row.get(1),
+            lap_number: row.get(2),
+            lap_time: row.get(3),
+            valid: row.get(4),
+            step: row.get(5),
+            time: row.get(6),
+            speed: row.get(7),
+            throttle: row.get(8),
+            brake: row.get(9),
+            gear: row.get(10),
+            steer: row.get(11),
+            created_at: row.get(12),
+        }
+    }
+}
+
+/// Represents the wear of the power unit components of a driver at the end of a session
+#[derive(Debug, Serialize)]
+pub struct EngineWear {
+    pub race_id: i32,
+    pub session_type: i16,
+    pub steam_name: Box<str>,
+    pub ice: i16,
+    pub mguh: i16,
+    pub mguk: i16,
+    pub es: i16,
+    pub ce: i16,
+    pub tc: i16,
+    pub created_at: DateTime<Utc>,
+}
+
+impl EngineWear {
+    /// Creates an EngineWear from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        EngineWear {
+            race_id: row.get(0),
+            session_type: row.get(1),
+            steam_name: row.get(2),
+            ice: row.get(3),
+            mguh: row.get(4),
+            mguk: row.get(5),
+            es: row.get(6),
+            ce: row.get(7),
+            tc: row.get(8),
+            created_at: row.get(9),
+        }
+    }
+}
+
+/// Represents a car of the entry list of a race, driven by a reserve when the regular
+/// driver is substituted
+#[derive(Debug, Serialize)]
+pub struct RaceEntry {
+    pub steam_name: Box<str>,
+    pub team_id: i16,
+    pub number: i16,
+    /// Regular driver the reserve replaces
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaces: Option<Box<str>>,
+}
+
+impl RaceEntry {
+    /// Creates a RaceEntry from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        RaceEntry {
+            steam_name: row.get(0),
+            team_id: row.get(1),
+            number: row.get(2),
+            replaces: row.get(3),
+        }
+    }
+}
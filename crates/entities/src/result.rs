@@ -1,5 +1,7 @@
 use chrono::{DateTime, Utc};
 use deadpool_postgres::tokio_postgres::Row;
+use postgres_derive::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
 
 /// Represents a result for a race session
 #[allow(unused)]
@@ -23,3 +25,195 @@ impl Result {
         }
     }
 }
+
+/// Kind of penalty applied by the stewards after a session
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, FromSql, ToSql, PartialEq)]
+#[postgres(name = "result_penalty_kind")]
+pub enum ResultPenaltyKind {
+    #[postgres(name = "TimePenalty")]
+    TimePenalty,
+    #[postgres(name = "Disqualification")]
+    Disqualification,
+}
+
+/// Represents the classification of a driver in a session result
+#[derive(Debug, Serialize)]
+pub struct ResultEntry {
+    pub steam_name: Box<str>,
+    pub team_id: i16,
+    pub grid_position: i16,
+    pub laps: i16,
+    pub race_time: f64,
+    pub penalties_time: i16,
+    pub best_lap_time: i32,
+    pub result_status: i16,
+    pub original_position: i16,
+    pub original_points: i16,
+    pub position: i16,
+    pub points: i16,
+    pub steward_time: i16,
+    pub disqualified: bool,
+}
+
+/// Represents a penalty applied to a session result, kept as audit trail
+#[derive(Debug, Serialize)]
+pub struct ResultPenalty {
+    pub steam_name: Box<str>,
+    pub kind: ResultPenaltyKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seconds: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incident_id: Option<i32>,
+    pub steward_id: i32,
+    pub previous_position: i16,
+    pub new_position: i16,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Represents the standing of a driver in a championship
+#[derive(Debug, Serialize)]
+pub struct DriverStanding {
+    pub steam_name: Box<str>,
+    pub points: i64,
+    pub wins: i64,
+    pub podiums: i64,
+    pub races: i64,
+    /// League team the driver currently races for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<str>>,
+}
+
+/// Represents the classification of a driver in a race session, read for career statistics
+#[derive(Debug)]
+pub struct CareerEntry {
+    pub race_id: i32,
+    pub session_type: i16,
+    pub steam_name: Box<str>,
+    pub team_id: i16,
+    pub grid_position: i16,
+    pub position: i16,
+    pub best_lap_time: i32,
+    pub result_status: i16,
+    pub disqualified: bool,
+}
+
+/// Represents the best lap of a driver on a track across all championships
+#[derive(Debug, Serialize)]
+pub struct TrackBestLap {
+    pub track_id: i16,
+    pub lap_time: i32,
+}
+
+/// Represents the standing of a team in a championship, a league team when the driver raced
+/// for one and the game team otherwise
+#[derive(Debug, Serialize)]
+pub struct TeamStanding {
+    pub game_team_id: i16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<str>>,
+    pub points: i64,
+    pub wins: i64,
+}
+
+impl ResultEntry {
+    /// Creates a ResultEntry from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        ResultEntry {
+            steam_name: row.get(0),
+            team_id: row.get(1),
+            grid_position: row.get(2),
+            laps: row.get(3),
+            race_time: row.get(4),
+            penalties_time: row.get(5),
+            best_lap_time: row.get(6),
+            result_status: row.get(7),
+            original_position: row.get(8),
+            original_points: row.get(9),
+            position: row.get(10),
+            points: row.get(11),
+            steward_time: row.get(12),
+            disqualified: row.get(13),
+        }
+    }
+}
+
+impl ResultPenalty {
+    /// Creates a ResultPenalty from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        ResultPenalty {
+            steam_name: row.get(0),
+            kind: row.get(1),
+            seconds: row.get(2),
+            reason: row.get(3),
+            incident_id: row.get(4),
+            steward_id: row.get(5),
+            previous_position: row.get(6),
+            new_position: row.get(7),
+            created_at: row.get(8),
+        }
+    }
+}
+
+impl DriverStanding {
+    /// Creates a DriverStanding from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        DriverStanding {
+            steam_name: row.get(0),
+            points: row.get(1),
+            wins: row.get(2),
+            podiums: row.get(3),
+            races: row.get(4),
+            team: row.get(5),
+        }
+    }
+}
+
+impl CareerEntry {
+    /// Creates a CareerEntry from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        CareerEntry {
+            race_id: row.get(0),
+            session_type: row.get(1),
+            steam_name: row.get(2),
+            team_id: row.get(3),
+            grid_position: row.get(4),
+            position: row.get(5),
+            best_lap_time: row.get(6),
+            result_status: row.get(7),
+            disqualified: row.get(8),
+        }
+    }
+}
+
+impl TrackBestLap {
+    /// Creates a TrackBestLap from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        TrackBestLap {
+            track_id: row.get(0),
+            lap_time: row.get(1),
+        }
+    }
+}
+
+impl TeamStanding {
+    /// Creates a TeamStanding from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        TeamStanding {
+            game_team_id: row.get(0),
+            team_id: row.get(1),
+            name: row.get(2),
+            points: row.get(3),
+            wins: row.get(4),
+        }
+    }
+}
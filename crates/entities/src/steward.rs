@@ -0,0 +1,302 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::tokio_postgres::Row;
+use postgres_derive::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+
+use crate::SessionRule;
+
+/// Kind of incident recorded during a session
+#[derive(Debug, Clone, Copy, Serialize, FromSql, ToSql, PartialEq)]
+#[postgres(name = "incident_kind")]
+pub enum IncidentKind {
+    #[postgres(name = "Penalty")]
+    Penalty,
+    #[postgres(name = "Collision")]
+    Collision,
+    #[postgres(name = "SafetyCar")]
+    SafetyCar,
+}
+
+impl IncidentKind {
+    /// Returns the name of the kind
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            IncidentKind::Penalty => "Penalty",
+            IncidentKind::Collision => "Collision",
+            IncidentKind::SafetyCar => "SafetyCar",
+        }
+    }
+}
+
+/// Decision taken by the stewards after reviewing an incident
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, FromSql, ToSql, PartialEq)]
+#[postgres(name = "ruling_decision")]
+pub enum RulingDecision {
+    #[postgres(name = "NoFurtherAction")]
+    NoFurtherAction,
+    #[postgres(name = "Warning")]
+    Warning,
+    #[postgres(name = "TimePenalty")]
+    TimePenalty,
+    #[postgres(name = "GridPenalty")]
+    GridPenalty,
+    #[postgres(name = "Disqualification")]
+    Disqualification,
+}
+
+/// Driver assists that leagues can forbid
+#[derive(Debug, Clone, Copy, Serialize, FromSql, ToSql, PartialEq)]
+#[postgres(name = "driver_assist")]
+pub enum DriverAssist {
+    #[postgres(name = "TractionControl")]
+    TractionControl,
+    #[postgres(name = "AntiLockBrakes")]
+    AntiLockBrakes,
+    #[postgres(name = "SteeringAssist")]
+    SteeringAssist,
+    #[postgres(name = "BrakingAssist")]
+    BrakingAssist,
+    #[postgres(name = "AutomaticGearbox")]
+    AutomaticGearbox,
+}
+
+/// Represents a yellow flag shown in a marshal zone during a race session
+#[derive(Debug, Serialize)]
+pub struct YellowFlag {
+    pub session_type: i16,
+    pub zone_index: i16,
+    pub zone_start: f32,
+    pub started_session_time: f32,
+    pub ended_session_time: f32,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Represents an incident recorded during a race session
+#[derive(Debug, Serialize)]
+pub struct Incident {
+    pub id: i32,
+    pub race_id: i32,
+    pub session_type: i16,
+    pub kind: IncidentKind,
+    pub session_time: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lap: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub steam_name: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub other_steam_name: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub penalty_type: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub infringement_type: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub penalty_time: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub safety_car_type: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub safety_car_event: Option<i16>,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ruling: Option<IncidentRuling>,
+}
+
+/// Represents the stewards ruling on an incident
+#[derive(Debug, Serialize)]
+pub struct IncidentRuling {
+    pub steward_id: i32,
+    pub decision: RulingDecision,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub penalty_time: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Box<str>>,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Represents a session setting that didn't match the championship rules
+#[derive(Debug, Serialize)]
+pub struct RuleDeviation {
+    pub session_type: i16,
+    pub rule: SessionRule,
+    pub expected: i16,
+    pub actual: i16,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Represents the evidence of a driver using an assist during a session
+#[derive(Debug, Serialize)]
+pub struct AssistEvidence {
+    pub session_type: i16,
+    pub steam_name: Box<str>,
+    pub assist: DriverAssist,
+    pub inferred: bool,
+    pub samples: i32,
+    pub total_samples: i32,
+    pub level: i16,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Represents the setup of a car as reported by the game
+#[derive(Debug, Default, Clone, Copy, Serialize, PartialEq)]
+pub struct CarSetup {
+    pub front_wing: i16,
+    pub rear_wing: i16,
+    pub on_throttle: i16,
+    pub off_throttle: i16,
+    pub front_camber: f32,
+    pub rear_camber: f32,
+    pub front_toe: f32,
+    pub rear_toe: f32,
+    pub front_suspension: i16,
+    pub rear_suspension: i16,
+    pub front_anti_roll_bar: i16,
+    pub rear_anti_roll_bar: i16,
+    pub front_suspension_height: i16,
+    pub rear_suspension_height: i16,
+    pub brake_pressure: i16,
+    pub brake_bias: i16,
+    pub engine_braking: i16,
+    pub rear_left_tyre_pressure: f32,
+    pub rear_right_tyre_pressure: f32,
+    pub front_left_tyre_pressure: f32,
+    pub front_right_tyre_pressure: f32,
+    pub ballast: i16,
+    pub fuel_load: f32,
+}
+
+/// Represents the setup a driver used in a session, kept to enforce parc fermé
+#[derive(Debug, Serialize)]
+pub struct SetupSnapshot {
+    pub session_type: i16,
+    pub steam_name: Box<str>,
+    #[serde(flatten)]
+    pub setup: CarSetup,
+    pub created_at: DateTime<Utc>,
+}
+
+impl YellowFlag {
+    /// Creates a YellowFlag from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        YellowFlag {
+            session_type: row.get(0),
+            zone_index: row.get(1),
+            zone_start: row.get(2),
+            started_session_time: row.get(3),
+            ended_session_time: row.get(4),
+            created_at: row.get(5),
+        }
+    }
+}
+
+impl Incident {
+    /// Creates an Incident from a database row, joined with its ruling
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        let ruling = row
+            .get::<_, Option<i32>>(14)
+            .map(|steward_id| IncidentRuling {
+                steward_id,
+                decision: row.get(15),
+                penalty_time: row.get(16),
+                notes: row.get(17),
+                created_at: row.get(18),
+                updated_at: row.get(19),
+            });
+
+        Incident {
+            id: row.get(0),
+            race_id: row.get(1),
+            session_type: row.get(2),
+            kind: row.get(3),
+            session_time: row.get(4),
+            lap: row.get(5),
+            steam_name: row.get(6),
+            other_steam_name: row.get(7),
+            penalty_type: row.get(8),
+            infringement_type: row.get(9),
+            penalty_time: row.get(10),
+            safety_car_type: row.get(11),
+            safety_car_event: row.get(12),
+            created_at: row.get(13),
+            ruling,
+        }
+    }
+}
+
+impl RuleDeviation {
+    /// Creates a RuleDeviation from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        RuleDeviation {
+            session_type: row.get(0),
+            rule: row.get(1),
+            expected: row.get(2),
+            actual: row.get(3),
+            created_at: row.get(4),
+        }
+    }
+}
+
+impl AssistEvidence {
+    /// Creates an AssistEvidence from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        AssistEvidence {
+            session_type: row.get(0),
+            steam_name: row.get(1),
+            assist: row.get(2),
+            inferred: row.get(3),
+            samples: row.get(4),
+            total_samples: row.get(5),
+            level: row.get(6),
+            created_at: row.get(7),
+        }
+    }
+}
+
+impl CarSetup {
+    /// Creates a CarSetup from the columns of a database row starting at `start`
+    #[inline]
+    pub fn from_row(row: &Row, start: usize) -> Self {
+        CarSetup {
+            front_wing: row.get(start),
+            rear_wing: row.get(start + 1),
+            on_throttle: row.get(start + 2),
+            off_throttle: row.get(start + 3),
+            front_camber: row.get(start + 4),
+            rear_camber: row.get(start + 5),
+            front_toe: row.get(start + 6),
+            rear_toe: row.get(start + 7),
+            front_suspension: row.get(start + 8),
+            rear_suspension: row.get(start + 9),
+            front_anti_roll_bar: row.get(start + 10),
+            rear_anti_roll_bar: row.get(start + 11),
+            front_suspension_height: row.get(start + 12),
+            rear_suspension_height: row.get(start + 13),
+            brake_pressure: row.get(start + 14),
+            brake_bias: row.get(start + 15),
+            engine_braking: row.get(start + 16),
+            rear_left_tyre_pressure: row.get(start + 17),
+            rear_right_tyre_pressure: row.get(start + 18),
+            front_left_tyre_pressure: row.get(start + 19),
+            front_right_tyre_pressure: row.get(start + 20),
+            ballast: row.get(start + 21),
+            fuel_load: row.get(start + 22),
+        }
+    }
+}
+
+impl SetupSnapshot {
+    /// Creates a SetupSnapshot from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        SetupSnapshot {
+            session_type: row.get(0),
+            steam_name: row.get(1),
+            setup: CarSetup::from_row(row, 2),
+            created_at: row.get(25),
+        }
+    }
+}
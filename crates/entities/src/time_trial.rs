@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use deadpool_postgres::tokio_postgres::Row;
+use postgres_derive::{FromSql, ToSql};
+use serde::Serialize;
+
+/// Lap reported by the game during a time trial session
+#[derive(Debug, Clone, Copy, Serialize, FromSql, ToSql, PartialEq)]
+#[postgres(name = "time_trial_lap_kind")]
+pub enum TimeTrialLapKind {
+    #[postgres(name = "SessionBest")]
+    SessionBest,
+    #[postgres(name = "PersonalBest")]
+    PersonalBest,
+}
+
+/// Represents a time trial lap set by a driver of a championship
+#[derive(Debug, Serialize)]
+pub struct TimeTrialLap {
+    pub steam_name: Box<str>,
+    pub track_id: i16,
+    pub kind: TimeTrialLapKind,
+    pub team_id: i16,
+    pub lap_time: i32,
+    pub sector1_time: i32,
+    pub sector2_time: i32,
+    pub sector3_time: i32,
+    pub traction_control: i16,
+    pub gearbox_assist: i16,
+    pub anti_lock_brakes: bool,
+    pub equal_car_performance: bool,
+    pub custom_setup: bool,
+    pub valid: bool,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl TimeTrialLap {
+    /// Creates a TimeTrialLap from a database row
+    #[inline]
+    pub fn from_row(row: &Row) -> Self {
+        TimeTrialLap {
+            steam_name: row.get(0),
+            track_id: row.get(1),
+            kind: row.get(2),
+            team_id: row.get(3),
+            lap_time: row.get(4),
+            sector1_time: row.get(5),
+            sector2_time: row.get(6),
+            sector3_time: row.get(7),
+            traction_control: row.get(8),
+            gearbox_assist: row.get(9),
+            anti_lock_brakes: row.get(10),
+            equal_car_performance: row.get(11),
+            custom_setup: row.get(12),
+            valid: row.get(13),
+            recorded_at: row.get(14),
+        }
+    }
+}
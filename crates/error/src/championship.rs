@@ -17,6 +17,14 @@ pub enum ChampionshipError {
     NoPortsAvailable,
     InvalidTeamId,
     NotAnEngineer,
+    NotAdmin,
+    NotRaceDirector,
+    TeamNotFound,
+    TeamAlreadyExists,
+    TeamHasHistory,
+    SeasonNotFound,
+    DivisionNotFound,
+    DivisionAlreadyExists,
 }
 
 impl std::error::Error for ChampionshipError {}
@@ -38,6 +46,14 @@ impl ChampionshipError {
             ChampionshipError::NoPortsAvailable => StatusCode::SERVICE_UNAVAILABLE,
             ChampionshipError::InvalidTeamId => StatusCode::BAD_REQUEST,
             ChampionshipError::NotAnEngineer => StatusCode::UNAUTHORIZED,
+            ChampionshipError::NotAdmin => StatusCode::UNAUTHORIZED,
+            ChampionshipError::NotRaceDirector => StatusCode::UNAUTHORIZED,
+            ChampionshipError::TeamNotFound => StatusCode::NOT_FOUND,
+            ChampionshipError::TeamAlreadyExists => StatusCode::CONFLICT,
+            ChampionshipError::TeamHasHistory => StatusCode::CONFLICT,
+            ChampionshipError::SeasonNotFound => StatusCode::NOT_FOUND,
+            ChampionshipError::DivisionNotFound => StatusCode::NOT_FOUND,
+            ChampionshipError::DivisionAlreadyExists => StatusCode::CONFLICT,
         }
     }
 
@@ -51,6 +67,14 @@ impl ChampionshipError {
             ChampionshipError::NoPortsAvailable => "No ports available",
             ChampionshipError::InvalidTeamId => "Invalid Team Id",
             ChampionshipError::NotAnEngineer => "Not an engineer",
+            ChampionshipError::NotAdmin => "Not an admin of Championship",
+            ChampionshipError::NotRaceDirector => "Not a race director of Championship",
+            ChampionshipError::TeamNotFound => "Team not found",
+            ChampionshipError::TeamAlreadyExists => "Team already exists",
+            ChampionshipError::TeamHasHistory => "Team has lineup history",
+            ChampionshipError::SeasonNotFound => "Season not found",
+            ChampionshipError::DivisionNotFound => "Division not found",
+            ChampionshipError::DivisionAlreadyExists => "Division already exists",
         }
     }
 }
@@ -11,18 +11,39 @@ use super::AppError;
 #[derive(Debug)]
 pub enum DriverError {
     AlreadyExists,
+    NotFound,
+    NotLinked,
+    AlreadyLinked,
+    UserAlreadyLinked,
+    ClaimNotFound,
+    NotInChampionship,
+    NoVerificationCode,
 }
 
 impl DriverError {
     pub const fn status_code(&self) -> StatusCode {
         match self {
             DriverError::AlreadyExists => StatusCode::CONFLICT,
+            DriverError::NotFound => StatusCode::NOT_FOUND,
+            DriverError::NotLinked => StatusCode::NOT_FOUND,
+            DriverError::AlreadyLinked => StatusCode::CONFLICT,
+            DriverError::UserAlreadyLinked => StatusCode::CONFLICT,
+            DriverError::ClaimNotFound => StatusCode::NOT_FOUND,
+            DriverError::NotInChampionship => StatusCode::BAD_REQUEST,
+            DriverError::NoVerificationCode => StatusCode::CONFLICT,
         }
     }
 
     pub const fn error_message(&self) -> &'static str {
         match self {
             DriverError::AlreadyExists => "Driver already exists",
+            DriverError::NotFound => "Driver not found",
+            DriverError::NotLinked => "No driver linked to the user",
+            DriverError::AlreadyLinked => "Driver already linked to a user",
+            DriverError::UserAlreadyLinked => "User already linked to a driver",
+            DriverError::ClaimNotFound => "Pending driver claim not found",
+            DriverError::NotInChampionship => "Driver not registered in the championship",
+            DriverError::NoVerificationCode => "No verification code available for the driver",
         }
     }
 }
@@ -3,6 +3,8 @@ mod common;
 mod driver;
 mod f1;
 mod firewall;
+mod race;
+mod steward;
 mod token;
 mod user;
 
@@ -22,6 +24,8 @@ pub use common::*;
 pub use driver::*;
 pub use f1::*;
 pub use firewall::*;
+pub use race::*;
+pub use steward::*;
 pub use token::*;
 pub use user::*;
 
@@ -37,6 +41,8 @@ pub enum AppError {
     Common(CommonError),
     F1(F1ServiceError),
     Firewall(FirewallError),
+    Race(RaceError),
+    Steward(StewardError),
     Twilight,
     PgError,
     PgPool,
@@ -103,6 +109,8 @@ impl AppError {
             AppError::Common(e) => e.status_code(),
             AppError::F1(e) => e.status_code(),
             AppError::Firewall(e) => e.status_code(),
+            AppError::Race(e) => e.status_code(),
+            AppError::Steward(e) => e.status_code(),
             AppError::PgError => StatusCode::INTERNAL_SERVER_ERROR,
             AppError::PgPool => StatusCode::INTERNAL_SERVER_ERROR,
             AppError::Reqwest => StatusCode::INTERNAL_SERVER_ERROR,
@@ -120,6 +128,8 @@ impl AppError {
             AppError::Common(e) => e.error_message(),
             AppError::F1(e) => e.error_message(),
             AppError::Firewall(e) => e.error_message(),
+            AppError::Race(e) => e.error_message(),
+            AppError::Steward(e) => e.error_message(),
             AppError::PgError => "Database error",
             AppError::PgPool => "Pool error",
             AppError::Reqwest => "Reqwest error",
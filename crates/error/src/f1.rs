@@ -9,6 +9,7 @@ pub enum F1ServiceError {
     CastingError,
     Shutdown,
     UnsupportedFormat,
+    NotInSession,
 }
 
 impl F1ServiceError {
@@ -19,6 +20,7 @@ impl F1ServiceError {
             F1ServiceError::Shutdown => StatusCode::INTERNAL_SERVER_ERROR,
             F1ServiceError::CastingError => StatusCode::INTERNAL_SERVER_ERROR,
             F1ServiceError::UnsupportedFormat => StatusCode::BAD_REQUEST,
+            F1ServiceError::NotInSession => StatusCode::NOT_FOUND,
         }
     }
 
@@ -29,6 +31,7 @@ impl F1ServiceError {
             F1ServiceError::Shutdown => "Error shutting down service",
             F1ServiceError::CastingError => "Error casting data",
             F1ServiceError::UnsupportedFormat => "Unsupported udp format",
+            F1ServiceError::NotInSession => "Driver not in session",
         }
     }
 }
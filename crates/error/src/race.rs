@@ -0,0 +1,52 @@
+use ntex::http::StatusCode;
+
+use super::AppError;
+
+#[derive(Debug)]
+pub enum RaceError {
+    NotFound,
+    TrackOutlineNotFound,
+    LapTraceNotFound,
+    NotRegular,
+    AlreadyEntered,
+    SubstitutionNotFound,
+}
+
+impl RaceError {
+    pub const fn status_code(&self) -> StatusCode {
+        match self {
+            RaceError::NotFound => StatusCode::NOT_FOUND,
+            RaceError::TrackOutlineNotFound => StatusCode::NOT_FOUND,
+            RaceError::LapTraceNotFound => StatusCode::NOT_FOUND,
+            RaceError::NotRegular => StatusCode::BAD_REQUEST,
+            RaceError::AlreadyEntered => StatusCode::CONFLICT,
+            RaceError::SubstitutionNotFound => StatusCode::NOT_FOUND,
+        }
+    }
+
+    pub const fn error_message(&self) -> &'static str {
+        match self {
+            RaceError::NotFound => "Race not found",
+            RaceError::TrackOutlineNotFound => "Track outline not recorded yet",
+            RaceError::LapTraceNotFound => "Lap trace not found",
+            RaceError::NotRegular => "Driver is not a regular of the championship",
+            RaceError::AlreadyEntered => "Driver already entered in the race",
+            RaceError::SubstitutionNotFound => "Substitution not found",
+        }
+    }
+}
+
+impl std::error::Error for RaceError {}
+
+impl std::fmt::Display for RaceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error_message())
+    }
+}
+
+impl From<RaceError> for AppError {
+    #[inline]
+    fn from(e: RaceError) -> Self {
+        AppError::Race(e)
+    }
+}
@@ -0,0 +1,49 @@
+use ntex::http::StatusCode;
+
+use super::AppError;
+
+#[derive(Debug)]
+pub enum StewardError {
+    IncidentNotFound,
+    ResultNotFound,
+    NotClassified,
+    MissingPenaltyTime,
+    NotRaceSession,
+}
+
+impl StewardError {
+    pub const fn status_code(&self) -> StatusCode {
+        match self {
+            StewardError::IncidentNotFound => StatusCode::NOT_FOUND,
+            StewardError::ResultNotFound => StatusCode::NOT_FOUND,
+            StewardError::NotClassified => StatusCode::BAD_REQUEST,
+            StewardError::MissingPenaltyTime => StatusCode::BAD_REQUEST,
+            StewardError::NotRaceSession => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    pub const fn error_message(&self) -> &'static str {
+        match self {
+            StewardError::IncidentNotFound => "Incident not found",
+            StewardError::ResultNotFound => "Result not found",
+            StewardError::NotClassified => "Driver not classified in this result",
+            StewardError::MissingPenaltyTime => "Time penalty without seconds",
+            StewardError::NotRaceSession => "Time penalties only apply to race results",
+        }
+    }
+}
+
+impl std::error::Error for StewardError {}
+
+impl std::fmt::Display for StewardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error_message())
+    }
+}
+
+impl From<StewardError> for AppError {
+    #[inline]
+    fn from(e: StewardError) -> Self {
+        AppError::Steward(e)
+    }
+}
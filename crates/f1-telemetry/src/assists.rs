@@ -0,0 +1,276 @@
+use entities::DriverAssist;
+
+use crate::types::{CarStatusData, CarTelemetryData, PacketSessionData};
+
+// Constants
+const MAX_CARS: usize = 22;
+const MAX_SHIFTS: usize = 500;
+const MIN_SHIFTS: usize = 30;
+const SHIFT_RPM_TOLERANCE: u16 = 150;
+const CONSISTENT_SHIFTS_PERCENT: usize = 90;
+const MIN_BRAKING_SAMPLES: u32 = 200;
+const OVERLAP_PERCENT: u32 = 15;
+const BRAKE_THRESHOLD: f32 = 0.2;
+const THROTTLE_THRESHOLD: f32 = 0.8;
+const AUTOMATIC_GEARBOX: u8 = 3;
+
+/// An assist a driver used or likely used during the session
+#[derive(Debug, PartialEq)]
+pub struct DetectedAssist {
+    pub vehicle_idx: u8,
+    pub assist: DriverAssist,
+    /// Whether the assist was deduced from the driving pattern instead of read from the game,
+    /// inferred assists are only hints as drivers without the assist can drive the same way
+    pub inferred: bool,
+    pub samples: u32,
+    pub total_samples: u32,
+    pub level: u8,
+}
+
+/// Counts the samples in which the game reported an assist as enabled
+#[derive(Debug, Default, Clone, Copy)]
+struct AssistCounter {
+    samples: u32,
+    total_samples: u32,
+    level: u8,
+}
+
+/// Signals gathered for a single car
+#[derive(Debug, Default)]
+struct CarAssists {
+    traction_control: AssistCounter,
+    anti_lock_brakes: AssistCounter,
+    steering_assist: AssistCounter,
+    braking_assist: AssistCounter,
+    automatic_gearbox: AssistCounter,
+    braking_samples: u32,
+    overlap_samples: u32,
+    last_gear: i8,
+    last_rpm: u16,
+    shift_rpms: Vec<u16>,
+}
+
+/// Collects the assist usage of every car during a session
+#[derive(Debug)]
+pub struct AssistComplianceTracker {
+    cars: Vec<CarAssists>,
+}
+
+impl Default for AssistComplianceTracker {
+    fn default() -> Self {
+        Self {
+            cars: (0..MAX_CARS).map(|_| CarAssists::default()).collect(),
+        }
+    }
+}
+
+impl AssistCounter {
+    #[inline]
+    fn record(&mut self, level: u8) {
+        self.total_samples += 1;
+
+        if level > 0 {
+            self.samples += 1;
+            self.level = self.level.max(level);
+        }
+    }
+}
+
+impl AssistComplianceTracker {
+    /// Records the traction control and ABS settings reported for every car
+    pub fn update_status(&mut self, status: &[CarStatusData]) {
+        for (car, data) in self.cars.iter_mut().zip(status) {
+            if data.max_rpm == 0 {
+                continue;
+            }
+
+            car.traction_control.record(data.traction_control);
+            car.anti_lock_brakes.record(data.anti_lock_brakes);
+        }
+    }
+
+    /// Records the assists of the player car, the only one reported by the session settings
+    pub fn update_session(&mut self, packet: &PacketSessionData) {
+        let Some(car) = self.cars.get_mut(packet.header.player_car_index as usize) else {
+            return;
+        };
+
+        car.steering_assist.record(packet.steering_assist);
+        car.braking_assist.record(packet.braking_assist);
+        car.automatic_gearbox
+            .record((packet.gearbox_assist == AUTOMATIC_GEARBOX) as u8);
+    }
+
+    /// Looks for braking and gear shifting patterns in the inputs of every car
+    pub fn update_telemetry(&mut self, telemetry: &[CarTelemetryData]) {
+        for (car, data) in self.cars.iter_mut().zip(telemetry) {
+            let (brake, throttle, gear, rpm) =
+                (data.brake, data.throttle, data.gear, data.engine_rpm);
+
+            if brake > BRAKE_THRESHOLD {
+                car.braking_samples += 1;
+
+                // The braking assist applies the brakes while the throttle is still pressed
+                if throttle > THROTTLE_THRESHOLD {
+                    car.overlap_samples += 1;
+                }
+            }
+
+            if car.last_gear >= 1 && gear == car.last_gear + 1 && car.shift_rpms.len() < MAX_SHIFTS
+            {
+                car.shift_rpms.push(car.last_rpm);
+            }
+
+            car.last_gear = gear;
+            car.last_rpm = rpm;
+        }
+    }
+
+    /// Returns the assists used by every car, preferring the values reported by the game
+    /// over the ones inferred from the driving patterns
+    pub fn detected(&self) -> Vec<DetectedAssist> {
+        let mut detected = Vec::new();
+
+        for (idx, car) in self.cars.iter().enumerate() {
+            let vehicle_idx = idx as u8;
+
+            let reported = [
+                (DriverAssist::TractionControl, car.traction_control),
+                (DriverAssist::AntiLockBrakes, car.anti_lock_brakes),
+                (DriverAssist::SteeringAssist, car.steering_assist),
+                (DriverAssist::BrakingAssist, car.braking_assist),
+                (DriverAssist::AutomaticGearbox, car.automatic_gearbox),
+            ];
+
+            for (assist, counter) in reported {
+                if counter.samples > 0 {
+                    detected.push(DetectedAssist {
+                        vehicle_idx,
+                        assist,
+                        inferred: false,
+                        samples: counter.samples,
+                        total_samples: counter.total_samples,
+                        level: counter.level,
+                    });
+                }
+            }
+
+            if car.braking_assist.samples == 0
+                && car.braking_samples >= MIN_BRAKING_SAMPLES
+                && car.overlap_samples * 100 >= car.braking_samples * OVERLAP_PERCENT
+            {
+                detected.push(DetectedAssist {
+                    vehicle_idx,
+                    assist: DriverAssist::BrakingAssist,
+                    inferred: true,
+                    samples: car.overlap_samples,
+                    total_samples: car.braking_samples,
+                    level: 0,
+                });
+            }
+
+            if car.automatic_gearbox.samples == 0 {
+                if let Some(consistent) = consistent_shifts(&car.shift_rpms) {
+                    detected.push(DetectedAssist {
+                        vehicle_idx,
+                        assist: DriverAssist::AutomaticGearbox,
+                        inferred: true,
+                        samples: consistent as u32,
+                        total_samples: car.shift_rpms.len() as u32,
+                        level: 0,
+                    });
+                }
+            }
+        }
+
+        detected
+    }
+}
+
+/// The automatic gearbox upshifts at almost the same engine speed every time, returns the
+/// number of upshifts close to the median when they are consistent enough. A manual driver
+/// shifting on the lights is just as consistent, so this is never more than a hint
+fn consistent_shifts(shift_rpms: &[u16]) -> Option<usize> {
+    if shift_rpms.len() < MIN_SHIFTS {
+        return None;
+    }
+
+    let mut sorted = shift_rpms.to_vec();
+    sorted.sort_unstable();
+    let median = sorted[sorted.len() / 2];
+
+    let consistent = sorted
+        .iter()
+        .filter(|rpm| rpm.abs_diff(median) <= SHIFT_RPM_TOLERANCE)
+        .count();
+
+    (consistent * 100 >= sorted.len() * CONSISTENT_SHIFTS_PERCENT).then_some(consistent)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::*;
+
+    fn telemetry(brake: f32, throttle: f32, gear: i8, rpm: u16) -> Vec<CarTelemetryData> {
+        // SAFETY: every field of the telemetry is a plain integer or float
+        let mut data: CarTelemetryData = unsafe { mem::zeroed() };
+
+        data.brake = brake;
+        data.throttle = throttle;
+        data.gear = gear;
+        data.engine_rpm = rpm;
+        vec![data]
+    }
+
+    fn shift_through_gears(tracker: &mut AssistComplianceTracker, shift_rpm: impl Fn(u32) -> u16) {
+        for lap in 0..10 {
+            for gear in 1..=7 {
+                let rpm = shift_rpm(lap * 7 + gear as u32);
+                tracker.update_telemetry(&telemetry(0.0, 1.0, gear, rpm));
+            }
+
+            tracker.update_telemetry(&telemetry(0.0, 1.0, 8, 11000));
+            tracker.update_telemetry(&telemetry(0.0, 0.0, 1, 6000));
+        }
+    }
+
+    #[test]
+    fn consistent_upshifts_look_like_automatic_gearbox() {
+        let mut tracker = AssistComplianceTracker::default();
+        shift_through_gears(&mut tracker, |shift| 11800 + (shift % 3) as u16 * 20);
+
+        let detected = tracker.detected();
+
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].assist, DriverAssist::AutomaticGearbox);
+        assert!(detected[0].inferred);
+        assert_eq!(detected[0].total_samples, 70);
+    }
+
+    #[test]
+    fn varied_upshifts_are_not_flagged() {
+        let mut tracker = AssistComplianceTracker::default();
+        shift_through_gears(&mut tracker, |shift| 10500 + (shift % 7) as u16 * 250);
+
+        assert!(tracker.detected().is_empty());
+    }
+
+    #[test]
+    fn braking_with_full_throttle_looks_like_braking_assist() {
+        let mut tracker = AssistComplianceTracker::default();
+
+        for sample in 0..300 {
+            let throttle = if sample % 4 == 0 { 1.0 } else { 0.0 };
+            tracker.update_telemetry(&telemetry(0.9, throttle, 3, 9000));
+        }
+
+        let detected = tracker.detected();
+
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].assist, DriverAssist::BrakingAssist);
+        assert_eq!(detected[0].samples, 75);
+        assert_eq!(detected[0].total_samples, 300);
+    }
+}
@@ -1,12 +1,20 @@
+mod assists;
+mod damage;
 mod firewall;
+mod flags;
 mod handler;
+mod lap_trace;
 mod live_service;
+mod lobby;
+mod rules;
+mod strategy;
+mod track_map;
 mod types;
 
 use dashmap::DashMap;
 use intelli_core::{
-    repositories::{ChampionshipRepository, DriverRepository},
-    services::{ChampionshipService, DriverService},
+    repositories::{ChampionshipRepository, DriverRepository, RaceRepository},
+    services::{ChampionshipService, DriverService, RaceService, StewardService},
 };
 use ntex::util::Bytes;
 use tokio::sync::{
@@ -37,6 +45,9 @@ pub struct F1State {
     pub driver_repo: &'static DriverRepository,
     pub championship_repo: &'static ChampionshipRepository,
     pub championship_svc: &'static ChampionshipService,
+    pub race_repo: &'static RaceRepository,
+    pub race_svc: &'static RaceService,
+    pub steward_svc: &'static StewardService,
 }
 
 impl F1ChampionshipManager {
@@ -51,6 +62,23 @@ impl F1ChampionshipManager {
         self.services.get(championship_id)?.team_sub(team_id)
     }
 
+    /// Subscribes to the telemetry of a single driver of a specific championship service.
+    pub fn subscribe_driver(
+        &self,
+        championship_id: &i32,
+        steam_name: &str,
+    ) -> AppResult<Receiver<Bytes>> {
+        let Some(service) = self.services.get(championship_id) else {
+            Err(F1ServiceError::NotActive)?
+        };
+
+        let Some(receiver) = service.driver_sub(steam_name) else {
+            Err(F1ServiceError::NotInSession)?
+        };
+
+        Ok(receiver)
+    }
+
     /// Retrieves cache and subscribes to a channel for a specific championship service.
     pub fn cache_and_subscribe(
         &self,
@@ -60,6 +88,30 @@ impl F1ChampionshipManager {
         Some((service.cache(), service.global_sub()))
     }
 
+    /// Retrieves the stewarding cache and subscribes to the stewarding feed of a championship service.
+    pub fn steward_cache_and_subscribe(
+        &self,
+        championship_id: &i32,
+    ) -> Option<(Option<Bytes>, Receiver<Bytes>)> {
+        Some(self.services.get(championship_id)?.steward_sub())
+    }
+
+    /// Retrieves the track outline and subscribes to the positions feed of a championship service.
+    pub fn positions_cache_and_subscribe(
+        &self,
+        championship_id: &i32,
+    ) -> Option<(Option<Bytes>, Receiver<Bytes>)> {
+        Some(self.services.get(championship_id)?.positions_sub())
+    }
+
+    /// Retrieves the race director state and subscribes to the race director feed of a championship service.
+    pub fn director_cache_and_subscribe(
+        &self,
+        championship_id: &i32,
+    ) -> Option<(Option<Bytes>, Receiver<Bytes>)> {
+        Some(self.services.get(championship_id)?.director_sub())
+    }
+
     /// Unsubscribes from a championship service.
     #[inline]
     pub fn unsubscribe(&self, championship_id: &i32) {
@@ -76,6 +128,38 @@ impl F1ChampionshipManager {
         }
     }
 
+    /// Unsubscribes from the telemetry of a single driver of a championship service.
+    #[inline]
+    pub fn unsubscribe_driver(&self, championship_id: &i32, steam_name: &str) {
+        if let Some(service) = self.services.get(championship_id) {
+            service.driver_unsub(steam_name);
+        }
+    }
+
+    /// Unsubscribes from the stewarding feed of a championship service.
+    #[inline]
+    pub fn unsubscribe_steward(&self, championship_id: &i32) {
+        if let Some(service) = self.services.get(championship_id) {
+            service.steward_unsub();
+        }
+    }
+
+    /// Unsubscribes from the positions feed of a championship service.
+    #[inline]
+    pub fn unsubscribe_positions(&self, championship_id: &i32) {
+        if let Some(service) = self.services.get(championship_id) {
+            service.positions_unsub();
+        }
+    }
+
+    /// Unsubscribes from the race director feed of a championship service.
+    #[inline]
+    pub fn unsubscribe_director(&self, championship_id: &i32) {
+        if let Some(service) = self.services.get(championship_id) {
+            service.director_unsub();
+        }
+    }
+
     /// Retrieves a list of all active service IDs.
     #[inline]
     pub fn services(&self) -> Vec<i32> {
@@ -90,6 +174,10 @@ impl F1ChampionshipManager {
                 active: true,
                 general_conn: service.global_count(),
                 engineer_conn: service.all_team_count(),
+                driver_conn: service.all_driver_count(),
+                steward_conn: service.steward_count(),
+                positions_conn: service.positions_count(),
+                director_conn: service.director_count(),
             })
             .unwrap_or_default()
     }
@@ -107,8 +195,7 @@ impl F1ChampionshipManager {
         let mut service =
             F1LiveTelemetryService::new(session_manager, orx, self.services, self.f1_state).await;
 
-        // TODO: give a real race_id
-        service.initialize(port, championship_id, 0).await?;
+        service.initialize(port, championship_id).await?;
 
         ntex::rt::spawn(async move { service.run().await });
 
@@ -142,11 +229,15 @@ impl F1ChampionshipManager {
 
 impl F1State {
     /// Creates a new F1State instance.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         driver_svc: &'static DriverService,
         driver_repo: &'static DriverRepository,
         championship_repo: &'static ChampionshipRepository,
         championship_svc: &'static ChampionshipService,
+        race_repo: &'static RaceRepository,
+        race_svc: &'static RaceService,
+        steward_svc: &'static StewardService,
         firewall: &'static FirewallService,
     ) -> Self {
         Self {
@@ -155,6 +246,9 @@ impl F1State {
             driver_repo,
             championship_repo,
             championship_svc,
+            race_repo,
+            race_svc,
+            steward_svc,
         }
     }
 }
@@ -0,0 +1,185 @@
+use entities::{DamageComponent, DamageThreshold};
+
+use crate::types::CarDamageData;
+
+// Constants
+const MAX_CARS: usize = 22;
+
+/// A component of a car that crossed the threshold set by the championship
+#[derive(Debug, PartialEq)]
+pub struct DamageAlert {
+    pub vehicle_idx: u8,
+    pub component: DamageComponent,
+    pub value: u8,
+    pub threshold: u8,
+}
+
+/// Current reading of a component, the worst corner for tyres and brakes
+#[inline]
+fn reading(component: DamageComponent, data: &CarDamageData) -> u8 {
+    let tyres_wear = data.tyres_wear;
+
+    match component {
+        DamageComponent::FrontWing => data
+            .front_left_wing_damage
+            .max(data.front_right_wing_damage),
+        DamageComponent::RearWing => data.rear_wing_damage,
+        DamageComponent::Floor => data.floor_damage,
+        DamageComponent::Diffuser => data.diffuser_damage,
+        DamageComponent::Sidepod => data.sidepod_damage,
+        DamageComponent::Gearbox => data.gear_box_damage,
+        DamageComponent::Engine => data.engine_damage,
+        DamageComponent::EngineIce => data.engine_ice_wear,
+        DamageComponent::EngineMguh => data.engine_mguh_wear,
+        DamageComponent::EngineMguk => data.engine_mguk_wear,
+        DamageComponent::EngineEs => data.engine_es_wear,
+        DamageComponent::EngineCe => data.engine_ce_wear,
+        DamageComponent::EngineTc => data.engine_tc_wear,
+        DamageComponent::Brakes => data.brakes_damage.into_iter().max().unwrap_or(0),
+        DamageComponent::TyreWear => tyres_wear.into_iter().fold(0f32, f32::max) as u8,
+        DamageComponent::TyreDamageSpike => data.tyres_damage.into_iter().max().unwrap_or(0),
+        DamageComponent::DrsFault => data.drs_fault,
+        DamageComponent::ErsFault => data.ers_fault,
+    }
+}
+
+/// Watches the damage of every car, alerting once every time a component crosses its threshold
+#[derive(Debug, Default)]
+pub struct DamageAlertTracker {
+    thresholds: Vec<(DamageComponent, u8)>,
+    /// Last reading of every threshold per car, empty until the first sample
+    readings: Vec<Vec<u8>>,
+}
+
+impl DamageAlertTracker {
+    /// Replaces the thresholds, forgetting the previous readings
+    pub fn set_thresholds(&mut self, thresholds: &[DamageThreshold]) {
+        self.thresholds = thresholds
+            .iter()
+            .map(|threshold| match threshold.component {
+                DamageComponent::DrsFault | DamageComponent::ErsFault => (threshold.component, 1),
+                component => (component, threshold.value.clamp(1, 100) as u8),
+            })
+            .collect();
+        self.readings = vec![Vec::new(); MAX_CARS];
+    }
+
+    /// Compares the damage of every car with the last reading, returning the new alerts
+    pub fn update(&mut self, damage: &[CarDamageData]) -> Vec<DamageAlert> {
+        let mut alerts = Vec::new();
+
+        if self.thresholds.is_empty() {
+            return alerts;
+        }
+
+        for (idx, (readings, data)) in self.readings.iter_mut().zip(damage).enumerate() {
+            let current: Vec<u8> = self
+                .thresholds
+                .iter()
+                .map(|&(component, _)| reading(component, data))
+                .collect();
+
+            if !readings.is_empty() {
+                for ((&(component, threshold), &value), &last) in
+                    self.thresholds.iter().zip(&current).zip(readings.iter())
+                {
+                    let crossed = match component {
+                        DamageComponent::TyreDamageSpike => value >= last.saturating_add(threshold),
+                        _ => value >= threshold && last < threshold,
+                    };
+
+                    if crossed {
+                        alerts.push(DamageAlert {
+                            vehicle_idx: idx as u8,
+                            component,
+                            value,
+                            threshold,
+                        });
+                    }
+                }
+            }
+
+            *readings = current;
+        }
+
+        alerts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::*;
+
+    fn damage(front_wing: u8, tyre_damage: u8, drs_fault: u8) -> CarDamageData {
+        // SAFETY: every field of the damage data is a plain number or array
+        let mut data: CarDamageData = unsafe { mem::zeroed() };
+        data.front_right_wing_damage = front_wing;
+        data.tyres_damage = [0, tyre_damage, 0, 0];
+        data.drs_fault = drs_fault;
+        data
+    }
+
+    fn tracker() -> DamageAlertTracker {
+        let mut tracker = DamageAlertTracker::default();
+        tracker.set_thresholds(&[
+            DamageThreshold {
+                component: DamageComponent::FrontWing,
+                value: 30,
+            },
+            DamageThreshold {
+                component: DamageComponent::TyreDamageSpike,
+                value: 10,
+            },
+            DamageThreshold {
+                component: DamageComponent::DrsFault,
+                value: 50,
+            },
+        ]);
+        tracker
+    }
+
+    #[test]
+    fn alerts_once_per_crossing() {
+        let mut tracker = tracker();
+
+        // Damage already there when the tracking starts is not an alert
+        assert!(tracker.update(&[damage(40, 0, 0)]).is_empty());
+        assert!(tracker.update(&[damage(10, 0, 0)]).is_empty());
+
+        let alerts = tracker.update(&[damage(35, 0, 1)]);
+        assert_eq!(
+            alerts,
+            vec![
+                DamageAlert {
+                    vehicle_idx: 0,
+                    component: DamageComponent::FrontWing,
+                    value: 35,
+                    threshold: 30,
+                },
+                DamageAlert {
+                    vehicle_idx: 0,
+                    component: DamageComponent::DrsFault,
+                    value: 1,
+                    threshold: 1,
+                },
+            ]
+        );
+
+        assert!(tracker.update(&[damage(50, 0, 1)]).is_empty());
+    }
+
+    #[test]
+    fn alerts_tyre_damage_spikes() {
+        let mut tracker = tracker();
+
+        tracker.update(&[damage(0, 5, 0)]);
+        assert!(tracker.update(&[damage(0, 12, 0)]).is_empty());
+
+        let alerts = tracker.update(&[damage(0, 30, 0)]);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].component, DamageComponent::TyreDamageSpike);
+        assert_eq!(alerts[0].value, 30);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::types::PacketSessionData;
+
+// Constants
+const MAX_MARSHAL_ZONES: usize = 21;
+const YELLOW_FLAG: i8 = 3;
+
+/// A period of time in which a marshal zone showed a yellow flag
+#[derive(Debug, PartialEq)]
+pub struct YellowFlagPeriod {
+    pub zone_index: u8,
+    pub zone_start: f32,
+    pub started_at: f32,
+    pub ended_at: f32,
+}
+
+/// Follows the flag of every marshal zone to detect when yellow flags start and end
+#[derive(Debug, Default)]
+pub struct MarshalZoneTracker {
+    yellow_since: [Option<f32>; MAX_MARSHAL_ZONES],
+    last_session_time: f32,
+}
+
+impl MarshalZoneTracker {
+    /// Updates the zones with a new session packet, returning the yellow flag periods that ended
+    pub fn update(&mut self, packet: &PacketSessionData) -> Vec<YellowFlagPeriod> {
+        let session_time = packet.header.session_time;
+        let mut finished = Vec::new();
+
+        // A flashback or a restart moves the session time back, open periods are not valid anymore
+        if session_time < self.last_session_time {
+            self.yellow_since = [None; MAX_MARSHAL_ZONES];
+        }
+
+        self.last_session_time = session_time;
+
+        let num_zones = (packet.num_marshal_zones as usize).min(MAX_MARSHAL_ZONES);
+
+        for (idx, yellow_since) in self.yellow_since.iter_mut().enumerate() {
+            let zone = &packet.marshal_zones[idx];
+            let is_yellow = idx < num_zones && zone.zone_flag == YELLOW_FLAG;
+
+            match (*yellow_since, is_yellow) {
+                (None, true) => *yellow_since = Some(session_time),
+
+                (Some(started_at), false) => {
+                    finished.push(YellowFlagPeriod {
+                        zone_index: idx as u8,
+                        zone_start: zone.zone_start,
+                        started_at,
+                        ended_at: session_time,
+                    });
+
+                    *yellow_since = None;
+                }
+
+                _ => {}
+            }
+        }
+
+        finished
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::*;
+
+    fn session_packet(session_time: f32, flags: &[i8]) -> Box<PacketSessionData> {
+        // SAFETY: every field of the packet is a plain integer or float
+        let mut packet: Box<PacketSessionData> = Box::new(unsafe { mem::zeroed() });
+
+        packet.header.session_time = session_time;
+        packet.num_marshal_zones = flags.len() as u8;
+
+        for (idx, &flag) in flags.iter().enumerate() {
+            packet.marshal_zones[idx].zone_start = idx as f32 / flags.len() as f32;
+            packet.marshal_zones[idx].zone_flag = flag;
+        }
+
+        packet
+    }
+
+    #[test]
+    fn records_yellow_flag_periods() {
+        let mut tracker = MarshalZoneTracker::default();
+
+        assert!(tracker.update(&session_packet(10.0, &[1, 1])).is_empty());
+        assert!(tracker.update(&session_packet(12.0, &[1, 3])).is_empty());
+        assert!(tracker.update(&session_packet(15.0, &[1, 3])).is_empty());
+
+        let finished = tracker.update(&session_packet(20.0, &[1, 1]));
+
+        assert_eq!(
+            finished,
+            vec![YellowFlagPeriod {
+                zone_index: 1,
+                zone_start: 0.5,
+                started_at: 12.0,
+                ended_at: 20.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn discards_open_periods_after_flashback() {
+        let mut tracker = MarshalZoneTracker::default();
+
+        tracker.update(&session_packet(30.0, &[3]));
+        tracker.update(&session_packet(25.0, &[1]));
+
+        assert!(tracker.update(&session_packet(26.0, &[1])).is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-use std::{ops::Deref, sync::Arc, time::Duration};
+use std::{mem, ops::Deref, sync::Arc, time::Duration};
 
 use ahash::{AHashMap, AHashSet};
 use ntex::{
@@ -13,15 +13,28 @@ use tokio::sync::{
 };
 use tracing::{error, warn};
 
-use crate::types::{
-    CarDamageData as F1CarDamageData, CarMotionData as F1CarMotionData,
-    CarStatusData as F1CarStatusData, CarTelemetryData as F1CarTelemetryData, EventCode,
-    EventDataDetails as F1EventDataDetails, FinalClassificationData as F1FinalClassificationData,
-    LapHistoryData as F1LapHistoryData, PacketCarDamageData, PacketCarStatusData,
-    PacketCarTelemetryData, PacketEventData, PacketEventData as F1PacketEventData,
-    PacketFinalClassificationData, PacketMotionData, PacketParticipantsData, PacketSessionData,
-    PacketSessionHistoryData, ParticipantData as F1ParticipantData,
-    TyreStintHistoryData as F1TyreStintHistoryData,
+use entities::CarSetup;
+use structs::{EngineWearData, IncidentData, RuleDeviationData, TrackOutlineData};
+
+use crate::{
+    damage::DamageAlert as F1DamageAlert,
+    lobby::LobbyEntry,
+    strategy::{PitPrediction, PitStrategyPredictor},
+    track_map::{
+        quantize_g_force, quantize_heading, quantize_position, TrackOutlineBuilder, OUTLINE_STEP,
+    },
+    types::{
+        CarDamageData as F1CarDamageData, CarMotionData as F1CarMotionData,
+        CarSetupData as F1CarSetupData, CarStatusData as F1CarStatusData,
+        CarTelemetryData as F1CarTelemetryData, EventCode, EventDataDetails as F1EventDataDetails,
+        FinalClassificationData as F1FinalClassificationData, LapHistoryData as F1LapHistoryData,
+        PacketCarDamageData, PacketCarSetupData, PacketCarStatusData, PacketCarTelemetryData,
+        PacketEventData, PacketEventData as F1PacketEventData, PacketFinalClassificationData,
+        PacketLapData, PacketMotionData, PacketMotionExData, PacketParticipantsData,
+        PacketSessionData, PacketSessionHistoryData, PacketTyreSetsData,
+        ParticipantData as F1ParticipantData, TyreSetData as F1TyreSetData,
+        TyreStintHistoryData as F1TyreStintHistoryData,
+    },
 };
 
 use event_data_details::Details;
@@ -44,26 +57,61 @@ const NOT_SEND_EVENTS: [EventCode; 9] = [
 include!(concat!(env!("OUT_DIR"), "/f1telemetry.rs"));
 
 // Structs
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DriverInfo {
     pub name: Box<str>,
     pub team_id: u8,
 }
 
+/// League team names shown instead of the game teams, by driver and by game team
+#[derive(Debug, Default)]
+pub struct LeagueTeamNames {
+    pub drivers: AHashMap<Box<str>, Box<str>>,
+    pub game_teams: AHashMap<u8, Box<str>>,
+}
+
 #[derive(Clone)]
 pub struct F1TelemetryPacketHandler {
     inner: Arc<F1TelemetryPacketHandlerInner>,
 }
 
+/// Last lap distance of every car, used to place the positions on the track outline
+#[derive(Debug, Default)]
+struct LapDistances {
+    distances: [f32; 22],
+    in_pits: [bool; 22],
+    invalid: [bool; 22],
+}
+
+/// Incidents of the service and what the race director feed still has to send
+#[derive(Debug, Default)]
+struct DirectorUpdates {
+    incidents: Vec<Incident>,
+    sent_incidents: usize,
+    rule_deviations_changed: bool,
+    last_session: Option<SessionData>,
+}
+
 #[derive(Debug)]
 pub struct F1TelemetryPacketHandlerInner {
     driver_info: RwLock<AHashMap<usize, DriverInfo>>,
+    league_teams: RwLock<LeagueTeamNames>,
     general: RwLock<F1GeneralInfo>,
     telemetry: RwLock<F1TelemetryInfo>,
     last_general: RwLock<F1GeneralInfo>,
     last_general_encoded: RwLock<Option<Bytes>>,
     last_telemetry: RwLock<F1TelemetryInfo>,
+    strategy: RwLock<PitStrategyPredictor>,
     team_senders: RwLock<AHashMap<u8, Sender<Bytes>>>,
+    driver_senders: RwLock<AHashMap<Box<str>, Sender<Bytes>>>,
+    stewarding: RwLock<StewardingInfo>,
+    steward_sender: Sender<Bytes>,
+    lap_distances: RwLock<LapDistances>,
+    track_outline: RwLock<TrackOutlineBuilder>,
+    positions_sender: Sender<Bytes>,
+    damage_alerts: Mutex<Vec<(u8, DamageAlert)>>,
+    director: Mutex<DirectorUpdates>,
+    director_sender: Sender<Bytes>,
     stop_sender: Mutex<Option<oneshot::Sender<()>>>,
 }
 
@@ -81,12 +129,23 @@ impl F1TelemetryPacketHandler {
     pub fn new(tx: Sender<Bytes>) -> Self {
         let inner = Arc::new(F1TelemetryPacketHandlerInner {
             driver_info: RwLock::new(AHashMap::new()),
+            league_teams: RwLock::new(LeagueTeamNames::default()),
             general: RwLock::new(F1GeneralInfo::default()),
             telemetry: RwLock::new(F1TelemetryInfo::default()),
             last_general: RwLock::new(F1GeneralInfo::default()),
             last_general_encoded: RwLock::new(None),
             last_telemetry: RwLock::new(F1TelemetryInfo::default()),
+            strategy: RwLock::new(PitStrategyPredictor::default()),
             team_senders: RwLock::new(AHashMap::new()),
+            driver_senders: RwLock::new(AHashMap::new()),
+            stewarding: RwLock::new(StewardingInfo::default()),
+            steward_sender: Sender::new(20),
+            lap_distances: RwLock::new(LapDistances::default()),
+            track_outline: RwLock::new(TrackOutlineBuilder::default()),
+            positions_sender: Sender::new(40),
+            damage_alerts: Mutex::new(Vec::new()),
+            director: Mutex::new(DirectorUpdates::default()),
+            director_sender: Sender::new(20),
             stop_sender: Mutex::new(None),
         });
 
@@ -109,6 +168,231 @@ impl F1TelemetryPacketHandler {
             .map(|sender| sender.subscribe())
     }
 
+    /// Gets a receiver for the telemetry of a single driver, if the driver is in the session
+    pub fn get_driver_receiver(&self, steam_name: &str) -> Option<Receiver<Bytes>> {
+        if !self
+            .telemetry
+            .read()
+            .player_telemetry
+            .contains_key(steam_name)
+        {
+            return None;
+        }
+
+        let receiver = self
+            .driver_senders
+            .write()
+            .entry(steam_name.into())
+            .or_insert_with(|| Sender::new(30))
+            .subscribe();
+
+        Some(receiver)
+    }
+
+    /// Removes the telemetry channel of a driver once nobody follows them
+    #[inline]
+    pub fn remove_driver_sender(&self, steam_name: &str) {
+        self.driver_senders.write().remove(steam_name);
+    }
+
+    /// Gets a receiver for the stewarding feed
+    #[inline]
+    pub fn get_steward_receiver(&self) -> Receiver<Bytes> {
+        self.steward_sender.subscribe()
+    }
+
+    /// Returns the current stewarding data, if anything was flagged
+    #[inline]
+    pub fn steward_cache(&self) -> Option<Bytes> {
+        let stewarding = self.stewarding.read();
+
+        if stewarding.rule_deviations.is_empty() {
+            return None;
+        }
+
+        Some(Bytes::from(stewarding.encode_to_vec()))
+    }
+
+    /// Replaces the rule deviations of a session and sends them on the stewarding feed
+    pub fn save_rule_deviations(&self, session_type: u8, deviations: &[RuleDeviationData]) {
+        let mut stewarding = self.stewarding.write();
+
+        stewarding
+            .rule_deviations
+            .retain(|deviation| deviation.session_type != session_type as u32);
+        stewarding
+            .rule_deviations
+            .extend(deviations.iter().map(RuleDeviation::from_data));
+
+        self.director.lock().rule_deviations_changed = true;
+
+        if self.steward_sender.receiver_count() > 0
+            && self
+                .steward_sender
+                .send(Bytes::from(stewarding.encode_to_vec()))
+                .is_err()
+        {
+            error!("Failed to send stewarding update");
+        }
+    }
+
+    /// Gets a receiver for the race director feed
+    #[inline]
+    pub fn get_director_receiver(&self) -> Receiver<Bytes> {
+        self.director_sender.subscribe()
+    }
+
+    /// Returns the full state of the race director feed, once the session is known
+    pub fn director_cache(&self) -> Option<Bytes> {
+        let general = self.general.read();
+        let session = general.session.clone()?;
+        let telemetry = self.telemetry.read();
+        let stewarding = self.stewarding.read();
+        let director = self.director.lock();
+
+        let info = DirectorInfo {
+            telemetry: Some(telemetry.clone()),
+            incidents: director.incidents.clone(),
+            rule_deviations: stewarding.rule_deviations.clone(),
+            session: Some(session),
+        };
+
+        Some(Bytes::from(info.encode_to_vec()))
+    }
+
+    /// Adds an incident to the race director feed
+    #[inline]
+    pub fn push_incident(&self, incident: &IncidentData) {
+        self.director.lock().incidents.push(Incident {
+            kind: incident.kind.as_str().to_owned(),
+            session_type: incident.session_type as u32,
+            session_time: incident.session_time,
+            lap: incident.lap.map(|lap| lap as u32),
+            steam_name: incident.steam_name.as_deref().map(str::to_owned),
+            other_steam_name: incident.other_steam_name.as_deref().map(str::to_owned),
+            penalty_type: incident.penalty_type.map(|value| value as u32),
+            infringement_type: incident.infringement_type.map(|value| value as u32),
+            penalty_time: incident.penalty_time.map(|value| value as u32),
+            safety_car_type: incident.safety_car_type.map(|value| value as u32),
+            safety_car_event: incident.safety_car_event.map(|value| value as u32),
+        });
+    }
+
+    /// Gets a receiver for the positions feed
+    #[inline]
+    pub fn get_positions_receiver(&self) -> Receiver<Bytes> {
+        self.positions_sender.subscribe()
+    }
+
+    /// Whether anyone is listening to the positions feed, nothing is tracked otherwise
+    #[inline]
+    pub fn has_positions_receivers(&self) -> bool {
+        self.positions_sender.receiver_count() > 0
+    }
+
+    /// Returns the track outline recorded so far
+    #[inline]
+    pub fn positions_cache(&self) -> Option<Bytes> {
+        let track_outline = self.track_outline.read();
+
+        if track_outline.is_empty() {
+            return None;
+        }
+
+        let positions = PositionsInfo {
+            cars: Vec::new(),
+            outline: Some(TrackOutline::from_builder(&track_outline)),
+        };
+
+        Some(Bytes::from(positions.encode_to_vec()))
+    }
+
+    /// Saves the lap distance of every car
+    #[inline]
+    pub fn save_lap_distances(&self, packet: &PacketLapData) {
+        let mut lap_distances = self.lap_distances.write();
+
+        for (idx, lap_data) in packet.lap_data.iter().enumerate() {
+            lap_distances.distances[idx] = lap_data.lap_distance;
+            lap_distances.in_pits[idx] = lap_data.pit_status != 0;
+            lap_distances.invalid[idx] = lap_data.current_lap_invalid != 0;
+        }
+    }
+
+    /// Adds the position of every car on a clean lap to the track outline, sending them on the
+    /// positions feed when anyone is listening
+    pub fn save_positions(&self, packet: &PacketMotionData) {
+        let lap_distances = self.lap_distances.read();
+        let mut track_outline = self.track_outline.write();
+        let mut completed = false;
+
+        let cars = packet
+            .car_motion_data
+            .iter()
+            .enumerate()
+            .filter(|(_, motion_data)| motion_data.world_position_x != 0f32)
+            .map(|(idx, motion_data)| {
+                if !lap_distances.invalid[idx] {
+                    completed |= track_outline.record(
+                        lap_distances.distances[idx],
+                        motion_data.world_position_x,
+                        motion_data.world_position_z,
+                        lap_distances.in_pits[idx],
+                    );
+                }
+
+                CarPosition::from_f1(idx, motion_data)
+            })
+            .collect();
+
+        let positions = PositionsInfo {
+            cars,
+            outline: completed.then(|| TrackOutline::from_builder(&track_outline)),
+        };
+
+        if self.has_positions_receivers()
+            && self
+                .positions_sender
+                .send(Bytes::from(positions.encode_to_vec()))
+                .is_err()
+        {
+            warn!("Failed to send positions update");
+        }
+    }
+
+    /// Replaces the league team names sent with the participants
+    #[inline]
+    pub fn set_league_teams(&self, names: LeagueTeamNames) {
+        *self.league_teams.write() = names;
+    }
+
+    /// Starts a new track outline when the track changes
+    #[inline]
+    pub fn reset_track_outline(&self, packet: &PacketSessionData) {
+        self.track_outline.write().reset(
+            packet.track_id,
+            packet.track_length,
+            packet.sector2_lap_distance_start,
+            packet.sector3_lap_distance_start,
+        );
+    }
+
+    /// Returns the normalized track outline once complete, holding it back while a car
+    /// is still driving through the pit lane or, if required, until the pit lane is recorded
+    pub fn track_outline(&self, require_pit_lane: bool) -> Option<TrackOutlineData> {
+        if self.lap_distances.read().in_pits.contains(&true) {
+            return None;
+        }
+
+        let track_outline = self.track_outline.read();
+
+        if require_pit_lane && !track_outline.has_pit_lane() {
+            return None;
+        }
+
+        track_outline.normalize()
+    }
+
     /// Pushes an event to the general data
     #[inline]
     pub fn push_event(&self, event: &PacketEventData) {
@@ -124,6 +408,28 @@ impl F1TelemetryPacketHandler {
         }
     }
 
+    /// Returns the steam name of the driver in the given car
+    #[inline]
+    pub fn driver_name(&self, vehicle_idx: u8) -> Option<Box<str>> {
+        let driver_info = self.driver_info.read();
+        driver_info
+            .get(&(vehicle_idx as usize))
+            .map(|driver| driver.name.clone())
+    }
+
+    /// Returns the driver in the given car
+    #[inline]
+    pub fn driver(&self, vehicle_idx: u8) -> Option<DriverInfo> {
+        let driver_info = self.driver_info.read();
+        driver_info.get(&(vehicle_idx as usize)).cloned()
+    }
+
+    /// Encodes the full general data, used to store session results
+    #[inline]
+    pub fn encode_general(&self) -> Vec<u8> {
+        self.general.read().encode_to_vec()
+    }
+
     /// Saves motion data
     #[inline]
     pub fn save_motion(&self, packet: &PacketMotionData) {
@@ -146,8 +452,52 @@ impl F1TelemetryPacketHandler {
     /// Saves session data
     #[inline]
     pub fn save_session(&self, packet: &PacketSessionData) {
+        {
+            let mut general = self.general.write();
+            general.update_session(packet);
+        }
+
+        self.strategy
+            .write()
+            .set_safety_car(packet.safety_car_status != 0);
+
+        let driver_info = self.driver_info.read();
+
+        if let Some(driver) = driver_info.get(&(packet.header.player_car_index as usize)) {
+            let mut telemetry = self.telemetry.write();
+            if let Some(player_telemetry) = telemetry.player_telemetry.get_mut(driver.name.as_ref())
+            {
+                player_telemetry.update_pit_window(packet);
+            }
+        }
+    }
+
+    /// Saves the flag state of every marshal zone
+    #[inline]
+    pub fn save_marshal_zones(&self, packet: &PacketSessionData) {
         let mut general = self.general.write();
-        general.update_session(packet);
+        general.update_marshal_zones(packet);
+    }
+
+    /// Saves lap data and refreshes the pit stop predictions of every car
+    #[inline]
+    pub fn save_lap_data(&self, packet: &PacketLapData) {
+        let driver_info = self.driver_info.read();
+        let mut strategy = self.strategy.write();
+        let mut telemetry = self.telemetry.write();
+
+        strategy.update(packet);
+
+        for (idx, driver) in driver_info.iter() {
+            let Some(prediction) = strategy.predict(*idx) else {
+                continue;
+            };
+
+            if let Some(player_telemetry) = telemetry.player_telemetry.get_mut(driver.name.as_ref())
+            {
+                player_telemetry.update_pit_strategy(&prediction);
+            }
+        }
     }
 
     /// Saves lap history data
@@ -163,9 +513,68 @@ impl F1TelemetryPacketHandler {
         }
     }
 
+    /// Saves the players waiting in the lobby for the pre-race view
+    #[inline]
+    pub fn save_lobby(&self, roster: &[LobbyEntry]) {
+        let mut general = self.general.write();
+        let lobby = general.lobby.get_or_insert_with(Default::default);
+
+        lobby.players.clear();
+        lobby
+            .players
+            .extend(roster.iter().map(LobbyPlayer::from_entry));
+    }
+
+    /// Saves the extended motion data of the player car, only needed when an engineer
+    /// of its team is subscribed
+    #[inline]
+    pub fn save_motion_ex(&self, packet: &PacketMotionExData) {
+        let driver_info = self.driver_info.read();
+
+        let Some(driver) = driver_info.get(&(packet.header.player_car_index as usize)) else {
+            return;
+        };
+
+        let has_engineer = self
+            .team_senders
+            .read()
+            .get(&driver.team_id)
+            .is_some_and(|sender| sender.receiver_count() > 0);
+
+        let has_driver = self
+            .driver_senders
+            .read()
+            .get(&driver.name)
+            .is_some_and(|sender| sender.receiver_count() > 0);
+
+        if !has_engineer && !has_driver && self.director_sender.receiver_count() == 0 {
+            return;
+        }
+
+        let mut telemetry = self.telemetry.write();
+        if let Some(player_telemetry) = telemetry.player_telemetry.get_mut(driver.name.as_ref()) {
+            player_telemetry.update_motion_ex(packet);
+        }
+    }
+
+    /// Saves the tyre sets available to a car
+    #[inline]
+    pub fn save_tyre_sets(&self, packet: &PacketTyreSetsData) {
+        let driver_info = self.driver_info.read();
+
+        if let Some(driver) = driver_info.get(&(packet.car_idx as usize)) {
+            let mut telemetry = self.telemetry.write();
+            if let Some(player_telemetry) = telemetry.player_telemetry.get_mut(driver.name.as_ref())
+            {
+                player_telemetry.update_tyre_sets(packet);
+            }
+        }
+    }
+
     /// Saves participants data
     #[inline]
     pub fn save_participants(&self, packet: &PacketParticipantsData) {
+        let league_teams = self.league_teams.read();
         let mut driver_info = self.driver_info.write();
         let mut general = self.general.write();
         let mut telemetry = self.telemetry.write();
@@ -191,13 +600,19 @@ impl F1TelemetryPacketHandler {
                 team_id: participant.team_id,
             });
 
+            let team_name = league_teams
+                .drivers
+                .get(steam_name)
+                .or_else(|| league_teams.game_teams.get(&participant.team_id))
+                .map(AsRef::as_ref);
+
             general
                 .players
                 .entry(steam_name.to_string())
-                .and_modify(|player| player.update_participant_info(participant))
+                .and_modify(|player| player.update_participant_info(i, participant, team_name))
                 .or_insert_with(|| {
                     let mut new_player = PlayerInfo::default();
-                    new_player.update_participant_info(participant);
+                    new_player.update_participant_info(i, participant, team_name);
                     telemetry
                         .player_telemetry
                         .entry(steam_name.to_string())
@@ -219,6 +634,28 @@ impl F1TelemetryPacketHandler {
         });
     }
 
+    /// Queues the damage alerts of human drivers, sent to their team with the next telemetry update
+    pub fn push_damage_alerts(&self, alerts: &[F1DamageAlert]) {
+        let driver_info = self.driver_info.read();
+        let mut damage_alerts = self.damage_alerts.lock();
+
+        for alert in alerts {
+            let Some(driver) = driver_info.get(&(alert.vehicle_idx as usize)) else {
+                continue;
+            };
+
+            damage_alerts.push((
+                driver.team_id,
+                DamageAlert {
+                    steam_name: driver.name.to_string(),
+                    component: alert.component.as_str().to_string(),
+                    value: alert.value as u32,
+                    threshold: alert.threshold as u32,
+                },
+            ));
+        }
+    }
+
     /// Saves car status data
     #[inline]
     pub fn save_car_status(&self, packet: &PacketCarStatusData) {
@@ -235,6 +672,56 @@ impl F1TelemetryPacketHandler {
         });
     }
 
+    /// Saves car setups data, other cars arrive blank in multiplayer sessions
+    #[inline]
+    pub fn save_car_setups(&self, packet: &PacketCarSetupData) {
+        self.process_telemetry_packet(&packet.car_setups, |player_telemetry, data| {
+            if data.brake_pressure != 0 {
+                player_telemetry.update_car_setup(data);
+            }
+        });
+    }
+
+    /// Returns the latest setup of every driver that shared it
+    pub fn car_setups(&self) -> Vec<(Box<str>, CarSetup)> {
+        let telemetry = self.telemetry.read();
+
+        telemetry
+            .player_telemetry
+            .iter()
+            .filter_map(|(steam_name, player_telemetry)| {
+                player_telemetry
+                    .car_setup
+                    .as_ref()
+                    .map(|&car_setup| (steam_name.as_str().into(), car_setup.to_entity()))
+            })
+            .collect()
+    }
+
+    /// Returns the last known wear of the power unit of every driver
+    pub fn engine_wear(&self, session_type: i16) -> Vec<EngineWearData> {
+        let telemetry = self.telemetry.read();
+
+        telemetry
+            .player_telemetry
+            .iter()
+            .filter_map(|(steam_name, player_telemetry)| {
+                let car_damage = player_telemetry.car_damage.as_ref()?;
+
+                Some(EngineWearData {
+                    session_type,
+                    steam_name: steam_name.as_str().into(),
+                    ice: car_damage.engine_ice_wear? as i16,
+                    mguh: car_damage.engine_mguh_wear? as i16,
+                    mguk: car_damage.engine_mguk_wear? as i16,
+                    es: car_damage.engine_es_wear? as i16,
+                    ce: car_damage.engine_ce_wear? as i16,
+                    tc: car_damage.engine_tc_wear? as i16,
+                })
+            })
+            .collect()
+    }
+
     /// Saves final classification data
     #[inline]
     pub fn save_final_classification(&self, packet: &PacketFinalClassificationData) {
@@ -325,8 +812,12 @@ impl F1TelemetryPacketHandler {
         let telemetry = inner.telemetry.read();
         let mut last_telemetry = inner.last_telemetry.write();
         let team_senders = inner.team_senders.read();
+        let driver_senders = inner.driver_senders.read();
 
         let mut team_updates: AHashMap<u8, F1TelemetryInfo> = AHashMap::new();
+        let mut driver_updates: AHashMap<&str, F1TelemetryInfo> = AHashMap::new();
+        let director_active = inner.director_sender.receiver_count() > 0;
+        let mut director_update = F1TelemetryInfo::default();
 
         let active_teams: AHashSet<u8> = team_senders
             .iter()
@@ -334,8 +825,17 @@ impl F1TelemetryPacketHandler {
             .map(|(&team_id, _)| team_id)
             .collect();
 
+        let active_drivers: AHashSet<&str> = driver_senders
+            .iter()
+            .filter(|(_, sender)| sender.receiver_count() > 0)
+            .map(|(steam_name, _)| steam_name.as_ref())
+            .collect();
+
         for (_, driver) in driver_info.iter() {
-            if active_teams.contains(&driver.team_id) {
+            let team_active = active_teams.contains(&driver.team_id);
+            let driver_active = active_drivers.contains(driver.name.as_ref());
+
+            if team_active || driver_active || director_active {
                 if let (Some(current_player_telemetry), Some(last_player_telemetry)) = (
                     telemetry.player_telemetry.get(driver.name.as_ref()),
                     last_telemetry.player_telemetry.get(driver.name.as_ref()),
@@ -343,16 +843,54 @@ impl F1TelemetryPacketHandler {
                     if let Some(diff_telemetry) =
                         Self::diff_player_telemetry(current_player_telemetry, last_player_telemetry)
                     {
-                        team_updates
-                            .entry(driver.team_id)
-                            .or_default()
-                            .player_telemetry
-                            .insert(driver.name.to_string(), diff_telemetry);
+                        if director_active {
+                            director_update
+                                .player_telemetry
+                                .insert(driver.name.to_string(), diff_telemetry.clone());
+                        }
+
+                        if driver_active {
+                            driver_updates
+                                .entry(driver.name.as_ref())
+                                .or_default()
+                                .player_telemetry
+                                .insert(driver.name.to_string(), diff_telemetry.clone());
+                        }
+
+                        if team_active {
+                            team_updates
+                                .entry(driver.team_id)
+                                .or_default()
+                                .player_telemetry
+                                .insert(driver.name.to_string(), diff_telemetry);
+                        }
                     }
                 }
             }
         }
 
+        for (team_id, alert) in inner.damage_alerts.lock().drain(..) {
+            if director_active {
+                director_update.damage_alerts.push(alert.clone());
+            }
+
+            if let Some(&steam_name) = active_drivers.get(alert.steam_name.as_str()) {
+                driver_updates
+                    .entry(steam_name)
+                    .or_default()
+                    .damage_alerts
+                    .push(alert.clone());
+            }
+
+            if active_teams.contains(&team_id) {
+                team_updates
+                    .entry(team_id)
+                    .or_default()
+                    .damage_alerts
+                    .push(alert);
+            }
+        }
+
         for (team_id, update) in team_updates {
             if let Some(sender) = team_senders.get(&team_id) {
                 let mut buf = BytesMut::with_capacity(update.encoded_len());
@@ -364,7 +902,72 @@ impl F1TelemetryPacketHandler {
             }
         }
 
+        for (steam_name, update) in driver_updates {
+            if let Some(sender) = driver_senders.get(steam_name) {
+                if sender.send(Bytes::from(update.encode_to_vec())).is_err() {
+                    error!("Failed to send telemetry update for driver {}", steam_name);
+                }
+            }
+        }
+
         *last_telemetry = telemetry.clone();
+
+        // The director update takes the general lock, which the director cache takes before
+        // the telemetry one, so holding these while waiting for it could deadlock
+        drop((
+            team_senders,
+            driver_senders,
+            last_telemetry,
+            telemetry,
+            driver_info,
+        ));
+
+        if director_active {
+            Self::send_director_update(inner, director_update);
+        }
+    }
+
+    /// Sends the race director the telemetry of every car with the incidents, rule
+    /// deviations and session changes since the last update
+    #[inline]
+    fn send_director_update(
+        inner: &Arc<F1TelemetryPacketHandlerInner>,
+        telemetry: F1TelemetryInfo,
+    ) {
+        let general = inner.general.read();
+        let stewarding = inner.stewarding.read();
+        let mut director = inner.director.lock();
+
+        let mut update = DirectorInfo {
+            incidents: director.incidents[director.sent_incidents..].to_vec(),
+            ..Default::default()
+        };
+        director.sent_incidents = director.incidents.len();
+
+        if !telemetry.player_telemetry.is_empty() || !telemetry.damage_alerts.is_empty() {
+            update.telemetry = Some(telemetry);
+        }
+
+        if mem::take(&mut director.rule_deviations_changed) {
+            update.rule_deviations = stewarding.rule_deviations.clone();
+        }
+
+        if general.session != director.last_session {
+            update.session = general.session.clone();
+            director.last_session = general.session.clone();
+        }
+
+        if update == DirectorInfo::default() {
+            return;
+        }
+
+        if inner
+            .director_sender
+            .send(Bytes::from(update.encode_to_vec()))
+            .is_err()
+        {
+            error!("Failed to send race director update");
+        }
     }
 
     /// Computes the difference between two F1GeneralInfo instances
@@ -451,6 +1054,24 @@ impl TyreStintsHistoryData {
     }
 }
 
+impl TyreSetData {
+    /// Creates a new TyreSetData from F1 data
+    #[inline]
+    fn from_f1(tyre_set: &F1TyreSetData) -> Self {
+        Self {
+            actual_tyre_compound: Some(tyre_set.actual_tyre_compound as u32),
+            visual_tyre_compound: Some(tyre_set.visual_tyre_compound as u32),
+            wear: Some(tyre_set.wear as u32),
+            available: Some(tyre_set.available == 1),
+            recommended_session: Some(tyre_set.recommended_session as u32),
+            life_span: Some(tyre_set.life_span as u32),
+            usable_life: Some(tyre_set.usable_life as u32),
+            lap_delta_time: Some(tyre_set.lap_delta_time as i32),
+            fitted: Some(tyre_set.fitted == 1),
+        }
+    }
+}
+
 impl LapHistoryData {
     /// Creates a new LapHistoryData from F1 data
     #[inline]
@@ -491,8 +1112,15 @@ impl PlayerInfo {
 
     /// Updates participant information for the player
     #[inline]
-    pub fn update_participant_info(&mut self, incoming_participant: &F1ParticipantData) {
+    pub fn update_participant_info(
+        &mut self,
+        car_idx: usize,
+        incoming_participant: &F1ParticipantData,
+        team_name: Option<&str>,
+    ) {
         let participant = self.participant.get_or_insert_with(Default::default);
+        participant.car_idx = Some(car_idx as u32);
+        participant.team_name = team_name.map(ToString::to_string);
         participant.team_id = Some(incoming_participant.team_id as u32);
         participant.race_number = Some(incoming_participant.race_number as u32);
         participant.nationality = Some(incoming_participant.nationality as u32);
@@ -650,6 +1278,115 @@ impl PlayerTelemetry {
             .extend_from_slice(unsafe { &tyres_pressure_ptr.read_unaligned() });
     }
 
+    /// Updates the pit stop prediction for the player
+    #[inline]
+    pub fn update_pit_strategy(&mut self, prediction: &PitPrediction) {
+        let pit_strategy = self.pit_strategy.get_or_insert_with(Default::default);
+
+        pit_strategy.pit_loss = Some(prediction.pit_loss);
+        pit_strategy.rejoin_position = Some(prediction.rejoin_position as u32);
+        pit_strategy.rejoin_gap_ahead = prediction.rejoin_gap_ahead;
+        pit_strategy.rejoin_gap_behind = prediction.rejoin_gap_behind;
+        pit_strategy.tyre_delta = Some(prediction.tyre_delta);
+        pit_strategy.undercut_gain = prediction.undercut_gain;
+        pit_strategy.overcut_margin = prediction.overcut_margin;
+    }
+
+    /// Updates car setup data for the player
+    #[inline]
+    pub fn update_car_setup(&mut self, data: &F1CarSetupData) {
+        let car_setup = self.car_setup.get_or_insert_with(Default::default);
+
+        car_setup.front_wing = Some(data.front_wing as u32);
+        car_setup.rear_wing = Some(data.rear_wing as u32);
+        car_setup.on_throttle = Some(data.on_throttle as u32);
+        car_setup.off_throttle = Some(data.off_throttle as u32);
+        car_setup.front_camber = Some(data.front_camber);
+        car_setup.rear_camber = Some(data.rear_camber);
+        car_setup.front_toe = Some(data.front_toe);
+        car_setup.rear_toe = Some(data.rear_toe);
+        car_setup.front_suspension = Some(data.front_suspension as u32);
+        car_setup.rear_suspension = Some(data.rear_suspension as u32);
+        car_setup.front_anti_roll_bar = Some(data.front_anti_roll_bar as u32);
+        car_setup.rear_anti_roll_bar = Some(data.rear_anti_roll_bar as u32);
+        car_setup.front_suspension_height = Some(data.front_suspension_height as u32);
+        car_setup.rear_suspension_height = Some(data.rear_suspension_height as u32);
+        car_setup.brake_pressure = Some(data.brake_pressure as u32);
+        car_setup.brake_bias = Some(data.brake_bias as u32);
+        car_setup.engine_braking = Some(data.engine_braking as u32);
+        car_setup.rear_left_tyre_pressure = Some(data.rear_left_tyre_pressure);
+        car_setup.rear_right_tyre_pressure = Some(data.rear_right_tyre_pressure);
+        car_setup.front_left_tyre_pressure = Some(data.front_left_tyre_pressure);
+        car_setup.front_right_tyre_pressure = Some(data.front_right_tyre_pressure);
+        car_setup.ballast = Some(data.ballast as u32);
+        car_setup.fuel_load = Some(data.fuel_load);
+    }
+
+    /// Updates the suspension and wheel data of the player
+    #[inline]
+    pub fn update_motion_ex(&mut self, packet: &PacketMotionExData) {
+        let motion_ex = self.motion_ex.get_or_insert_with(Default::default);
+
+        motion_ex.suspension_position.clear();
+
+        let suspension_position_ptr = &raw const packet.suspension_position;
+
+        motion_ex
+            .suspension_position
+            .extend_from_slice(unsafe { &suspension_position_ptr.read_unaligned() });
+
+        motion_ex.suspension_velocity.clear();
+
+        let suspension_velocity_ptr = &raw const packet.suspension_velocity;
+
+        motion_ex
+            .suspension_velocity
+            .extend_from_slice(unsafe { &suspension_velocity_ptr.read_unaligned() });
+
+        motion_ex.wheel_slip_ratio.clear();
+
+        let wheel_slip_ratio_ptr = &raw const packet.wheel_slip_ratio;
+
+        motion_ex
+            .wheel_slip_ratio
+            .extend_from_slice(unsafe { &wheel_slip_ratio_ptr.read_unaligned() });
+
+        motion_ex.wheel_slip_angle.clear();
+
+        let wheel_slip_angle_ptr = &raw const packet.wheel_slip_angle;
+
+        motion_ex
+            .wheel_slip_angle
+            .extend_from_slice(unsafe { &wheel_slip_angle_ptr.read_unaligned() });
+
+        motion_ex.local_velocity_x = Some(packet.local_velocity_x);
+        motion_ex.local_velocity_y = Some(packet.local_velocity_y);
+        motion_ex.local_velocity_z = Some(packet.local_velocity_z);
+        motion_ex.height_of_cog = Some(packet.height_of_cog_above_ground);
+    }
+
+    /// Updates the tyre sets available for the player
+    #[inline]
+    pub fn update_tyre_sets(&mut self, packet: &PacketTyreSetsData) {
+        let tyre_sets = self.tyre_sets.get_or_insert_with(Default::default);
+        tyre_sets.fitted_idx = Some(packet.fitted_idx as u32);
+
+        tyre_sets.tyre_sets.clear();
+        tyre_sets
+            .tyre_sets
+            .extend(packet.tyre_set_data.iter().map(TyreSetData::from_f1));
+    }
+
+    /// Updates the game pit window, only available for the player car
+    #[inline]
+    pub fn update_pit_window(&mut self, packet: &PacketSessionData) {
+        let pit_strategy = self.pit_strategy.get_or_insert_with(Default::default);
+
+        pit_strategy.pit_window_ideal_lap = Some(packet.pit_stop_window_ideal_lap as u32);
+        pit_strategy.pit_window_latest_lap = Some(packet.pit_stop_window_latest_lap as u32);
+        pit_strategy.game_rejoin_position = Some(packet.pit_stop_rejoin_position as u32);
+    }
+
     /// Computes the difference between two PlayerTelemetry instances
     #[inline]
     pub fn diff(&self, last: &Self) -> Option<Self> {
@@ -799,6 +1536,135 @@ impl PlayerTelemetry {
             has_changes = true;
         }
 
+        // Compare pit_strategy
+        if let (Some(cur_strategy), Some(last_strategy)) = (&self.pit_strategy, &last.pit_strategy)
+        {
+            let mut diff_strategy = PitStrategyData::default();
+            let mut strategy_changed = false;
+
+            macro_rules! diff_strategy_field {
+                ($field:ident) => {
+                    if cur_strategy.$field != last_strategy.$field {
+                        diff_strategy.$field = cur_strategy.$field;
+                        strategy_changed = true;
+                    }
+                };
+            }
+
+            diff_strategy_field!(pit_loss);
+            diff_strategy_field!(rejoin_position);
+            diff_strategy_field!(rejoin_gap_ahead);
+            diff_strategy_field!(rejoin_gap_behind);
+            diff_strategy_field!(tyre_delta);
+            diff_strategy_field!(undercut_gain);
+            diff_strategy_field!(overcut_margin);
+            diff_strategy_field!(pit_window_ideal_lap);
+            diff_strategy_field!(pit_window_latest_lap);
+            diff_strategy_field!(game_rejoin_position);
+
+            if strategy_changed {
+                diff.pit_strategy = Some(diff_strategy);
+                has_changes = true;
+            }
+        } else if self.pit_strategy != last.pit_strategy {
+            diff.pit_strategy = self.pit_strategy;
+            has_changes = true;
+        }
+
+        // Compare car_setup
+        if let (Some(cur_setup), Some(last_setup)) = (&self.car_setup, &last.car_setup) {
+            let mut diff_setup = CarSetupData::default();
+            let mut setup_changed = false;
+
+            macro_rules! diff_setup_field {
+                ($field:ident) => {
+                    if cur_setup.$field != last_setup.$field {
+                        diff_setup.$field = cur_setup.$field;
+                        setup_changed = true;
+                    }
+                };
+            }
+
+            diff_setup_field!(front_wing);
+            diff_setup_field!(rear_wing);
+            diff_setup_field!(on_throttle);
+            diff_setup_field!(off_throttle);
+            diff_setup_field!(front_camber);
+            diff_setup_field!(rear_camber);
+            diff_setup_field!(front_toe);
+            diff_setup_field!(rear_toe);
+            diff_setup_field!(front_suspension);
+            diff_setup_field!(rear_suspension);
+            diff_setup_field!(front_anti_roll_bar);
+            diff_setup_field!(rear_anti_roll_bar);
+            diff_setup_field!(front_suspension_height);
+            diff_setup_field!(rear_suspension_height);
+            diff_setup_field!(brake_pressure);
+            diff_setup_field!(brake_bias);
+            diff_setup_field!(engine_braking);
+            diff_setup_field!(rear_left_tyre_pressure);
+            diff_setup_field!(rear_right_tyre_pressure);
+            diff_setup_field!(front_left_tyre_pressure);
+            diff_setup_field!(front_right_tyre_pressure);
+            diff_setup_field!(ballast);
+            diff_setup_field!(fuel_load);
+
+            if setup_changed {
+                diff.car_setup = Some(diff_setup);
+                has_changes = true;
+            }
+        } else if self.car_setup != last.car_setup {
+            diff.car_setup = self.car_setup;
+            has_changes = true;
+        }
+
+        // Compare motion_ex
+        if let (Some(cur_motion_ex), Some(last_motion_ex)) = (&self.motion_ex, &last.motion_ex) {
+            let mut diff_motion_ex = MotionExData::default();
+            let mut motion_ex_changed = false;
+
+            macro_rules! diff_motion_ex_field {
+                ($field:ident) => {
+                    if cur_motion_ex.$field != last_motion_ex.$field {
+                        diff_motion_ex.$field = cur_motion_ex.$field;
+                        motion_ex_changed = true;
+                    }
+                };
+            }
+
+            macro_rules! diff_motion_ex_wheels {
+                ($field:ident) => {
+                    if cur_motion_ex.$field != last_motion_ex.$field {
+                        diff_motion_ex.$field = cur_motion_ex.$field.clone();
+                        motion_ex_changed = true;
+                    }
+                };
+            }
+
+            diff_motion_ex_wheels!(suspension_position);
+            diff_motion_ex_wheels!(suspension_velocity);
+            diff_motion_ex_wheels!(wheel_slip_ratio);
+            diff_motion_ex_wheels!(wheel_slip_angle);
+            diff_motion_ex_field!(local_velocity_x);
+            diff_motion_ex_field!(local_velocity_y);
+            diff_motion_ex_field!(local_velocity_z);
+            diff_motion_ex_field!(height_of_cog);
+
+            if motion_ex_changed {
+                diff.motion_ex = Some(diff_motion_ex);
+                has_changes = true;
+            }
+        } else if self.motion_ex != last.motion_ex {
+            diff.motion_ex = self.motion_ex.clone();
+            has_changes = true;
+        }
+
+        // Tyre sets are sent whole, they only change a few times per session
+        if self.tyre_sets != last.tyre_sets {
+            diff.tyre_sets = self.tyre_sets.clone();
+            has_changes = true;
+        }
+
         if has_changes {
             Some(diff)
         } else {
@@ -835,6 +1701,45 @@ impl F1GeneralInfo {
                 .iter()
                 .map(|&x| x as u32),
         );
+
+        let num_samples = (packet.num_weather_forecast_samples as usize)
+            .min(packet.weather_forecast_samples.len());
+
+        session.forecast_accuracy = Some(packet.forecast_accuracy as u32);
+        session.weather_forecast.clear();
+        session
+            .weather_forecast
+            .extend(
+                packet.weather_forecast_samples[..num_samples]
+                    .iter()
+                    .map(|sample| WeatherForecastSample {
+                        session_type: Some(sample.session_type as u32),
+                        time_offset: Some(sample.time_offset as u32),
+                        weather: Some(sample.weather as u32),
+                        track_temperature: Some(sample.track_temperature as i32),
+                        track_temperature_change: Some(sample.track_temperature_change as i32),
+                        air_temperature: Some(sample.air_temperature as i32),
+                        air_temperature_change: Some(sample.air_temperature_change as i32),
+                        rain_percentage: Some(sample.rain_percentage as u32),
+                    }),
+            );
+    }
+
+    /// Updates the flag state of the marshal zones
+    #[inline]
+    pub fn update_marshal_zones(&mut self, packet: &PacketSessionData) {
+        let session = self.session.get_or_insert_with(Default::default);
+        let num_zones = (packet.num_marshal_zones as usize).min(packet.marshal_zones.len());
+
+        let zones = packet.marshal_zones[..num_zones]
+            .iter()
+            .map(|zone| MarshalZone {
+                zone_start: Some(zone.zone_start),
+                zone_flag: Some(zone.zone_flag as i32),
+            });
+
+        session.marshal_zones.clear();
+        session.marshal_zones.extend(zones);
     }
 
     /// Computes the difference between two F1GeneralInfo instances
@@ -873,6 +1778,18 @@ impl F1GeneralInfo {
             diff_session_field!(num_red_flags);
             diff_session_field!(s2_lap_distance_start);
             diff_session_field!(s3_lap_distance_start);
+            diff_session_field!(forecast_accuracy);
+
+            // The forecast is rolling, so it's sent whole when any sample changes
+            if cur_session.weather_forecast != last_session.weather_forecast {
+                diff_session.weather_forecast = cur_session.weather_forecast.clone();
+                session_changed = true;
+            }
+
+            if cur_session.marshal_zones != last_session.marshal_zones {
+                diff_session.marshal_zones = cur_session.marshal_zones.clone();
+                session_changed = true;
+            }
 
             // Optimized weekend_structure diff
             if cur_session.weekend_structure.len() > last_session.weekend_structure.len() {
@@ -922,6 +1839,12 @@ impl F1GeneralInfo {
             has_changes = true;
         }
 
+        // The lobby is small and only changes while players join or get ready
+        if self.lobby != last.lobby {
+            diff.lobby = self.lobby.clone();
+            has_changes = true;
+        }
+
         // Optimized players diff
         for (key, cur_player) in &self.players {
             if let Some(last_player) = last.players.get(key) {
@@ -929,7 +1852,7 @@ impl F1GeneralInfo {
                 let mut player_changed = false;
 
                 if cur_player.participant != last_player.participant {
-                    diff_player.participant = cur_player.participant;
+                    diff_player.participant = cur_player.participant.clone();
                     player_changed = true;
                 }
 
@@ -1011,6 +1934,97 @@ impl F1GeneralInfo {
     }
 }
 
+impl CarSetupData {
+    /// Converts the streamed setup into the one stored for parc fermé checks
+    #[inline]
+    fn to_entity(self) -> CarSetup {
+        CarSetup {
+            front_wing: self.front_wing.unwrap_or_default() as i16,
+            rear_wing: self.rear_wing.unwrap_or_default() as i16,
+            on_throttle: self.on_throttle.unwrap_or_default() as i16,
+            off_throttle: self.off_throttle.unwrap_or_default() as i16,
+            front_camber: self.front_camber.unwrap_or_default(),
+            rear_camber: self.rear_camber.unwrap_or_default(),
+            front_toe: self.front_toe.unwrap_or_default(),
+            rear_toe: self.rear_toe.unwrap_or_default(),
+            front_suspension: self.front_suspension.unwrap_or_default() as i16,
+            rear_suspension: self.rear_suspension.unwrap_or_default() as i16,
+            front_anti_roll_bar: self.front_anti_roll_bar.unwrap_or_default() as i16,
+            rear_anti_roll_bar: self.rear_anti_roll_bar.unwrap_or_default() as i16,
+            front_suspension_height: self.front_suspension_height.unwrap_or_default() as i16,
+            rear_suspension_height: self.rear_suspension_height.unwrap_or_default() as i16,
+            brake_pressure: self.brake_pressure.unwrap_or_default() as i16,
+            brake_bias: self.brake_bias.unwrap_or_default() as i16,
+            engine_braking: self.engine_braking.unwrap_or_default() as i16,
+            rear_left_tyre_pressure: self.rear_left_tyre_pressure.unwrap_or_default(),
+            rear_right_tyre_pressure: self.rear_right_tyre_pressure.unwrap_or_default(),
+            front_left_tyre_pressure: self.front_left_tyre_pressure.unwrap_or_default(),
+            front_right_tyre_pressure: self.front_right_tyre_pressure.unwrap_or_default(),
+            ballast: self.ballast.unwrap_or_default() as i16,
+            fuel_load: self.fuel_load.unwrap_or_default(),
+        }
+    }
+}
+
+impl LobbyPlayer {
+    /// Creates a new LobbyPlayer from a checked lobby entry
+    #[inline]
+    fn from_entry(entry: &LobbyEntry) -> Self {
+        Self {
+            steam_name: Some(entry.steam_name.to_string()),
+            team_id: Some(entry.team_id as u32),
+            nationality: Some(entry.nationality as u32),
+            platform: Some(entry.platform as u32),
+            car_number: Some(entry.car_number as u32),
+            ready_status: Some(entry.ready_status as u32),
+            registered: Some(entry.registered),
+            substitute: Some(entry.substitute),
+            expected_team_id: entry.expected_team_id.map(|team_id| team_id as u32),
+            expected_number: entry.expected_number.map(|number| number as u32),
+        }
+    }
+}
+
+impl RuleDeviation {
+    /// Creates a new RuleDeviation from a detected deviation
+    #[inline]
+    fn from_data(deviation: &RuleDeviationData) -> Self {
+        Self {
+            rule: deviation.rule.as_str().to_owned(),
+            session_type: deviation.session_type as u32,
+            expected: deviation.expected as u32,
+            actual: deviation.actual as u32,
+        }
+    }
+}
+
+impl CarPosition {
+    /// Creates a new CarPosition from the motion data of a car
+    #[inline]
+    fn from_f1(car_idx: usize, motion_data: &F1CarMotionData) -> Self {
+        Self {
+            car_idx: car_idx as u32,
+            x: quantize_position(motion_data.world_position_x),
+            z: quantize_position(motion_data.world_position_z),
+            heading: quantize_heading(motion_data.yaw),
+            g_force_lateral: quantize_g_force(motion_data.g_force_lateral),
+            g_force_longitudinal: quantize_g_force(motion_data.g_force_longitudinal),
+        }
+    }
+}
+
+impl TrackOutline {
+    /// Creates a new TrackOutline from the points recorded so far
+    #[inline]
+    fn from_builder(builder: &TrackOutlineBuilder) -> Self {
+        Self {
+            track_id: builder.track_id() as i32,
+            step: OUTLINE_STEP as u32,
+            points: builder.points(),
+        }
+    }
+}
+
 impl EventData {
     /// Creates a new EventData from F1 event data
     #[inline]
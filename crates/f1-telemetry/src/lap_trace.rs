@@ -0,0 +1,196 @@
+use crate::types::{CarTelemetryData, LapData};
+
+// Constants
+const MAX_CARS: usize = 22;
+/// Metres of lap distance between two samples of a trace
+pub const TRACE_STEP: f32 = 10.0;
+
+/// Telemetry of a completed lap, sampled every [`TRACE_STEP`] metres
+#[derive(Debug, Default, PartialEq)]
+pub struct LapTrace {
+    pub vehicle_idx: u8,
+    pub lap_number: u8,
+    pub lap_time: u32,
+    pub valid: bool,
+    /// Time into the lap in milliseconds
+    pub time: Vec<i32>,
+    pub speed: Vec<i16>,
+    /// Throttle and brake in percent
+    pub throttle: Vec<i16>,
+    pub brake: Vec<i16>,
+    pub gear: Vec<i16>,
+    /// Steering from -100 (full left) to 100 (full right)
+    pub steer: Vec<i16>,
+}
+
+/// Lap being recorded for a single car
+#[derive(Debug, Default)]
+struct CarTrace {
+    lap_number: u8,
+    lap_distance: f32,
+    lap_time: u32,
+    invalid: bool,
+    trace: LapTrace,
+}
+
+/// Samples the telemetry of every car against its lap distance
+#[derive(Debug)]
+pub struct LapTraceTracker {
+    cars: Vec<CarTrace>,
+}
+
+impl Default for LapTraceTracker {
+    fn default() -> Self {
+        Self {
+            cars: (0..MAX_CARS).map(|_| CarTrace::default()).collect(),
+        }
+    }
+}
+
+impl LapTrace {
+    /// Adds the samples up to the given step, interpolating the time of the steps in between
+    fn push(&mut self, step: usize, lap_time: u32, data: &CarTelemetryData) {
+        let last_time = self.time.last().copied().unwrap_or(0);
+        let missing = (step + 1 - self.time.len()) as i32;
+
+        for n in 1..=missing {
+            self.time
+                .push(last_time + (lap_time as i32 - last_time) * n / missing);
+            self.speed.push(data.speed as i16);
+            self.throttle.push((data.throttle * 100.0).round() as i16);
+            self.brake.push((data.brake * 100.0).round() as i16);
+            self.gear.push(data.gear as i16);
+            self.steer.push((data.steer * 100.0).round() as i16);
+        }
+    }
+}
+
+impl LapTraceTracker {
+    /// Follows the lap of every car, returning the traces of the laps completed
+    pub fn update_laps(&mut self, laps: &[LapData]) -> Vec<LapTrace> {
+        let mut completed = Vec::new();
+
+        for (idx, (car, data)) in self.cars.iter_mut().zip(laps).enumerate() {
+            let lap_number = data.current_lap_num;
+
+            if lap_number == 0 {
+                continue;
+            }
+
+            if lap_number != car.lap_number {
+                let trace = std::mem::take(&mut car.trace);
+
+                if car.lap_number != 0 && lap_number == car.lap_number + 1 && !trace.time.is_empty()
+                {
+                    completed.push(LapTrace {
+                        vehicle_idx: idx as u8,
+                        lap_number: car.lap_number,
+                        lap_time: data.last_lap_time_in_ms,
+                        valid: !car.invalid,
+                        ..trace
+                    });
+                }
+
+                car.lap_number = lap_number;
+                car.invalid = false;
+            }
+
+            car.lap_distance = data.lap_distance;
+            car.lap_time = data.current_lap_time_in_ms;
+            car.invalid |= data.current_lap_invalid != 0;
+        }
+
+        completed
+    }
+
+    /// Samples the telemetry of every car once per step of lap distance. Traces only start
+    /// when a car crosses the line, laps joined halfway are skipped
+    pub fn update_telemetry(&mut self, telemetry: &[CarTelemetryData]) {
+        for (car, data) in self.cars.iter_mut().zip(telemetry) {
+            if car.lap_number == 0 || car.lap_distance < 0.0 {
+                continue;
+            }
+
+            let step = (car.lap_distance / TRACE_STEP) as usize;
+            let len = car.trace.time.len();
+
+            if step < len || (len == 0 && step > 0) {
+                continue;
+            }
+
+            car.trace.push(step, car.lap_time, data);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::*;
+
+    fn lap(lap_number: u8, lap_distance: f32, lap_time: u32) -> LapData {
+        // SAFETY: every field of the lap data is a plain number
+        let mut data: LapData = unsafe { mem::zeroed() };
+        data.current_lap_num = lap_number;
+        data.lap_distance = lap_distance;
+        data.current_lap_time_in_ms = lap_time;
+        data
+    }
+
+    fn telemetry(speed: u16, throttle: f32) -> CarTelemetryData {
+        // SAFETY: every field of the telemetry data is a plain number or array
+        let mut data: CarTelemetryData = unsafe { mem::zeroed() };
+        data.speed = speed;
+        data.throttle = throttle;
+        data.gear = 7;
+        data
+    }
+
+    #[test]
+    fn traces_completed_laps() {
+        let mut tracker = LapTraceTracker::default();
+
+        // Joined halfway through the first lap
+        tracker.update_laps(&[lap(1, 500.0, 20000)]);
+        tracker.update_telemetry(&[telemetry(250, 1.0)]);
+
+        let mut next_lap = lap(2, 2.0, 30);
+        next_lap.last_lap_time_in_ms = 60000;
+        assert!(tracker.update_laps(&[next_lap]).is_empty());
+
+        tracker.update_telemetry(&[telemetry(200, 1.0)]);
+        tracker.update_laps(&[lap(2, 14.0, 230)]);
+        tracker.update_telemetry(&[telemetry(210, 1.0)]);
+
+        // Skipped a step, the time in between is interpolated
+        let mut invalid = lap(2, 31.0, 560);
+        invalid.current_lap_invalid = 1;
+        tracker.update_laps(&[invalid]);
+        tracker.update_telemetry(&[telemetry(220, 0.5)]);
+
+        let mut next_lap = lap(3, 1.0, 20);
+        next_lap.last_lap_time_in_ms = 61000;
+        let traces = tracker.update_laps(&[next_lap]);
+
+        assert_eq!(traces.len(), 1);
+        assert_eq!(traces[0].lap_number, 2);
+        assert_eq!(traces[0].lap_time, 61000);
+        assert!(!traces[0].valid);
+        assert_eq!(traces[0].time, vec![30, 230, 395, 560]);
+        assert_eq!(traces[0].speed, vec![200, 210, 220, 220]);
+        assert_eq!(traces[0].throttle, vec![100, 100, 50, 50]);
+        assert_eq!(traces[0].gear, vec![7; 4]);
+    }
+
+    #[test]
+    fn flashbacks_drop_the_lap() {
+        let mut tracker = LapTraceTracker::default();
+
+        tracker.update_laps(&[lap(3, 0.0, 10)]);
+        tracker.update_telemetry(&[telemetry(200, 1.0)]);
+
+        assert!(tracker.update_laps(&[lap(2, 4000.0, 50000)]).is_empty());
+        assert!(tracker.update_laps(&[lap(3, 0.0, 10)]).is_empty());
+    }
+}
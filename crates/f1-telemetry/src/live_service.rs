@@ -10,6 +10,7 @@ use std::{
 };
 
 use ahash::AHashMap;
+use chrono::Utc;
 use dashmap::DashMap;
 use ntex::util::Bytes;
 use parking_lot::RwLock;
@@ -23,19 +24,38 @@ use tokio::{
 };
 use tracing::{error, info, info_span, warn};
 
+use entities::{IncidentKind, TimeTrialLapKind};
 use error::{AppResult, CommonError, F1ServiceError};
-use intelli_core::services::{ChampionshipServiceOperations, DriverServiceOperations};
+use intelli_core::{
+    scheduled_race,
+    services::{
+        ChampionshipServiceOperations, DriverServiceOperations, RaceServiceOperations,
+        StewardServiceOperations,
+    },
+    substitutes, QUALIFYING_SESSION_TYPES,
+};
+use structs::{
+    AssistEvidenceData, IncidentData, LapTraceData, ResultEntryData, SetupSnapshotData,
+    TimeTrialLapData, WeatherSampleData, YellowFlagData,
+};
 
 use crate::{
+    assists::AssistComplianceTracker,
+    damage::DamageAlertTracker,
+    flags::MarshalZoneTracker,
+    lap_trace::{LapTrace, LapTraceTracker, TRACE_STEP},
+    lobby, rules,
     types::{
-        PacketCarDamageData, PacketCarStatusData, PacketCarTelemetryData, PacketEventData,
-        PacketFinalClassificationData, PacketHeader, PacketIds, PacketMotionData,
-        PacketParticipantsData, PacketSessionData, PacketSessionHistoryData, SessionType,
+        EventCode, PacketCarDamageData, PacketCarSetupData, PacketCarStatusData,
+        PacketCarTelemetryData, PacketEventData, PacketFinalClassificationData, PacketHeader,
+        PacketIds, PacketLapData, PacketLobbyInfoData, PacketMotionData, PacketMotionExData,
+        PacketParticipantsData, PacketSessionData, PacketSessionHistoryData, PacketTimeTrialData,
+        PacketTyreSetsData, SessionType, TimeTrialDataSet,
     },
     F1State,
 };
 
-use super::handler::F1TelemetryPacketHandler;
+use super::handler::{F1TelemetryPacketHandler, LeagueTeamNames};
 
 // Constants
 const BUFFER_SIZE: usize = 1460;
@@ -45,33 +65,54 @@ const TELEMETRY_INTERVAL: Duration = Duration::from_millis(100);
 const HISTORY_INTERVAL: Duration = Duration::from_secs(1);
 const SESSION_INTERVAL: Duration = Duration::from_secs(10);
 const MOTION_INTERVAL: Duration = Duration::from_millis(700);
+const POSITIONS_INTERVAL: Duration = Duration::from_millis(50);
+const WEATHER_INTERVAL: Duration = Duration::from_secs(60);
+const SETUPS_INTERVAL: Duration = Duration::from_secs(1);
+const LOBBY_INTERVAL: Duration = Duration::from_secs(5);
 const PARTICIPANTS_TICK_UPDATE: u8 = 6;
 
 /// Enum representing different types of F1 packet data
 enum F1TelemetryPacket<'a> {
     Motion(&'a PacketMotionData),
+    MotionEx(&'a PacketMotionExData),
     Session(&'a PacketSessionData),
     Event(&'a PacketEventData),
     Participants(&'a PacketParticipantsData),
     FinalClassification(&'a PacketFinalClassificationData),
     SessionHistory(&'a PacketSessionHistoryData),
     CarDamage(&'a PacketCarDamageData),
+    CarSetups(&'a PacketCarSetupData),
     CarStatus(&'a PacketCarStatusData),
     CarTelemetry(&'a PacketCarTelemetryData),
+    TyreSets(&'a PacketTyreSetsData),
+    TimeTrial(&'a PacketTimeTrialData),
+    LobbyInfo(&'a PacketLobbyInfoData),
+    LapData(&'a PacketLapData),
 }
 
 /// Represents an F1 service that processes and manages F1 telemetry data
 pub struct F1LiveTelemetryService {
     port: i32,
     race_id: i32,
+    race_track_id: i16,
     tick_counter: u8,
     championship_id: i32,
     error_count: u8,
     port_partially_opened: bool,
     timestamps: PacketProcessingTimestamps,
+    marshal_zones: MarshalZoneTracker,
+    assists: AssistComplianceTracker,
+    lap_traces: LapTraceTracker,
+    damage_alerts: DamageAlertTracker,
     socket: UdpSocket,
     shutdown: oneshot::Receiver<()>,
     session_type: Option<SessionType>,
+    rules_checked: Option<SessionType>,
+    setups_stored: Option<SessionType>,
+    assists_recorded: Option<SessionType>,
+    track_id: Option<i16>,
+    recording_outline: bool,
+    time_trial_laps: [u32; 2],
     packet_handler: F1TelemetryPacketHandler,
     services: &'static DashMap<i32, F1SessionBroadcaster>,
     f1_state: &'static F1State,
@@ -89,17 +130,28 @@ pub struct F1SessionBroadcasterInner {
     global_channel: Sender<Bytes>,
     global_subscribers: AtomicU32,
     team_subscribers: RwLock<AHashMap<u8, u32>>,
+    driver_subscribers: RwLock<AHashMap<Box<str>, u32>>,
+    steward_subscribers: AtomicU32,
+    positions_subscribers: AtomicU32,
+    director_subscribers: AtomicU32,
 }
 
 /// Tracks the last update times for various packet types
 struct PacketProcessingTimestamps {
     session: Instant,
     car_motion: Instant,
+    car_motion_ex: Instant,
+    positions: Instant,
     car_status: Instant,
     car_damage: Instant,
+    car_setups: Instant,
     car_telemetry: Instant,
+    lap_data: Instant,
     participants: Instant,
+    lobby: Instant,
+    weather: Instant,
     car_lap: [Instant; 22],
+    car_tyre_sets: [Instant; 22],
 }
 
 impl F1TelemetryPacket<'_> {
@@ -113,12 +165,18 @@ impl F1TelemetryPacket<'_> {
             PacketIds::Motion => {
                 Some(cast::<PacketMotionData>(data).map(F1TelemetryPacket::Motion)?)
             }
+            PacketIds::MotionEx => {
+                Some(cast::<PacketMotionExData>(data).map(F1TelemetryPacket::MotionEx)?)
+            }
             PacketIds::Session => {
                 Some(cast::<PacketSessionData>(data).map(F1TelemetryPacket::Session)?)
             }
             PacketIds::CarDamage => {
                 Some(cast::<PacketCarDamageData>(data).map(F1TelemetryPacket::CarDamage)?)
             }
+            PacketIds::CarSetups => {
+                Some(cast::<PacketCarSetupData>(data).map(F1TelemetryPacket::CarSetups)?)
+            }
             PacketIds::CarStatus => {
                 Some(cast::<PacketCarStatusData>(data).map(F1TelemetryPacket::CarStatus)?)
             }
@@ -135,8 +193,18 @@ impl F1TelemetryPacket<'_> {
                 cast::<PacketFinalClassificationData>(data)
                     .map(F1TelemetryPacket::FinalClassification)?,
             ),
-
-            _ => None,
+            PacketIds::TyreSets => {
+                Some(cast::<PacketTyreSetsData>(data).map(F1TelemetryPacket::TyreSets)?)
+            }
+            PacketIds::TimeTrial => {
+                Some(cast::<PacketTimeTrialData>(data).map(F1TelemetryPacket::TimeTrial)?)
+            }
+            PacketIds::LobbyInfo => {
+                Some(cast::<PacketLobbyInfoData>(data).map(F1TelemetryPacket::LobbyInfo)?)
+            }
+            PacketIds::LapData => {
+                Some(cast::<PacketLapData>(data).map(F1TelemetryPacket::LapData)?)
+            }
         };
 
         Ok(packet)
@@ -156,14 +224,25 @@ impl F1LiveTelemetryService {
         F1LiveTelemetryService {
             port: 0,
             race_id: 0,
+            race_track_id: 0,
             championship_id: 0,
             tick_counter: 10,
             error_count: 0,
             port_partially_opened: false,
             timestamps: PacketProcessingTimestamps::new(),
+            marshal_zones: MarshalZoneTracker::default(),
+            assists: AssistComplianceTracker::default(),
+            lap_traces: LapTraceTracker::default(),
+            damage_alerts: DamageAlertTracker::default(),
             shutdown,
             socket: UdpSocket::bind("0.0.0.0:0").await.unwrap(),
             session_type: None,
+            rules_checked: None,
+            setups_stored: None,
+            assists_recorded: None,
+            track_id: None,
+            recording_outline: false,
+            time_trial_laps: [0; 2],
             packet_handler,
             services,
             f1_state,
@@ -171,12 +250,7 @@ impl F1LiveTelemetryService {
     }
 
     /// Initializes the F1 service with a specific port and championship ID
-    pub async fn initialize(
-        &mut self,
-        port: i32,
-        championship_id: i32,
-        race_id: i32,
-    ) -> AppResult<()> {
+    pub async fn initialize(&mut self, port: i32, championship_id: i32) -> AppResult<()> {
         let Ok(socket) = UdpSocket::bind(SocketAddr::new(SOCKET_HOST, port as u16)).await else {
             error!("There was an error binding to the socket");
             return Err(CommonError::InternalServerError)?;
@@ -184,7 +258,6 @@ impl F1LiveTelemetryService {
 
         self.port = port;
         self.socket = socket;
-        self.race_id = race_id;
         self.championship_id = championship_id;
 
         self.f1_state
@@ -303,6 +376,9 @@ impl F1LiveTelemetryService {
 
         match packet {
             F1TelemetryPacket::Motion(motion_data) => self.handle_motion_packet(motion_data, now),
+            F1TelemetryPacket::MotionEx(motion_ex_data) => {
+                self.handle_motion_ex_packet(motion_ex_data, now)
+            }
             F1TelemetryPacket::Session(session_data) => {
                 self.handle_session_packet(session_data, now).await
             }
@@ -310,7 +386,7 @@ impl F1LiveTelemetryService {
                 self.handle_participants_packet(participants_data, now)
                     .await?
             }
-            F1TelemetryPacket::Event(event_data) => self.handle_event_packet(event_data),
+            F1TelemetryPacket::Event(event_data) => self.handle_event_packet(event_data).await,
             F1TelemetryPacket::SessionHistory(session_history_data) => {
                 self.handle_session_history_packet(session_history_data, now)
             }
@@ -321,12 +397,25 @@ impl F1LiveTelemetryService {
             F1TelemetryPacket::CarDamage(car_damage) => {
                 self.handle_car_damage_packet(car_damage, now)
             }
+            F1TelemetryPacket::CarSetups(car_setups) => {
+                self.handle_car_setups_packet(car_setups, now).await
+            }
             F1TelemetryPacket::CarStatus(car_status) => {
                 self.handle_car_status_packet(car_status, now)
             }
             F1TelemetryPacket::CarTelemetry(car_telemetry) => {
                 self.handle_car_telemetry_packet(car_telemetry, now)
             }
+            F1TelemetryPacket::TyreSets(tyre_sets) => self.handle_tyre_sets_packet(tyre_sets, now),
+            F1TelemetryPacket::TimeTrial(time_trial) => {
+                self.handle_time_trial_packet(time_trial).await
+            }
+            F1TelemetryPacket::LobbyInfo(lobby_info) => {
+                self.handle_lobby_info_packet(lobby_info, now).await
+            }
+            F1TelemetryPacket::LapData(lap_data) => {
+                self.handle_lap_data_packet(lap_data, now).await
+            }
         }
 
         Ok(())
@@ -334,6 +423,13 @@ impl F1LiveTelemetryService {
 
     #[inline]
     fn handle_motion_packet(&mut self, motion_data: &PacketMotionData, now: Instant) {
+        if (self.recording_outline || self.packet_handler.has_positions_receivers())
+            && now.duration_since(self.timestamps.positions) >= POSITIONS_INTERVAL
+        {
+            self.packet_handler.save_positions(motion_data);
+            self.timestamps.positions = now;
+        }
+
         if now.duration_since(self.timestamps.car_motion) < MOTION_INTERVAL {
             return;
         }
@@ -342,12 +438,37 @@ impl F1LiveTelemetryService {
         self.timestamps.car_motion = now;
     }
 
+    #[inline]
+    fn handle_motion_ex_packet(&mut self, motion_ex_data: &PacketMotionExData, now: Instant) {
+        if now.duration_since(self.timestamps.car_motion_ex) > TELEMETRY_INTERVAL {
+            self.packet_handler.save_motion_ex(motion_ex_data);
+            self.timestamps.car_motion_ex = now;
+        }
+    }
+
     #[inline]
     async fn handle_session_packet(&mut self, session_data: &PacketSessionData, now: Instant) {
+        // Flags can't wait for the session interval
+        self.track_marshal_zones(session_data).await;
+
         if now.duration_since(self.timestamps.session) < SESSION_INTERVAL {
             return;
         }
 
+        let Ok(session_type) = SessionType::try_from(session_data.session_type) else {
+            error!("Error deserializing F1 session type");
+            return;
+        };
+
+        self.update_track(session_data).await;
+
+        // Time trial is played offline and only feeds the hotlap leaderboards
+        if session_type == SessionType::TimeTrial {
+            self.session_type = Some(session_type);
+            self.timestamps.session = now;
+            return;
+        }
+
         #[cfg(not(debug_assertions))]
         if session_data.network_game != 1 {
             error!("Not Online Game, closing service");
@@ -355,16 +476,271 @@ impl F1LiveTelemetryService {
             return;
         }
 
-        let Ok(session_type) = SessionType::try_from(session_data.session_type) else {
-            error!("Error deserializing F1 session type");
-            return;
-        };
+        // Sessions on another track belong to another race
+        if self.race_id == 0 || self.race_track_id != session_data.track_id as i16 {
+            if let Err(e) = self.resolve_race(session_data.track_id).await {
+                error!("Error resolving race: {}", e);
+            }
+        }
+
+        // A new session started without a final classification
+        if let Some(previous) = self
+            .session_type
+            .filter(|&previous| previous != session_type)
+        {
+            self.record_assists(previous).await;
+        }
 
+        if self.session_type != Some(session_type) {
+            self.assists_recorded = None;
+            self.lap_traces = LapTraceTracker::default();
+            self.load_damage_thresholds().await;
+            self.load_league_teams().await;
+        }
+
+        self.check_rules(session_data, session_type).await;
+        self.assists.update_session(session_data);
         self.session_type = Some(session_type);
         self.packet_handler.save_session(session_data);
+        self.record_weather(session_data, now).await;
         self.timestamps.session = now;
     }
 
+    /// Loads the damage thresholds of the championship, changes apply from the next session
+    async fn load_damage_thresholds(&mut self) {
+        match self
+            .f1_state
+            .championship_repo
+            .damage_thresholds(self.championship_id)
+            .await
+        {
+            Ok(thresholds) => self.damage_alerts.set_thresholds(&thresholds),
+            Err(e) => error!("Error loading damage thresholds: {}", e),
+        }
+    }
+
+    /// Loads the names of the league teams shown in the streams, resolving game teams shared
+    /// by several league teams only through the lineups. Changes apply from the next session
+    async fn load_league_teams(&self) {
+        let championship_repo = self.f1_state.championship_repo;
+
+        let (teams, lineups) = match tokio::try_join!(
+            championship_repo.teams(self.championship_id),
+            championship_repo.lineups(self.championship_id)
+        ) {
+            Ok(league_teams) => league_teams,
+            Err(e) => {
+                error!("Error loading league teams: {}", e);
+                return;
+            }
+        };
+
+        let mut names = LeagueTeamNames::default();
+
+        for team in &teams {
+            if teams
+                .iter()
+                .filter(|other| other.game_team_id == team.game_team_id)
+                .count()
+                == 1
+            {
+                names
+                    .game_teams
+                    .insert(team.game_team_id as u8, team.name.clone());
+            }
+        }
+
+        for lineup in lineups.iter().filter(|lineup| lineup.left_at.is_none()) {
+            if let Some(team) = teams.iter().find(|team| team.id == lineup.team_id) {
+                names
+                    .drivers
+                    .insert(lineup.steam_name.clone(), team.name.clone());
+            }
+        }
+
+        self.packet_handler.set_league_teams(names);
+    }
+
+    /// Checks whether the outline of a new track still has to be recorded, storing it once
+    /// the outline and the pit lane are complete
+    async fn update_track(&mut self, session_data: &PacketSessionData) {
+        let track_id = session_data.track_id as i16;
+
+        if self.track_id != Some(track_id) {
+            self.track_id = Some(track_id);
+            self.recording_outline = match self.f1_state.race_repo.track_outline(track_id).await {
+                Ok(outline) => outline.is_none_or(|outline| outline.pit_lane.is_empty()),
+                Err(e) => {
+                    error!("Error loading track outline: {}", e);
+                    false
+                }
+            };
+        }
+
+        self.packet_handler.reset_track_outline(session_data);
+        self.store_track_outline(true).await;
+    }
+
+    /// Stores the recorded track outline. Mid session it waits for the pit lane, at the end
+    /// of a session the outline is stored without it and recording goes on
+    async fn store_track_outline(&mut self, require_pit_lane: bool) {
+        if !self.recording_outline {
+            return;
+        }
+
+        let Some(outline) = self.packet_handler.track_outline(require_pit_lane) else {
+            return;
+        };
+
+        if let Err(e) = self.f1_state.race_svc.save_track_outline(&outline).await {
+            error!("Error storing track outline: {}", e);
+            return;
+        }
+
+        self.recording_outline = outline.pit_lane.is_empty();
+    }
+
+    /// Compares the session settings with the championship rules once per session,
+    /// flagging the deviations on the stewarding feed
+    async fn check_rules(&mut self, session_data: &PacketSessionData, session_type: SessionType) {
+        if self.race_id == 0 || self.rules_checked == Some(session_type) {
+            return;
+        }
+
+        let rules = match self
+            .f1_state
+            .championship_repo
+            .rules(self.championship_id)
+            .await
+        {
+            Ok(rules) => rules,
+            Err(e) => {
+                error!("Error loading championship rules: {}", e);
+                return;
+            }
+        };
+
+        self.rules_checked = Some(session_type);
+
+        let deviations = rules::check_rules(&rules, session_data);
+        self.packet_handler
+            .save_rule_deviations(session_data.session_type, &deviations);
+
+        for deviation in &deviations {
+            warn!(
+                "Rule deviation in race {}: {} expected {}, found {}",
+                self.race_id,
+                deviation.rule.as_str(),
+                deviation.expected,
+                deviation.actual
+            );
+
+            if let Err(e) = self
+                .f1_state
+                .steward_svc
+                .add_rule_deviation(self.race_id, deviation)
+                .await
+            {
+                error!("Error recording rule deviation: {}", e);
+            }
+        }
+    }
+
+    /// Updates the marshal zone flags and records the yellow flags that ended
+    async fn track_marshal_zones(&mut self, session_data: &PacketSessionData) {
+        self.packet_handler.save_marshal_zones(session_data);
+
+        let finished = self.marshal_zones.update(session_data);
+
+        if self.race_id == 0 {
+            return;
+        }
+
+        for period in finished {
+            let yellow_flag = YellowFlagData {
+                session_type: session_data.session_type as i16,
+                zone_index: period.zone_index as i16,
+                zone_start: period.zone_start,
+                started_session_time: period.started_at,
+                ended_session_time: period.ended_at,
+            };
+
+            if let Err(e) = self
+                .f1_state
+                .steward_svc
+                .add_yellow_flag(self.race_id, &yellow_flag)
+                .await
+            {
+                error!("Error recording yellow flag: {}", e);
+            }
+        }
+    }
+
+    /// Links the session to the championship race held on this track around today,
+    /// creating the race if there isn't one
+    async fn resolve_race(&mut self, track_id: i8) -> AppResult<()> {
+        // Nothing is recorded for the previous race until the new one is resolved
+        self.race_id = 0;
+
+        let track_id = track_id as i16;
+        let now = Utc::now();
+
+        let races = self
+            .f1_state
+            .championship_repo
+            .races(self.championship_id)
+            .await?;
+
+        self.race_id = match scheduled_race(&races, Some(track_id), now) {
+            Some(race) => race.id,
+            None => {
+                self.f1_state
+                    .championship_svc
+                    .create_race(self.championship_id, track_id, now)
+                    .await?
+            }
+        };
+
+        self.race_track_id = track_id;
+        info!("Session linked to race: {}", self.race_id);
+        Ok(())
+    }
+
+    /// Records the current weather conditions for the race weather history
+    async fn record_weather(&mut self, session_data: &PacketSessionData, now: Instant) {
+        if self.race_id == 0 || now.duration_since(self.timestamps.weather) < WEATHER_INTERVAL {
+            return;
+        }
+
+        let num_samples = (session_data.num_weather_forecast_samples as usize)
+            .min(session_data.weather_forecast_samples.len());
+        let rain_percentage = session_data.weather_forecast_samples[..num_samples]
+            .iter()
+            .find(|sample| {
+                sample.session_type == session_data.session_type && sample.time_offset == 0
+            })
+            .map(|sample| sample.rain_percentage as i16);
+
+        let sample = WeatherSampleData {
+            session_type: session_data.session_type as i16,
+            weather: session_data.weather as i16,
+            track_temperature: session_data.track_temperature as i16,
+            air_temperature: session_data.air_temperature as i16,
+            rain_percentage,
+        };
+
+        if let Err(e) = self
+            .f1_state
+            .race_svc
+            .add_weather_sample(self.race_id, &sample)
+            .await
+        {
+            error!("Error recording weather sample: {}", e);
+        }
+
+        self.timestamps.weather = now;
+    }
+
     #[inline]
     async fn handle_participants_packet(
         &mut self,
@@ -382,6 +758,7 @@ impl F1LiveTelemetryService {
 
             self.ensure_participants_registered(participants_data)
                 .await?;
+            self.verify_driver_claims(participants_data).await?;
         }
 
         self.packet_handler.save_participants(participants_data);
@@ -389,19 +766,136 @@ impl F1LiveTelemetryService {
         Ok(())
     }
 
+    /// Checks the lobby roster against the championship drivers, registering the new
+    /// players before the session starts
+    async fn handle_lobby_info_packet(&mut self, lobby_info: &PacketLobbyInfoData, now: Instant) {
+        if now.duration_since(self.timestamps.lobby) < LOBBY_INTERVAL {
+            return;
+        }
+
+        self.timestamps.lobby = now;
+
+        let drivers = match self
+            .f1_state
+            .championship_repo
+            .drivers(self.championship_id)
+            .await
+        {
+            Ok(drivers) => drivers,
+            Err(e) => {
+                error!("Error loading championship drivers: {}", e);
+                return;
+            }
+        };
+
+        // Reserves stay out of the roster, as they do once the session starts
+        let substitutes = match self.race_substitutes().await {
+            Ok(substitutes) => substitutes,
+            Err(e) => {
+                error!("Error loading race substitutes: {}", e);
+                return;
+            }
+        };
+
+        let roster = lobby::check_roster(lobby_info, &drivers, &substitutes);
+
+        for entry in roster.iter().filter(|entry| entry.can_register()) {
+            if let Err(e) = self
+                .register_driver(
+                    &entry.steam_name,
+                    entry.nationality,
+                    entry.team_id,
+                    entry.car_number,
+                )
+                .await
+            {
+                error!("Error registering lobby player: {}", e);
+            }
+        }
+
+        self.packet_handler.save_lobby(&roster);
+    }
+
     #[inline]
-    fn handle_event_packet(&mut self, event_data: &PacketEventData) {
-        let Some(session_type) = &self.session_type else {
+    async fn handle_event_packet(&mut self, event_data: &PacketEventData) {
+        let Some(session_type) = self.session_type else {
             return;
         };
 
-        if ![SessionType::R, SessionType::R2, SessionType::R3].contains(session_type) {
+        self.record_incident(event_data, session_type).await;
+
+        if ![SessionType::R, SessionType::R2, SessionType::R3].contains(&session_type) {
             return;
         }
 
         self.packet_handler.push_event(event_data);
     }
 
+    /// Records penalties, collisions and safety car deployments in the stewarding log and
+    /// the race director feed
+    async fn record_incident(&self, event_data: &PacketEventData, session_type: SessionType) {
+        let Ok(event_code) = EventCode::try_from(&event_data.event_string_code) else {
+            return;
+        };
+
+        let session_type = session_type as i16;
+        let session_time = event_data.header.session_time;
+
+        let incident = match event_code {
+            EventCode::PenaltyIssued => {
+                let penalty = unsafe { &event_data.event_details.penalty };
+                let mut incident =
+                    IncidentData::new(session_type, IncidentKind::Penalty, session_time);
+
+                incident.lap = Some(penalty.lap_num as i16);
+                incident.steam_name = self.packet_handler.driver_name(penalty.vehicle_idx);
+                incident.other_steam_name =
+                    self.packet_handler.driver_name(penalty.other_vehicle_idx);
+                incident.penalty_type = Some(penalty.penalty_type as i16);
+                incident.infringement_type = Some(penalty.infringement_type as i16);
+                incident.penalty_time = (penalty.time != 255).then_some(penalty.time as i16);
+                incident
+            }
+
+            EventCode::Collision => {
+                let collision = unsafe { &event_data.event_details.collision };
+                let mut incident =
+                    IncidentData::new(session_type, IncidentKind::Collision, session_time);
+
+                incident.steam_name = self.packet_handler.driver_name(collision.vehicle1_idx);
+                incident.other_steam_name = self.packet_handler.driver_name(collision.vehicle2_idx);
+                incident
+            }
+
+            EventCode::SafetyCar => {
+                let safety_car = unsafe { &event_data.event_details.safety_car };
+                let mut incident =
+                    IncidentData::new(session_type, IncidentKind::SafetyCar, session_time);
+
+                incident.safety_car_type = Some(safety_car.safety_car_type as i16);
+                incident.safety_car_event = Some(safety_car.event_type as i16);
+                incident
+            }
+
+            _ => return,
+        };
+
+        self.packet_handler.push_incident(&incident);
+
+        if self.race_id == 0 {
+            return;
+        }
+
+        if let Err(e) = self
+            .f1_state
+            .steward_svc
+            .add_incident(self.race_id, &incident)
+            .await
+        {
+            error!("Error recording incident: {}", e);
+        }
+    }
+
     #[inline]
     fn handle_session_history_packet(
         &mut self,
@@ -428,7 +922,7 @@ impl F1LiveTelemetryService {
         &mut self,
         final_classification: &PacketFinalClassificationData,
     ) -> AppResult<()> {
-        let Some(_session_type) = self.session_type.take() else {
+        let Some(session_type) = self.session_type.take() else {
             error!("Not defined session type when trying to save final_classification_data");
             return Ok(());
         };
@@ -436,18 +930,176 @@ impl F1LiveTelemetryService {
         self.packet_handler
             .save_final_classification(final_classification);
 
+        if self.race_id != 0 {
+            self.store_result(final_classification, session_type).await;
+
+            if QUALIFYING_SESSION_TYPES.contains(&(session_type as i16)) {
+                self.store_setups(session_type).await;
+            }
+
+            self.store_engine_wear(session_type).await;
+        }
+
+        self.record_assists(session_type).await;
+        self.store_track_outline(false).await;
         Ok(())
     }
 
+    /// Records the evidence of the assists used during the session and starts tracking again.
+    /// The evidence is recorded once per session, samples taken on the results screen after
+    /// the final classification are dropped
+    async fn record_assists(&mut self, session_type: SessionType) {
+        let detected = mem::take(&mut self.assists).detected();
+
+        if self.race_id == 0 || self.assists_recorded == Some(session_type) {
+            return;
+        }
+
+        self.assists_recorded = Some(session_type);
+
+        for assist in detected {
+            let Some(steam_name) = self.packet_handler.driver_name(assist.vehicle_idx) else {
+                continue;
+            };
+
+            let evidence = AssistEvidenceData {
+                session_type: session_type as i16,
+                steam_name,
+                assist: assist.assist,
+                inferred: assist.inferred,
+                samples: assist.samples as i32,
+                total_samples: assist.total_samples as i32,
+                level: assist.level as i16,
+            };
+
+            if let Err(e) = self
+                .f1_state
+                .steward_svc
+                .add_assist_evidence(self.race_id, &evidence)
+                .await
+            {
+                error!("Error recording assist evidence: {}", e);
+            }
+        }
+    }
+
+    /// Stores the session result with the final classification of every driver
+    async fn store_result(
+        &self,
+        final_classification: &PacketFinalClassificationData,
+        session_type: SessionType,
+    ) {
+        let num_cars = (final_classification.num_cars as usize)
+            .min(final_classification.classification_data.len());
+        let mut entries = Vec::with_capacity(num_cars);
+
+        for (idx, classification) in final_classification.classification_data[..num_cars]
+            .iter()
+            .enumerate()
+        {
+            let Some(driver) = self.packet_handler.driver(idx as u8) else {
+                continue;
+            };
+
+            entries.push(ResultEntryData {
+                steam_name: driver.name,
+                team_id: driver.team_id as i16,
+                grid_position: classification.grid_position as i16,
+                laps: classification.num_laps as i16,
+                race_time: classification.total_race_time,
+                penalties_time: classification.penalties_time as i16,
+                best_lap_time: classification.best_lap_time_in_ms as i32,
+                result_status: classification.result_status as i16,
+                position: classification.position as i16,
+                points: classification.points as i16,
+            });
+        }
+
+        let data = self.packet_handler.encode_general();
+
+        if let Err(e) = self
+            .f1_state
+            .championship_svc
+            .add_race_result(self.race_id, session_type as i16, &data, &entries)
+            .await
+        {
+            error!("Error storing session result: {}", e);
+        }
+    }
+
     #[inline]
     fn handle_car_damage_packet(&mut self, car_damage: &PacketCarDamageData, now: Instant) {
+        let alerts = self.damage_alerts.update(&car_damage.car_damage_data);
+        if !alerts.is_empty() {
+            self.packet_handler.push_damage_alerts(&alerts);
+        }
+
         if now.duration_since(self.timestamps.car_damage) > TELEMETRY_INTERVAL {
             self.packet_handler.save_car_damage(car_damage);
         }
     }
 
+    #[inline]
+    async fn handle_car_setups_packet(&mut self, car_setups: &PacketCarSetupData, now: Instant) {
+        if now.duration_since(self.timestamps.car_setups) < SETUPS_INTERVAL {
+            return;
+        }
+
+        self.packet_handler.save_car_setups(car_setups);
+        self.timestamps.car_setups = now;
+
+        let Some(session_type) = self.session_type else {
+            return;
+        };
+
+        // The race setups are locked by parc fermé, the first ones received are enough
+        if self.race_id != 0
+            && self.setups_stored != Some(session_type)
+            && [SessionType::R, SessionType::R2, SessionType::R3].contains(&session_type)
+        {
+            self.setups_stored = Some(session_type);
+            self.store_setups(session_type).await;
+        }
+    }
+
+    /// Stores the setup of every driver so stewards can compare qualifying and race setups
+    async fn store_setups(&self, session_type: SessionType) {
+        for (steam_name, setup) in self.packet_handler.car_setups() {
+            let snapshot = SetupSnapshotData {
+                session_type: session_type as i16,
+                steam_name,
+                setup,
+            };
+
+            if let Err(e) = self
+                .f1_state
+                .steward_svc
+                .add_setup_snapshot(self.race_id, &snapshot)
+                .await
+            {
+                error!("Error storing setup snapshot: {}", e);
+            }
+        }
+    }
+
+    /// Stores the wear of the power unit every driver ended the session with
+    async fn store_engine_wear(&self, session_type: SessionType) {
+        for wear in self.packet_handler.engine_wear(session_type as i16) {
+            if let Err(e) = self
+                .f1_state
+                .race_svc
+                .add_engine_wear(self.race_id, &wear)
+                .await
+            {
+                error!("Error storing engine wear: {}", e);
+            }
+        }
+    }
+
     #[inline]
     fn handle_car_status_packet(&mut self, car_status: &PacketCarStatusData, now: Instant) {
+        self.assists.update_status(&car_status.car_status_data);
+
         if now.duration_since(self.timestamps.car_status) > TELEMETRY_INTERVAL {
             self.packet_handler.save_car_status(car_status);
         }
@@ -459,12 +1111,126 @@ impl F1LiveTelemetryService {
         car_telemetry: &PacketCarTelemetryData,
         now: Instant,
     ) {
+        // Input patterns need every sample
+        self.assists
+            .update_telemetry(&car_telemetry.car_telemetry_data);
+        self.lap_traces
+            .update_telemetry(&car_telemetry.car_telemetry_data);
+
         if now.duration_since(self.timestamps.car_telemetry) > TELEMETRY_INTERVAL {
             self.packet_handler.save_car_telemetry(car_telemetry);
         }
     }
 
-    /// Ensures all participants are registered in the system
+    #[inline]
+    fn handle_tyre_sets_packet(&mut self, tyre_sets: &PacketTyreSetsData, now: Instant) {
+        let Some(last_update) = self
+            .timestamps
+            .car_tyre_sets
+            .get_mut(tyre_sets.car_idx as usize)
+        else {
+            warn!("CarIdx out of bounds");
+            return;
+        };
+
+        if now.duration_since(*last_update) > HISTORY_INTERVAL {
+            self.packet_handler.save_tyre_sets(tyre_sets);
+            *last_update = now;
+        }
+    }
+
+    /// Stores the time trial laps of the player when the game reports new times
+    async fn handle_time_trial_packet(&mut self, time_trial: &PacketTimeTrialData) {
+        let (Some(SessionType::TimeTrial), Some(track_id)) = (self.session_type, self.track_id)
+        else {
+            return;
+        };
+
+        let Some(steam_name) = self
+            .packet_handler
+            .driver_name(time_trial.header.player_car_index)
+        else {
+            return;
+        };
+
+        // The rival data set is a lap of another player, only the laps of the player are kept
+        let data_sets = [
+            (
+                TimeTrialLapKind::SessionBest,
+                &time_trial.player_session_best_data_set,
+            ),
+            (
+                TimeTrialLapKind::PersonalBest,
+                &time_trial.personal_best_data_set,
+            ),
+        ];
+
+        for ((kind, data_set), last_lap_time) in
+            data_sets.into_iter().zip(&mut self.time_trial_laps)
+        {
+            let lap_time = data_set.lap_time_in_ms;
+
+            if lap_time == 0 || lap_time == *last_lap_time {
+                continue;
+            }
+
+            *last_lap_time = lap_time;
+            let lap = time_trial_lap(steam_name.clone(), track_id, kind, data_set);
+
+            if let Err(e) = self
+                .f1_state
+                .championship_svc
+                .add_time_trial_lap(self.championship_id, &lap)
+                .await
+            {
+                error!("Error storing time trial lap: {}", e);
+            }
+        }
+    }
+
+    #[inline]
+    async fn handle_lap_data_packet(&mut self, lap_data: &PacketLapData, now: Instant) {
+        let traces = self.lap_traces.update_laps(&lap_data.lap_data);
+        if !traces.is_empty() {
+            self.store_lap_traces(traces).await;
+        }
+
+        if self.recording_outline || self.packet_handler.has_positions_receivers() {
+            self.packet_handler.save_lap_distances(lap_data);
+        }
+
+        if now.duration_since(self.timestamps.lap_data) > TELEMETRY_INTERVAL {
+            self.packet_handler.save_lap_data(lap_data);
+            self.timestamps.lap_data = now;
+        }
+    }
+
+    /// Stores the telemetry traces of the laps the drivers just completed
+    async fn store_lap_traces(&self, traces: Vec<LapTrace>) {
+        let Some(session_type) = self.session_type.filter(|_| self.race_id != 0) else {
+            return;
+        };
+
+        for trace in traces {
+            let Some(steam_name) = self.packet_handler.driver_name(trace.vehicle_idx) else {
+                continue;
+            };
+
+            let trace = lap_trace_data(steam_name, session_type, trace);
+
+            if let Err(e) = self
+                .f1_state
+                .race_svc
+                .add_lap_trace(self.race_id, &trace)
+                .await
+            {
+                error!("Error storing lap trace: {}", e);
+            }
+        }
+    }
+
+    /// Ensures all participants are registered in the system. Reserves entered in the race
+    /// in place of a regular driver are not added to the championship roster
     #[inline]
     async fn ensure_participants_registered(
         &self,
@@ -476,6 +1242,8 @@ impl F1LiveTelemetryService {
             .drivers_linked(self.championship_id)
             .await?;
 
+        let substitutes = self.race_substitutes().await?;
+
         drivers.sort_unstable();
 
         for idx in 0..participants_data.num_active_cars {
@@ -497,32 +1265,139 @@ impl F1LiveTelemetryService {
                 continue;
             }
 
-            if self.f1_state.driver_repo.find(steam_name).await?.is_none() {
-                self.f1_state
-                    .driver_svc
-                    .create(steam_name, participant.nationality as i16, None)
+            if drivers
+                .binary_search_by(|probe| probe.as_ref().cmp(steam_name))
+                .is_ok()
+            {
+                continue;
+            }
+
+            if substitutes
+                .iter()
+                .any(|substitute| substitute.as_ref() == steam_name)
+            {
+                self.ensure_driver(steam_name, participant.nationality)
                     .await?;
+            } else {
+                self.register_driver(
+                    steam_name,
+                    participant.nationality,
+                    participant.team_id,
+                    participant.race_number,
+                )
+                .await?;
             }
+        }
 
-            if drivers
-                .binary_search_by(|probe| probe.as_ref().cmp(steam_name))
-                .is_err()
+        Ok(())
+    }
+
+    /// Links the drivers racing with the verification code of a pending claim as race number
+    /// to the user that claimed them
+    async fn verify_driver_claims(
+        &self,
+        participants_data: &PacketParticipantsData,
+    ) -> AppResult<()> {
+        let claims = self
+            .f1_state
+            .driver_repo
+            .pending_claims(self.championship_id)
+            .await?;
+
+        if claims.is_empty() {
+            return Ok(());
+        }
+
+        for idx in 0..participants_data.num_active_cars {
+            let Some(participant) = participants_data.participants.get(idx as usize) else {
+                error!("Participant id out of bounce");
+                continue;
+            };
+
+            let Some(steam_name) = participant.steam_name() else {
+                continue;
+            };
+
+            let Some(claim) = claims.iter().find(|claim| {
+                claim.steam_name.as_ref() == steam_name
+                    && claim.verification_code == participant.race_number as i16
+            }) else {
+                continue;
+            };
+
+            if let Err(e) = self
+                .f1_state
+                .driver_svc
+                .verify_claim(steam_name, claim.user_id)
+                .await
             {
-                self.f1_state
-                    .championship_svc
-                    .add_driver(
-                        self.championship_id,
-                        steam_name,
-                        participant.team_id as i16,
-                        participant.race_number as i16,
-                    )
+                error!("Error verifying driver {}: {}", steam_name, e);
+                continue;
+            }
+
+            info!("Driver {} verified for user {}", steam_name, claim.user_id);
+        }
+
+        Ok(())
+    }
+
+    /// Reserves entered in the race of the session or, while no session linked it yet, in
+    /// the race scheduled closest to now
+    async fn race_substitutes(&self) -> AppResult<Vec<Box<str>>> {
+        let race_id = match self.race_id {
+            0 => {
+                let races = self
+                    .f1_state
+                    .championship_repo
+                    .races(self.championship_id)
                     .await?;
+
+                let Some(race) = scheduled_race(&races, None, Utc::now()) else {
+                    return Ok(Vec::new());
+                };
+
+                race.id
             }
+            race_id => race_id,
+        };
+
+        let entries = self.f1_state.race_repo.entries(race_id).await?;
+        Ok(substitutes(&entries))
+    }
+
+    /// Creates the driver if it's the first time it races
+    async fn ensure_driver(&self, steam_name: &str, nationality: u8) -> AppResult<()> {
+        if self.f1_state.driver_repo.find(steam_name).await?.is_none() {
+            self.f1_state
+                .driver_svc
+                .create(steam_name, nationality as i16, None)
+                .await?;
         }
 
         Ok(())
     }
 
+    /// Adds a driver to the championship, creating it first if it's the first time it races
+    async fn register_driver(
+        &self,
+        steam_name: &str,
+        nationality: u8,
+        team_id: u8,
+        number: u8,
+    ) -> AppResult<()> {
+        self.ensure_driver(steam_name, nationality).await?;
+
+        self.f1_state
+            .championship_svc
+            .add_driver(
+                self.championship_id,
+                steam_name,
+                team_id as i16,
+                number as i16,
+            )
+            .await
+    }
+
     /// Increment error count a returns true if we passed the max cast errors
     #[inline]
     fn increment_error_count(&mut self) -> bool {
@@ -557,6 +1432,10 @@ impl F1SessionBroadcaster {
             global_channel,
             global_subscribers: AtomicU32::new(0),
             team_subscribers: RwLock::new(AHashMap::new()),
+            driver_subscribers: RwLock::new(AHashMap::new()),
+            steward_subscribers: AtomicU32::new(0),
+            positions_subscribers: AtomicU32::new(0),
+            director_subscribers: AtomicU32::new(0),
         });
 
         Self {
@@ -579,6 +1458,46 @@ impl F1SessionBroadcaster {
         self.global_channel.subscribe()
     }
 
+    /// Retrieves the cached stewarding data and subscribes to the stewarding feed
+    #[inline]
+    pub fn steward_sub(&self) -> (Option<Bytes>, Receiver<Bytes>) {
+        self.steward_subscribers.fetch_add(1, Ordering::Relaxed);
+        (
+            self.packet_handler.steward_cache(),
+            self.packet_handler.get_steward_receiver(),
+        )
+    }
+
+    /// Retrieves the recorded track outline and subscribes to the positions feed
+    #[inline]
+    pub fn positions_sub(&self) -> (Option<Bytes>, Receiver<Bytes>) {
+        self.positions_subscribers.fetch_add(1, Ordering::Relaxed);
+        (
+            self.packet_handler.positions_cache(),
+            self.packet_handler.get_positions_receiver(),
+        )
+    }
+
+    /// Retrieves the full race director state and subscribes to the race director feed
+    #[inline]
+    pub fn director_sub(&self) -> (Option<Bytes>, Receiver<Bytes>) {
+        self.director_subscribers.fetch_add(1, Ordering::Relaxed);
+        (
+            self.packet_handler.director_cache(),
+            self.packet_handler.get_director_receiver(),
+        )
+    }
+
+    /// Subscribes to the telemetry of a single driver
+    #[inline]
+    pub fn driver_sub(&self, steam_name: &str) -> Option<Receiver<Bytes>> {
+        // Held while subscribing so the channel can't be removed by a concurrent unsubscribe
+        let mut driver_subs = self.driver_subscribers.write();
+        let receiver = self.packet_handler.get_driver_receiver(steam_name)?;
+        *driver_subs.entry(steam_name.into()).or_insert(0) += 1;
+        Some(receiver)
+    }
+
     /// Subscribes to a team-specific broadcast channel
     #[inline]
     pub fn team_sub(&self, team_id: u8) -> Option<Receiver<Bytes>> {
@@ -600,6 +1519,30 @@ impl F1SessionBroadcaster {
         self.team_subscribers.read().values().sum()
     }
 
+    /// Gets the current number of subscribers for all drivers
+    #[inline]
+    pub fn all_driver_count(&self) -> u32 {
+        self.driver_subscribers.read().values().sum()
+    }
+
+    /// Gets the current number of subscribers to the stewarding feed
+    #[inline]
+    pub fn steward_count(&self) -> u32 {
+        self.steward_subscribers.load(Ordering::Relaxed)
+    }
+
+    /// Gets the current number of subscribers to the positions feed
+    #[inline]
+    pub fn positions_count(&self) -> u32 {
+        self.positions_subscribers.load(Ordering::Relaxed)
+    }
+
+    /// Gets the current number of subscribers to the race director feed
+    #[inline]
+    pub fn director_count(&self) -> u32 {
+        self.director_subscribers.load(Ordering::Relaxed)
+    }
+
     /// Gets the current number of subscribers for a specific team
     #[inline]
     #[allow(unused)]
@@ -624,6 +1567,39 @@ impl F1SessionBroadcaster {
         }
     }
 
+    /// Decrements the driver subscriber count, removing the channel of the driver once
+    /// nobody follows them
+    #[inline]
+    pub fn driver_unsub(&self, steam_name: &str) {
+        let mut driver_subs = self.driver_subscribers.write();
+        if let Some(count) = driver_subs.get_mut(steam_name) {
+            *count = count.saturating_sub(1);
+
+            if *count == 0 {
+                driver_subs.remove(steam_name);
+                self.packet_handler.remove_driver_sender(steam_name);
+            }
+        }
+    }
+
+    /// Decrements the stewarding subscriber count
+    #[inline]
+    pub fn steward_unsub(&self) {
+        self.steward_subscribers.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// Decrements the positions subscriber count
+    #[inline]
+    pub fn positions_unsub(&self) {
+        self.positions_subscribers.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// Decrements the race director subscriber count
+    #[inline]
+    pub fn director_unsub(&self) {
+        self.director_subscribers.fetch_sub(1, Ordering::Relaxed);
+    }
+
     /// Initiates the shutdown process for the service
     pub fn shutdown(&mut self) -> Result<(), ()> {
         self.shutdown.take().unwrap().send(())
@@ -646,15 +1622,71 @@ impl PacketProcessingTimestamps {
         Self {
             session: time,
             car_motion: time,
+            car_motion_ex: time,
+            positions: time,
             participants: time,
+            lobby: time,
+            weather: time,
             car_damage: time,
+            car_setups: time,
             car_status: time,
             car_telemetry: time,
+            lap_data: time,
             car_lap: [time; 22],
+            car_tyre_sets: [time; 22],
         }
     }
 }
 
+/// Builds the stored time trial lap from a game data set
+#[inline]
+fn time_trial_lap(
+    steam_name: Box<str>,
+    track_id: i16,
+    kind: TimeTrialLapKind,
+    data_set: &TimeTrialDataSet,
+) -> TimeTrialLapData {
+    TimeTrialLapData {
+        steam_name,
+        track_id,
+        kind,
+        team_id: data_set.team_id as i16,
+        lap_time: data_set.lap_time_in_ms as i32,
+        sector1_time: data_set.sector1_time_in_ms as i32,
+        sector2_time: data_set.sector2_time_in_ms as i32,
+        sector3_time: data_set.sector3_time_in_ms as i32,
+        traction_control: data_set.traction_control as i16,
+        gearbox_assist: data_set.gearbox_assist as i16,
+        anti_lock_brakes: data_set.anti_lock_brakes == 1,
+        equal_car_performance: data_set.equal_car_performance == 1,
+        custom_setup: data_set.custom_setup == 1,
+        valid: data_set.valid == 1,
+    }
+}
+
+/// Builds the stored lap trace from a completed lap
+#[inline]
+fn lap_trace_data(
+    steam_name: Box<str>,
+    session_type: SessionType,
+    trace: LapTrace,
+) -> LapTraceData {
+    LapTraceData {
+        session_type: session_type as i16,
+        steam_name,
+        lap_number: trace.lap_number as i16,
+        lap_time: trace.lap_time as i32,
+        valid: trace.valid,
+        step: TRACE_STEP as i16,
+        time: trace.time,
+        speed: trace.speed,
+        throttle: trace.throttle,
+        brake: trace.brake,
+        gear: trace.gear,
+        steer: trace.steer,
+    }
+}
+
 /// Casts raw bytes to a PacketHeader reference
 #[inline]
 fn header_cast(bytes: &[u8]) -> AppResult<&PacketHeader> {
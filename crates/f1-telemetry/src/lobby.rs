@@ -0,0 +1,147 @@
+use entities::ChampionshipDriver;
+
+use crate::types::PacketLobbyInfoData;
+
+// Constants
+const NO_TEAM: u8 = 255;
+
+/// A human player waiting in the lobby, checked against the championship roster
+#[derive(Debug, PartialEq)]
+pub struct LobbyEntry {
+    pub steam_name: Box<str>,
+    pub team_id: u8,
+    pub nationality: u8,
+    pub platform: u8,
+    pub car_number: u8,
+    pub ready_status: u8,
+    /// Whether the player was already a driver of the championship
+    pub registered: bool,
+    /// Whether the player is a reserve entered in place of a regular driver
+    pub substitute: bool,
+    /// Team the driver is registered with, when the lobby shows a different one
+    pub expected_team_id: Option<i16>,
+    /// Number the driver is registered with, when the lobby shows a different one
+    pub expected_number: Option<i16>,
+}
+
+impl LobbyEntry {
+    /// New players can only be registered once they picked a team, reserves never join
+    /// the roster
+    #[inline]
+    pub fn can_register(&self) -> bool {
+        !self.registered && !self.substitute && self.team_id != NO_TEAM
+    }
+}
+
+/// Compares the players in the lobby with the drivers registered in the championship
+pub fn check_roster(
+    packet: &PacketLobbyInfoData,
+    drivers: &[ChampionshipDriver],
+    substitutes: &[Box<str>],
+) -> Vec<LobbyEntry> {
+    let num_players = (packet.num_players as usize).min(packet.lobby_players.len());
+
+    packet.lobby_players[..num_players]
+        .iter()
+        .filter(|player| player.ai_controlled == 0)
+        .filter_map(|player| {
+            let steam_name = player.steam_name().filter(|name| *name != "Player")?;
+            let driver = drivers
+                .iter()
+                .find(|driver| *driver.steam_name == *steam_name);
+
+            let (team_id, car_number) = (player.team_id, player.car_number);
+
+            Some(LobbyEntry {
+                steam_name: steam_name.into(),
+                team_id,
+                nationality: player.nationality,
+                platform: player.platform,
+                car_number,
+                ready_status: player.ready_status,
+                registered: driver.is_some(),
+                substitute: substitutes.iter().any(|name| **name == *steam_name),
+                expected_team_id: driver
+                    .filter(|driver| team_id != NO_TEAM && driver.team_id != team_id as i16)
+                    .map(|driver| driver.team_id),
+                expected_number: driver
+                    .filter(|driver| driver.number != car_number as i16)
+                    .map(|driver| driver.number),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::*;
+
+    fn lobby_packet(players: &[(&str, u8, u8)]) -> Box<PacketLobbyInfoData> {
+        // SAFETY: every field of the packet is a plain integer or byte array
+        let mut packet: Box<PacketLobbyInfoData> = Box::new(unsafe { mem::zeroed() });
+
+        packet.num_players = players.len() as u8;
+
+        for (idx, &(name, team_id, car_number)) in players.iter().enumerate() {
+            let player = &mut packet.lobby_players[idx];
+
+            player.name[..name.len()].copy_from_slice(name.as_bytes());
+            player.team_id = team_id;
+            player.car_number = car_number;
+        }
+
+        packet
+    }
+
+    fn driver(steam_name: &str, team_id: i16, number: i16) -> ChampionshipDriver {
+        ChampionshipDriver {
+            steam_name: steam_name.into(),
+            team_id,
+            number,
+            division_id: None,
+        }
+    }
+
+    #[test]
+    fn flags_team_and_number_mismatches() {
+        let packet = lobby_packet(&[("a", 1, 44), ("b", 2, 7)]);
+        let drivers = [driver("a", 1, 44), driver("b", 3, 8)];
+
+        let roster = check_roster(&packet, &drivers, &[]);
+
+        assert_eq!(roster.len(), 2);
+        assert!(roster[0].registered);
+        assert_eq!(roster[0].expected_team_id, None);
+        assert_eq!(roster[0].expected_number, None);
+        assert_eq!(roster[1].expected_team_id, Some(3));
+        assert_eq!(roster[1].expected_number, Some(8));
+    }
+
+    #[test]
+    fn new_players_need_a_team_to_register() {
+        let packet = lobby_packet(&[("a", NO_TEAM, 10), ("b", 4, 11), ("Player", 5, 12)]);
+
+        let roster = check_roster(&packet, &[], &[]);
+
+        assert_eq!(roster.len(), 2);
+        assert!(!roster[0].can_register());
+        assert_eq!(roster[0].expected_team_id, None);
+        assert!(roster[1].can_register());
+    }
+
+    #[test]
+    fn reserves_are_not_registered() {
+        let packet = lobby_packet(&[("a", 1, 44), ("reserve", 1, 2)]);
+        let drivers = [driver("a", 1, 44), driver("b", 1, 7)];
+
+        let roster = check_roster(&packet, &drivers, &[Box::from("reserve")]);
+
+        assert_eq!(roster.len(), 2);
+        assert!(!roster[0].substitute);
+        assert!(roster[1].substitute);
+        assert!(!roster[1].registered);
+        assert!(!roster[1].can_register());
+    }
+}
@@ -0,0 +1,116 @@
+use entities::{ChampionshipRule, SessionRule};
+use structs::RuleDeviationData;
+
+use crate::types::PacketSessionData;
+
+/// Compares the settings of a session against the rules required by the championship
+pub fn check_rules(
+    rules: &[ChampionshipRule],
+    packet: &PacketSessionData,
+) -> Vec<RuleDeviationData> {
+    rules
+        .iter()
+        .filter_map(|required| {
+            let actual = session_setting(packet, required.rule) as i16;
+
+            (actual != required.value).then_some(RuleDeviationData {
+                session_type: packet.session_type as i16,
+                rule: required.rule,
+                expected: required.value,
+                actual,
+            })
+        })
+        .collect()
+}
+
+/// Reads the value of a rule from the session settings
+#[inline]
+fn session_setting(packet: &PacketSessionData, rule: SessionRule) -> u8 {
+    match rule {
+        SessionRule::SteeringAssist => packet.steering_assist,
+        SessionRule::BrakingAssist => packet.braking_assist,
+        SessionRule::GearboxAssist => packet.gearbox_assist,
+        SessionRule::PitAssist => packet.pit_assist,
+        SessionRule::PitReleaseAssist => packet.pit_release_assist,
+        SessionRule::ErsAssist => packet.ers_assist,
+        SessionRule::DrsAssist => packet.drs_assist,
+        SessionRule::DynamicRacingLine => packet.dynamic_racing_line,
+        SessionRule::EqualCarPerformance => packet.equal_car_performance,
+        SessionRule::RecoveryMode => packet.recovery_mode,
+        SessionRule::FlashbackLimit => packet.flashback_limit,
+        SessionRule::SurfaceType => packet.surface_type,
+        SessionRule::LowFuelMode => packet.low_fuel_mode,
+        SessionRule::RaceStarts => packet.race_starts,
+        SessionRule::TyreTemperature => packet.tyre_temperature,
+        SessionRule::PitLaneTyreSim => packet.pit_lane_tyre_sim,
+        SessionRule::CarDamage => packet.car_damage,
+        SessionRule::CarDamageRate => packet.car_damage_rate,
+        SessionRule::Collisions => packet.collisions,
+        SessionRule::CollisionsOffForFirstLapOnly => packet.collisions_off_for_first_lap_only,
+        SessionRule::UnsafePitRelease => packet.mp_unsafe_pit_release,
+        SessionRule::OffForGriefing => packet.mp_off_for_griefing,
+        SessionRule::CornerCuttingStringency => packet.corner_cutting_stringency,
+        SessionRule::ParcFermeRules => packet.parc_ferme_rules,
+        SessionRule::SafetyCar => packet.safety_car,
+        SessionRule::FormationLap => packet.formation_lap,
+        SessionRule::RedFlags => packet.red_flags,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::*;
+
+    fn session_packet() -> Box<PacketSessionData> {
+        // SAFETY: every field of the packet is a plain integer or float
+        let mut packet: Box<PacketSessionData> = Box::new(unsafe { mem::zeroed() });
+
+        packet.session_type = 15;
+        packet.car_damage = 3;
+        packet.collisions = 2;
+        packet.parc_ferme_rules = 1;
+        packet
+    }
+
+    #[test]
+    fn matching_settings_have_no_deviations() {
+        let rules = [
+            ChampionshipRule {
+                rule: SessionRule::CarDamage,
+                value: 3,
+            },
+            ChampionshipRule {
+                rule: SessionRule::ParcFermeRules,
+                value: 1,
+            },
+        ];
+
+        assert!(check_rules(&rules, &session_packet()).is_empty());
+    }
+
+    #[test]
+    fn reports_settings_that_differ() {
+        let rules = [
+            ChampionshipRule {
+                rule: SessionRule::Collisions,
+                value: 2,
+            },
+            ChampionshipRule {
+                rule: SessionRule::EqualCarPerformance,
+                value: 1,
+            },
+        ];
+
+        assert_eq!(
+            check_rules(&rules, &session_packet()),
+            vec![RuleDeviationData {
+                session_type: 15,
+                rule: SessionRule::EqualCarPerformance,
+                expected: 1,
+                actual: 0,
+            }]
+        );
+    }
+}
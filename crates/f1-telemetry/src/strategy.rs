@@ -0,0 +1,264 @@
+use std::collections::VecDeque;
+
+use crate::types::PacketLapData;
+
+// Constants
+const DEFAULT_PIT_LOSS: u32 = 22_000;
+const MIN_PIT_LOSS: u32 = 8_000;
+const MAX_PIT_LOSS: u32 = 60_000;
+const PACE_SAMPLES: usize = 5;
+const ACTIVE_RESULT_STATUS: u8 = 2;
+
+/// Prediction for a car stopping at the end of the current lap
+#[derive(Debug, Default, PartialEq)]
+pub struct PitPrediction {
+    pub pit_loss: u32,
+    pub rejoin_position: u8,
+    pub rejoin_gap_ahead: Option<u32>,
+    pub rejoin_gap_behind: Option<u32>,
+    pub tyre_delta: u32,
+    pub undercut_gain: Option<i32>,
+    pub overcut_margin: Option<i32>,
+}
+
+/// Tracks lap times, gaps and pit stops for every car to predict pit stop outcomes
+#[derive(Debug, Default)]
+pub struct PitStrategyPredictor {
+    cars: [CarStrategyState; 22],
+    pit_losses: Vec<u32>,
+    safety_car: bool,
+}
+
+#[derive(Debug, Default)]
+struct CarStrategyState {
+    active: bool,
+    lap_num: u8,
+    position: u8,
+    delta_to_leader: u32,
+    pitted_this_lap: bool,
+    pit_laps: u32,
+    pit_laps_time: u32,
+    clean_laps: VecDeque<u32>,
+    stint_best: Option<u32>,
+}
+
+impl PitStrategyPredictor {
+    /// Updates the safety car state, laps run under it are not used as pace reference
+    #[inline]
+    pub fn set_safety_car(&mut self, active: bool) {
+        self.safety_car = active;
+    }
+
+    /// Updates every car state with a new lap data packet
+    pub fn update(&mut self, packet: &PacketLapData) {
+        for (car, lap_data) in self.cars.iter_mut().zip(packet.lap_data.iter()) {
+            car.active = lap_data.result_status == ACTIVE_RESULT_STATUS;
+
+            if !car.active {
+                continue;
+            }
+
+            car.position = lap_data.car_position;
+            car.delta_to_leader = lap_data.delta_to_race_leader_minutes_part as u32 * 60_000
+                + lap_data.delta_to_race_leader_ms_part as u32;
+
+            if lap_data.current_lap_num > car.lap_num {
+                if car.lap_num > 0 {
+                    if let Some(pit_loss) =
+                        car.complete_lap(lap_data.last_lap_time_in_ms, self.safety_car)
+                    {
+                        self.pit_losses.push(pit_loss);
+                    }
+                }
+
+                car.lap_num = lap_data.current_lap_num;
+            }
+
+            if lap_data.pit_status != 0 {
+                car.pitted_this_lap = true;
+            }
+        }
+    }
+
+    /// Average time lost by a pit stop in this session, measured from completed stops
+    #[inline]
+    pub fn pit_loss(&self) -> u32 {
+        if self.pit_losses.is_empty() {
+            return DEFAULT_PIT_LOSS;
+        }
+
+        self.pit_losses.iter().sum::<u32>() / self.pit_losses.len() as u32
+    }
+
+    /// Predicts where the car would rejoin if it pitted at the end of the current lap
+    pub fn predict(&self, car_idx: usize) -> Option<PitPrediction> {
+        let car = self.cars.get(car_idx)?;
+
+        if !car.active || car.lap_num == 0 {
+            return None;
+        }
+
+        let pit_loss = self.pit_loss();
+        let projected = car.delta_to_leader + pit_loss;
+
+        let mut prediction = PitPrediction {
+            pit_loss,
+            rejoin_position: 1,
+            tyre_delta: car.tyre_delta(),
+            ..Default::default()
+        };
+
+        for (idx, other) in self.cars.iter().enumerate() {
+            if idx == car_idx || !other.active || other.lap_num == 0 {
+                continue;
+            }
+
+            if other.delta_to_leader <= projected {
+                let gap = projected - other.delta_to_leader;
+                prediction.rejoin_position += 1;
+                prediction.rejoin_gap_ahead = Some(
+                    prediction
+                        .rejoin_gap_ahead
+                        .map_or(gap, |best| best.min(gap)),
+                );
+            } else {
+                let gap = other.delta_to_leader - projected;
+                prediction.rejoin_gap_behind = Some(
+                    prediction
+                        .rejoin_gap_behind
+                        .map_or(gap, |best| best.min(gap)),
+                );
+            }
+
+            if other.position + 1 == car.position {
+                let gap = car.delta_to_leader.saturating_sub(other.delta_to_leader);
+                prediction.undercut_gain = Some(prediction.tyre_delta as i32 - gap as i32);
+            } else if other.position == car.position + 1 {
+                let gap = other.delta_to_leader.saturating_sub(car.delta_to_leader);
+                prediction.overcut_margin = Some(gap as i32 - other.tyre_delta() as i32);
+            }
+        }
+
+        Some(prediction)
+    }
+}
+
+impl CarStrategyState {
+    /// Registers a completed lap, returning the measured pit loss if a stop just finished
+    fn complete_lap(&mut self, lap_time: u32, safety_car: bool) -> Option<u32> {
+        let mut pit_loss = None;
+
+        if self.pitted_this_lap {
+            self.pit_laps += 1;
+            self.pit_laps_time += lap_time;
+            self.pitted_this_lap = false;
+            return None;
+        }
+
+        if self.pit_laps > 0 {
+            if let Some(pace) = self.pace() {
+                let loss = self.pit_laps_time.saturating_sub(pace * self.pit_laps);
+
+                if !safety_car && (MIN_PIT_LOSS..=MAX_PIT_LOSS).contains(&loss) {
+                    pit_loss = Some(loss);
+                }
+            }
+
+            self.pit_laps = 0;
+            self.pit_laps_time = 0;
+            self.clean_laps.clear();
+            self.stint_best = None;
+        }
+
+        // The first lap is skipped because of the standing start
+        if !safety_car && lap_time > 0 && self.lap_num > 1 {
+            if self.clean_laps.len() == PACE_SAMPLES {
+                self.clean_laps.pop_front();
+            }
+
+            self.clean_laps.push_back(lap_time);
+            self.stint_best = Some(self.stint_best.map_or(lap_time, |best| best.min(lap_time)));
+        }
+
+        pit_loss
+    }
+
+    /// Average of the last clean laps
+    #[inline]
+    fn pace(&self) -> Option<u32> {
+        if self.clean_laps.is_empty() {
+            return None;
+        }
+
+        Some(self.clean_laps.iter().sum::<u32>() / self.clean_laps.len() as u32)
+    }
+
+    /// Time per lap currently lost to tyre wear compared to the best lap of the stint
+    #[inline]
+    fn tyre_delta(&self) -> u32 {
+        match (self.pace(), self.stint_best) {
+            (Some(pace), Some(best)) => pace.saturating_sub(best),
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::*;
+
+    fn lap_packet(cars: &[(u8, u8, u32, u32, u8)]) -> Box<PacketLapData> {
+        // SAFETY: every field of the packet is a plain integer or float
+        let mut packet: Box<PacketLapData> = Box::new(unsafe { mem::zeroed() });
+
+        for (idx, &(position, lap_num, last_lap, delta, pit_status)) in cars.iter().enumerate() {
+            let lap_data = &mut packet.lap_data[idx];
+            lap_data.result_status = ACTIVE_RESULT_STATUS;
+            lap_data.car_position = position;
+            lap_data.current_lap_num = lap_num;
+            lap_data.last_lap_time_in_ms = last_lap;
+            lap_data.delta_to_race_leader_minutes_part = (delta / 60_000) as u8;
+            lap_data.delta_to_race_leader_ms_part = (delta % 60_000) as u16;
+            lap_data.pit_status = pit_status;
+        }
+
+        packet
+    }
+
+    #[test]
+    fn predicts_rejoin_position_with_default_loss() {
+        let mut predictor = PitStrategyPredictor::default();
+        predictor.update(&lap_packet(&[
+            (1, 5, 90_000, 0, 0),
+            (2, 5, 90_000, 10_000, 0),
+            (3, 5, 90_000, 30_000, 0),
+        ]));
+
+        let prediction = predictor.predict(0).unwrap();
+
+        assert_eq!(prediction.pit_loss, DEFAULT_PIT_LOSS);
+        assert_eq!(prediction.rejoin_position, 2);
+        assert_eq!(prediction.rejoin_gap_ahead, Some(12_000));
+        assert_eq!(prediction.rejoin_gap_behind, Some(8_000));
+        assert_eq!(prediction.overcut_margin, Some(10_000));
+        assert!(predictor.predict(5).is_none());
+    }
+
+    #[test]
+    fn measures_pit_loss_from_in_and_out_laps() {
+        let mut predictor = PitStrategyPredictor::default();
+
+        for lap in 1..=4 {
+            predictor.update(&lap_packet(&[(1, lap, 90_000, 0, 0)]));
+        }
+
+        predictor.update(&lap_packet(&[(1, 4, 90_000, 0, 1)]));
+        predictor.update(&lap_packet(&[(1, 5, 100_000, 0, 1)]));
+        predictor.update(&lap_packet(&[(1, 6, 103_000, 0, 0)]));
+        predictor.update(&lap_packet(&[(1, 7, 89_000, 0, 0)]));
+
+        assert_eq!(predictor.pit_loss(), 23_000);
+    }
+}
@@ -0,0 +1,277 @@
+use std::f32::consts::PI;
+
+use structs::TrackOutlineData;
+
+// Constants
+/// Distance in metres covered by every point of the outline
+pub const OUTLINE_STEP: f32 = 10.0;
+/// Steps of a full turn used to quantize the heading
+const HEADING_STEPS: f32 = 1024.0;
+
+/// World position in decimetres
+#[inline]
+pub fn quantize_position(value: f32) -> i32 {
+    (value * 10.0).round() as i32
+}
+
+/// Yaw in 1/1024 of a turn, starting at -π
+#[inline]
+pub fn quantize_heading(yaw: f32) -> u32 {
+    let turn = (yaw + PI) / (2.0 * PI);
+    ((turn * HEADING_STEPS).round() as u32) % HEADING_STEPS as u32
+}
+
+/// G-force in hundredths of g
+#[inline]
+pub fn quantize_g_force(value: f32) -> i32 {
+    (value * 100.0).round() as i32
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct OutlinePoint {
+    x: f32,
+    z: f32,
+    samples: u32,
+}
+
+impl OutlinePoint {
+    /// Adds a sample to the running average, returns true for the first one
+    #[inline]
+    fn add(&mut self, x: f32, z: f32) -> bool {
+        self.samples = self.samples.saturating_add(1);
+        self.x += (x - self.x) / self.samples as f32;
+        self.z += (z - self.z) / self.samples as f32;
+        self.samples == 1
+    }
+}
+
+/// Builds the outline of a track by averaging the positions of the cars every
+/// [`OUTLINE_STEP`] metres of lap distance. Positions in the pit lane are kept apart
+#[derive(Debug, Default)]
+pub struct TrackOutlineBuilder {
+    track_id: i8,
+    track_length: u16,
+    sector2_start: f32,
+    sector3_start: f32,
+    points: Vec<OutlinePoint>,
+    pit_lane: Vec<OutlinePoint>,
+    filled: usize,
+}
+
+impl TrackOutlineBuilder {
+    /// Starts a new outline when the track changes, keeping the current one otherwise
+    pub fn reset(
+        &mut self,
+        track_id: i8,
+        track_length: u16,
+        sector2_start: f32,
+        sector3_start: f32,
+    ) {
+        self.sector2_start = sector2_start;
+        self.sector3_start = sector3_start;
+
+        if self.track_id == track_id && self.track_length == track_length {
+            return;
+        }
+
+        let len = (track_length as f32 / OUTLINE_STEP).ceil() as usize;
+
+        self.track_id = track_id;
+        self.track_length = track_length;
+        self.points = vec![OutlinePoint::default(); len];
+        self.pit_lane = vec![OutlinePoint::default(); len];
+        self.filled = 0;
+    }
+
+    /// Adds a car position to the outline, returns true when it completed the outline
+    pub fn record(&mut self, lap_distance: f32, x: f32, z: f32, in_pits: bool) -> bool {
+        if lap_distance < 0.0 {
+            return false;
+        }
+
+        let idx = (lap_distance / OUTLINE_STEP) as usize;
+
+        if in_pits {
+            if let Some(point) = self.pit_lane.get_mut(idx) {
+                point.add(x, z);
+            }
+
+            return false;
+        }
+
+        let Some(point) = self.points.get_mut(idx) else {
+            return false;
+        };
+
+        if !point.add(x, z) {
+            return false;
+        }
+
+        self.filled += 1;
+        self.is_complete()
+    }
+
+    #[inline]
+    pub fn track_id(&self) -> i8 {
+        self.track_id
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.filled == 0
+    }
+
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        !self.points.is_empty() && self.filled == self.points.len()
+    }
+
+    #[inline]
+    pub fn has_pit_lane(&self) -> bool {
+        self.pit_lane.iter().any(|point| point.samples > 0)
+    }
+
+    /// Quantized x and z pairs of the recorded points, in lap distance order
+    pub fn points(&self) -> Vec<i32> {
+        self.points
+            .iter()
+            .filter(|point| point.samples > 0)
+            .flat_map(|point| [quantize_position(point.x), quantize_position(point.z)])
+            .collect()
+    }
+
+    /// Points of the pit lane from entry to exit. The pit lane usually crosses the start
+    /// line, so it starts after the longest stretch of the lap without pit lane points
+    fn pit_lane_points(&self) -> Vec<OutlinePoint> {
+        let len = self.pit_lane.len();
+        let (mut start, mut longest, mut gap) = (0, 0, 0);
+
+        for idx in 0..len * 2 {
+            if self.pit_lane[idx % len].samples > 0 {
+                gap = 0;
+                continue;
+            }
+
+            gap += 1;
+            if gap > longest && gap <= len {
+                longest = gap;
+                start = (idx + 1) % len;
+            }
+        }
+
+        (0..len)
+            .map(|offset| self.pit_lane[(start + offset) % len])
+            .filter(|point| point.samples > 0)
+            .collect()
+    }
+
+    /// Scales the complete outline and pit lane to fit a unit square, marking the points
+    /// where the second and third sectors start
+    pub fn normalize(&self) -> Option<TrackOutlineData> {
+        if !self.is_complete() {
+            return None;
+        }
+
+        let pit_lane = self.pit_lane_points();
+        let (mut min_x, mut min_z) = (f32::MAX, f32::MAX);
+        let (mut max_x, mut max_z) = (f32::MIN, f32::MIN);
+
+        for point in self.points.iter().chain(&pit_lane) {
+            min_x = min_x.min(point.x);
+            min_z = min_z.min(point.z);
+            max_x = max_x.max(point.x);
+            max_z = max_z.max(point.z);
+        }
+
+        let scale = (max_x - min_x).max(max_z - min_z).max(1.0);
+        let normalized = |points: &[OutlinePoint]| {
+            points
+                .iter()
+                .flat_map(|point| [(point.x - min_x) / scale, (point.z - min_z) / scale])
+                .collect()
+        };
+
+        Some(TrackOutlineData {
+            track_id: self.track_id as i16,
+            track_length: self.track_length as i16,
+            origin_x: min_x,
+            origin_z: min_z,
+            scale,
+            points: normalized(&self.points),
+            pit_lane: normalized(&pit_lane),
+            sector2_index: (self.sector2_start / OUTLINE_STEP) as i16,
+            sector3_index: (self.sector3_start / OUTLINE_STEP) as i16,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn averages_positions_per_step() {
+        let mut outline = TrackOutlineBuilder::default();
+        outline.reset(3, 30, 10.0, 20.0);
+
+        assert!(!outline.record(-5.0, 1.0, 1.0, false));
+        assert!(!outline.record(35.0, 1.0, 1.0, false));
+        assert!(!outline.record(15.0, 1.0, 1.0, true));
+        assert!(outline.is_empty());
+
+        outline.record(2.0, 1.0, 4.0, false);
+        outline.record(8.0, 2.0, 6.0, false);
+        outline.record(25.0, 10.0, -3.0, false);
+
+        assert_eq!(outline.points(), vec![15, 50, 100, -30]);
+        assert!(outline.normalize().is_none());
+        assert!(outline.record(12.0, 5.0, 5.0, false));
+        assert!(outline.is_complete());
+
+        // Same track keeps the accumulated points
+        outline.reset(3, 30, 10.0, 20.0);
+        assert!(outline.is_complete());
+
+        outline.reset(4, 30, 10.0, 20.0);
+        assert!(outline.is_empty());
+        assert!(!outline.has_pit_lane());
+    }
+
+    #[test]
+    fn normalizes_outline_and_pit_lane() {
+        let mut outline = TrackOutlineBuilder::default();
+        outline.reset(7, 40, 10.0, 30.0);
+
+        for (idx, (x, z)) in [(0.0, 0.0), (100.0, 0.0), (100.0, 50.0), (0.0, 50.0)]
+            .into_iter()
+            .enumerate()
+        {
+            outline.record(idx as f32 * OUTLINE_STEP, x, z, false);
+        }
+
+        // Pit entry before the line, exit after it
+        outline.record(35.0, 10.0, 40.0, true);
+        outline.record(5.0, 50.0, 40.0, true);
+
+        let normalized = outline.normalize().unwrap();
+
+        assert_eq!(normalized.track_id, 7);
+        assert_eq!(normalized.scale, 100.0);
+        assert_eq!(
+            normalized.points,
+            vec![0.0, 0.0, 1.0, 0.0, 1.0, 0.5, 0.0, 0.5]
+        );
+        assert_eq!(normalized.pit_lane, vec![0.1, 0.4, 0.5, 0.4]);
+        assert_eq!(normalized.sector2_index, 1);
+        assert_eq!(normalized.sector3_index, 3);
+    }
+
+    #[test]
+    fn quantizes_motion() {
+        assert_eq!(quantize_position(-123.456), -1235);
+        assert_eq!(quantize_g_force(1.234), 123);
+        assert_eq!(quantize_heading(-PI), 0);
+        assert_eq!(quantize_heading(0.0), 512);
+        assert_eq!(quantize_heading(PI), 0);
+    }
+}
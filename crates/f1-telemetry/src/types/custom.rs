@@ -2,7 +2,7 @@ use core::str;
 
 use error::{AppError, ChampionshipError};
 
-use super::ParticipantData;
+use super::{LobbyInfoData, ParticipantData};
 
 pub enum PacketIds {
     Motion,
@@ -47,7 +47,7 @@ pub enum EventCode {
     Collision,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SessionType {
     Unknown,
     Practice1,
@@ -508,3 +508,13 @@ impl ParticipantData {
             .filter(|s| !s.is_empty())
     }
 }
+
+impl LobbyInfoData {
+    #[inline]
+    pub fn steam_name(&self) -> Option<&str> {
+        let nul_position = memchr::memchr(0, &self.name)?;
+        str::from_utf8(&self.name[..nul_position])
+            .ok()
+            .filter(|s| !s.is_empty())
+    }
+}
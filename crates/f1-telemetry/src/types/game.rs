@@ -20,6 +20,44 @@ pub struct PacketMotionData {
     pub car_motion_data: [CarMotionData; 22], // Data for all cars on track
 }
 
+#[repr(C, packed)]
+pub struct PacketMotionExData {
+    pub header: PacketHeader,              // Header, player car only
+    pub suspension_position: [f32; 4],     // RL, RR, FL, FR
+    pub suspension_velocity: [f32; 4],     // RL, RR, FL, FR
+    pub suspension_acceleration: [f32; 4], // RL, RR, FL, FR
+    pub wheel_speed: [f32; 4],             // Speed of each wheel
+    pub wheel_slip_ratio: [f32; 4],        // Slip ratio for each wheel
+    pub wheel_slip_angle: [f32; 4],        // Slip angles for each wheel
+    pub wheel_lat_force: [f32; 4],         // Lateral forces for each wheel
+    pub wheel_long_force: [f32; 4],        // Longitudinal forces for each wheel
+    pub height_of_cog_above_ground: f32,   // Height of centre of gravity above ground
+    pub local_velocity_x: f32,             // Velocity in local space - metres/s
+    pub local_velocity_y: f32,             // Velocity in local space
+    pub local_velocity_z: f32,             // Velocity in local space
+    pub angular_velocity_x: f32,           // Angular velocity x-component - radians/s
+    pub angular_velocity_y: f32,           // Angular velocity y-component
+    pub angular_velocity_z: f32,           // Angular velocity z-component
+    pub angular_acceleration_x: f32,       // Angular acceleration x-component - radians/s/s
+    pub angular_acceleration_y: f32,       // Angular acceleration y-component
+    pub angular_acceleration_z: f32,       // Angular acceleration z-component
+    pub front_wheels_angle: f32,           // Current front wheels angle in radians
+    pub wheel_vert_force: [f32; 4],        // Vertical forces for each wheel
+    pub front_aero_height: f32,            // Front plank edge height above road surface
+    pub rear_aero_height: f32,             // Rear plank edge height above road surface
+    pub front_roll_angle: f32,             // Roll angle of the front suspension
+    pub rear_roll_angle: f32,              // Roll angle of the rear suspension
+    pub chassis_yaw: f32, // Chassis yaw relative to the direction of motion - radians
+}
+
+#[repr(C, packed)]
+pub struct PacketLapData {
+    pub header: PacketHeader,         // Header
+    pub lap_data: [LapData; 22],      // Lap data for all cars on track
+    pub time_trial_pb_car_idx: u8,    // Index of Personal Best car in time trial (255 if invalid)
+    pub time_trial_rival_car_idx: u8, // Index of Rival car in time trial (255 if invalid)
+}
+
 #[repr(C, packed)]
 pub struct PacketEventData {
     pub header: PacketHeader,
@@ -60,6 +98,13 @@ pub struct PacketCarDamageData {
     pub car_damage_data: [CarDamageData; 22], // 22
 }
 
+#[repr(C, packed)]
+pub struct PacketCarSetupData {
+    pub header: PacketHeader,           // Header
+    pub car_setups: [CarSetupData; 22], // 22
+    pub next_front_wing_value: f32,     // Value of front wing after next pit stop - player only
+}
+
 #[repr(C, packed)]
 pub struct PacketCarTelemetryData {
     pub header: PacketHeader,                       // Header
@@ -83,6 +128,29 @@ pub struct PacketSessionHistoryData {
     pub tyre_stints_history_data: [TyreStintHistoryData; 8],
 }
 
+#[repr(C, packed)]
+pub struct PacketTyreSetsData {
+    pub header: PacketHeader,             // Header
+    pub car_idx: u8,                      // Index of the car this data relates to
+    pub tyre_set_data: [TyreSetData; 20], // 13 (dry) + 7 (wet)
+    pub fitted_idx: u8,                   // Index into array of fitted tyre
+}
+
+#[repr(C, packed)]
+pub struct PacketTimeTrialData {
+    pub header: PacketHeader,                           // Header
+    pub player_session_best_data_set: TimeTrialDataSet, // Player session best data set
+    pub personal_best_data_set: TimeTrialDataSet,       // Personal best data set
+    pub rival_data_set: TimeTrialDataSet,               // Rival data set
+}
+
+#[repr(C, packed)]
+pub struct PacketLobbyInfoData {
+    pub header: PacketHeader,               // Header
+    pub num_players: u8,                    // Number of players in the lobby data
+    pub lobby_players: [LobbyInfoData; 22], // 22
+}
+
 #[repr(C, packed)]
 pub struct PacketSessionData {
     pub header: PacketHeader,
@@ -170,7 +238,7 @@ pub struct CarMotionData {
     pub world_position_x: f32,     // World space X position - metres
     pub world_position_y: f32,     // World space Y position
     pub world_position_z: f32,     // World space Z position
-    pub world_velocity_x: f32,     // Velocity in world space X – metres/s
+    pub world_velocity_x: f32,     // Velocity in world space X - metres/s
     pub world_velocity_y: f32,     // Velocity in world space Y
     pub world_velocity_z: f32,     // Velocity in world space Z
     pub world_forward_dir_x: i16,  // World space forward X direction (normalized)
@@ -340,6 +408,20 @@ pub struct ParticipantData {
     pub platform: u8,       // 1 = Steam, 3 = PlayStation, 4 = Xbox, 6 = Origin, 255 = unknown
 }
 
+#[repr(C, packed)]
+pub struct LobbyInfoData {
+    pub ai_controlled: u8,  // Whether the vehicle is AI (1) or Human (0) controlled
+    pub team_id: u8,        // Team id - see appendix (255 if no team currently selected)
+    pub nationality: u8,    // Nationality of the driver
+    pub platform: u8,       // 1 = Steam, 3 = PlayStation, 4 = Xbox, 6 = Origin, 255 = unknown
+    pub name: [u8; 48],     // Name of participant in UTF-8 format - null terminated
+    pub car_number: u8,     // Car number of the player
+    pub your_telemetry: u8, // The player's UDP setting, 0 = restricted, 1 = public
+    pub show_online_names: u8, // The player's show online names setting, 0 = off, 1 = on
+    pub tech_level: u16,    // F1 World tech level
+    pub ready_status: u8,   // 0 = not ready, 1 = ready, 2 = spectating
+}
+
 #[repr(C, packed)]
 pub struct FinalClassificationData {
     pub position: u8,                  // Finishing position
@@ -370,6 +452,99 @@ pub struct LapHistoryData {
     pub lap_valid_bit_flags: u8, // 0x01 bit set - lap valid, 0x02 bit set - sector 1 valid, 0x04 bit set - sector 2 valid, 0x08 bit set - sector 3 valid
 }
 
+#[repr(C, packed)]
+pub struct LapData {
+    pub last_lap_time_in_ms: u32,           // Last lap time in milliseconds
+    pub current_lap_time_in_ms: u32,        // Current time around the lap in milliseconds
+    pub sector1_time_ms_part: u16,          // Sector 1 time milliseconds part
+    pub sector1_time_minutes_part: u8,      // Sector 1 whole minute part
+    pub sector2_time_ms_part: u16,          // Sector 2 time milliseconds part
+    pub sector2_time_minutes_part: u8,      // Sector 2 whole minute part
+    pub delta_to_car_in_front_ms_part: u16, // Time delta to car in front milliseconds part
+    pub delta_to_car_in_front_minutes_part: u8, // Time delta to car in front whole minute part
+    pub delta_to_race_leader_ms_part: u16,  // Time delta to race leader milliseconds part
+    pub delta_to_race_leader_minutes_part: u8, // Time delta to race leader whole minute part
+    pub lap_distance: f32, // Distance vehicle is around current lap in metres – could be negative if line hasn't been crossed yet
+    pub total_distance: f32, // Total distance travelled in session in metres – could be negative if line hasn't been crossed yet
+    pub safety_car_delta: f32, // Delta in seconds for safety car
+    pub car_position: u8,    // Car race position
+    pub current_lap_num: u8, // Current lap number
+    pub pit_status: u8,      // 0 = none, 1 = pitting, 2 = in pit area
+    pub num_pit_stops: u8,   // Number of pit stops taken in this race
+    pub sector: u8,          // 0 = sector1, 1 = sector2, 2 = sector3
+    pub current_lap_invalid: u8, // Current lap invalid - 0 = valid, 1 = invalid
+    pub penalties: u8,       // Accumulated time penalties in seconds to be added
+    pub total_warnings: u8,  // Accumulated number of warnings issued
+    pub corner_cutting_warnings: u8, // Accumulated number of corner cutting warnings issued
+    pub num_unserved_drive_through_pens: u8, // Num drive through pens left to serve
+    pub num_unserved_stop_go_pens: u8, // Num stop go pens left to serve
+    pub grid_position: u8,   // Grid position the vehicle started the race in
+    pub driver_status: u8, // Status of driver - 0 = in garage, 1 = flying lap, 2 = in lap, 3 = out lap, 4 = on track
+    pub result_status: u8, // Result status - 0 = invalid, 1 = inactive, 2 = active, 3 = finished, 4 = didnotfinish, 5 = disqualified, 6 = not classified, 7 = retired
+    pub pit_lane_timer_active: u8, // Pit lane timing, 0 = inactive, 1 = active
+    pub pit_lane_time_in_lane_in_ms: u16, // If active, the current time spent in the pit lane in ms
+    pub pit_stop_timer_in_ms: u16, // Time of the actual pit stop in ms
+    pub pit_stop_should_serve_pen: u8, // Whether the car should serve a penalty at this stop
+    pub speed_trap_fastest_speed: f32, // Fastest speed through speed trap for this car in kmph
+    pub speed_trap_fastest_lap: u8, // Lap no the fastest speed was achieved, 255 = not set
+}
+
+#[repr(C, packed)]
+pub struct CarSetupData {
+    pub front_wing: u8,                 // Front wing aero
+    pub rear_wing: u8,                  // Rear wing aero
+    pub on_throttle: u8,                // Differential adjustment on throttle (percentage)
+    pub off_throttle: u8,               // Differential adjustment off throttle (percentage)
+    pub front_camber: f32,              // Front camber angle (suspension geometry)
+    pub rear_camber: f32,               // Rear camber angle (suspension geometry)
+    pub front_toe: f32,                 // Front toe angle (suspension geometry)
+    pub rear_toe: f32,                  // Rear toe angle (suspension geometry)
+    pub front_suspension: u8,           // Front suspension
+    pub rear_suspension: u8,            // Rear suspension
+    pub front_anti_roll_bar: u8,        // Front anti-roll bar
+    pub rear_anti_roll_bar: u8,         // Rear anti-roll bar
+    pub front_suspension_height: u8,    // Front ride height
+    pub rear_suspension_height: u8,     // Rear ride height
+    pub brake_pressure: u8,             // Brake pressure (percentage)
+    pub brake_bias: u8,                 // Brake bias (percentage)
+    pub engine_braking: u8,             // Engine braking (percentage)
+    pub rear_left_tyre_pressure: f32,   // Rear left tyre pressure (PSI)
+    pub rear_right_tyre_pressure: f32,  // Rear right tyre pressure (PSI)
+    pub front_left_tyre_pressure: f32,  // Front left tyre pressure (PSI)
+    pub front_right_tyre_pressure: f32, // Front right tyre pressure (PSI)
+    pub ballast: u8,                    // Ballast
+    pub fuel_load: f32,                 // Fuel load
+}
+
+#[repr(C, packed)]
+pub struct TimeTrialDataSet {
+    pub car_idx: u8,               // Index of the car this data relates to
+    pub team_id: u8,               // Team id - see appendix
+    pub lap_time_in_ms: u32,       // Lap time in milliseconds
+    pub sector1_time_in_ms: u32,   // Sector 1 time in milliseconds
+    pub sector2_time_in_ms: u32,   // Sector 2 time in milliseconds
+    pub sector3_time_in_ms: u32,   // Sector 3 time in milliseconds
+    pub traction_control: u8,      // 0 = off, 1 = medium, 2 = full
+    pub gearbox_assist: u8,        // 1 = manual, 2 = manual & suggested gear, 3 = auto
+    pub anti_lock_brakes: u8,      // 0 (off) - 1 (on)
+    pub equal_car_performance: u8, // 0 = Realistic, 1 = Equal
+    pub custom_setup: u8,          // 0 = No, 1 = Yes
+    pub valid: u8,                 // 0 = invalid, 1 = valid
+}
+
+#[repr(C, packed)]
+pub struct TyreSetData {
+    pub actual_tyre_compound: u8, // Actual tyre compound used
+    pub visual_tyre_compound: u8, // Visual tyre compound used
+    pub wear: u8,                 // Tyre wear (percentage)
+    pub available: u8,            // Whether this set is currently available
+    pub recommended_session: u8,  // Recommended session for tyre set, see appendix
+    pub life_span: u8,            // Laps left in this tyre set
+    pub usable_life: u8,          // Max number of laps recommended for this compound
+    pub lap_delta_time: i16,      // Lap delta time in milliseconds compared to fitted set
+    pub fitted: u8,               // Whether the set is fitted or not
+}
+
 #[repr(C, packed)]
 pub struct CarTelemetryData {
     pub speed: u16,                         // Speed of car in km/h
@@ -0,0 +1,175 @@
+use std::cmp::Reverse;
+
+use entities::CareerEntry;
+use structs::{DriverCareerStats, TeammateHeadToHead};
+
+use crate::standings::FINISHED_STATUS;
+
+// Constants
+/// Result statuses of a driver that retired or wasn't classified (DNF, not classified, retired)
+const DNF_STATUSES: [i16; 3] = [4, 6, 7];
+
+/// Aggregates the career of a driver from the classifications of every race session they
+/// started, including the other drivers of those sessions. Entries must be grouped by
+/// session
+pub fn career_stats(
+    steam_name: &str,
+    entries: &[CareerEntry],
+) -> (DriverCareerStats, Vec<TeammateHeadToHead>) {
+    let mut stats = DriverCareerStats::default();
+    let mut teammates: Vec<TeammateHeadToHead> = Vec::new();
+    let mut finishes = 0u32;
+    let mut positions_sum = 0u32;
+
+    for session in
+        entries.chunk_by(|a, b| a.race_id == b.race_id && a.session_type == b.session_type)
+    {
+        let Some(driver) = session
+            .iter()
+            .find(|entry| entry.steam_name.as_ref() == steam_name)
+        else {
+            continue;
+        };
+
+        stats.starts += 1;
+
+        if driver.grid_position == 1 {
+            stats.poles += 1;
+        }
+
+        if !driver.disqualified {
+            if driver.position == 1 {
+                stats.wins += 1;
+            }
+
+            if driver.position <= 3 {
+                stats.podiums += 1;
+            }
+        }
+
+        if driver.result_status == FINISHED_STATUS && !driver.disqualified {
+            finishes += 1;
+            positions_sum += driver.position as u32;
+        } else if DNF_STATUSES.contains(&driver.result_status) {
+            stats.dnfs += 1;
+        }
+
+        let fastest_lap = session
+            .iter()
+            .map(|entry| entry.best_lap_time)
+            .filter(|&lap_time| lap_time > 0)
+            .min();
+
+        if driver.best_lap_time > 0 && fastest_lap == Some(driver.best_lap_time) {
+            stats.fastest_laps += 1;
+        }
+
+        for teammate in session.iter().filter(|entry| {
+            entry.team_id == driver.team_id && entry.steam_name != driver.steam_name
+        }) {
+            let head_to_head = match teammates
+                .iter_mut()
+                .position(|head_to_head| head_to_head.steam_name == teammate.steam_name)
+            {
+                Some(idx) => &mut teammates[idx],
+                None => {
+                    teammates.push(TeammateHeadToHead {
+                        steam_name: teammate.steam_name.clone(),
+                        races: 0,
+                        ahead: 0,
+                        behind: 0,
+                    });
+
+                    teammates.last_mut().unwrap()
+                }
+            };
+
+            head_to_head.races += 1;
+
+            if driver.position < teammate.position {
+                head_to_head.ahead += 1;
+            } else {
+                head_to_head.behind += 1;
+            }
+        }
+    }
+
+    if finishes > 0 {
+        stats.average_finish = Some(positions_sum as f32 / finishes as f32);
+    }
+
+    teammates.sort_by_key(|head_to_head| Reverse(head_to_head.races));
+    (stats, teammates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(race_id: i32, steam_name: &str, team_id: i16, position: i16) -> CareerEntry {
+        CareerEntry {
+            race_id,
+            session_type: 15,
+            steam_name: steam_name.into(),
+            team_id,
+            grid_position: position,
+            position,
+            best_lap_time: 90_000 + position as i32,
+            result_status: FINISHED_STATUS,
+            disqualified: false,
+        }
+    }
+
+    #[test]
+    fn aggregates_race_results() {
+        let mut entries = vec![
+            entry(800000001, "Driver", 0, 1),
+            entry(800000001, "Teammate", 0, 2),
+            entry(800000001, "Other", 1, 3),
+            entry(800000002, "Other", 1, 1),
+            entry(800000002, "Teammate", 0, 2),
+            entry(800000002, "Driver", 0, 3),
+            entry(800000003, "Other", 1, 1),
+            entry(800000003, "Driver", 2, 5),
+        ];
+
+        entries[3].best_lap_time = 0;
+        entries[5].best_lap_time = 89_000;
+        entries[7].result_status = 7;
+
+        let (stats, teammates) = career_stats("Driver", &entries);
+
+        assert_eq!(stats.starts, 3);
+        assert_eq!(stats.wins, 1);
+        assert_eq!(stats.podiums, 2);
+        assert_eq!(stats.poles, 1);
+        assert_eq!(stats.fastest_laps, 2);
+        assert_eq!(stats.dnfs, 1);
+        assert_eq!(stats.average_finish, Some(2.0));
+
+        assert_eq!(
+            teammates,
+            [TeammateHeadToHead {
+                steam_name: "Teammate".into(),
+                races: 2,
+                ahead: 1,
+                behind: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn disqualified_results_are_not_counted() {
+        let mut entries = vec![entry(800000001, "Driver", 0, 1)];
+        entries[0].disqualified = true;
+
+        let (stats, teammates) = career_stats("Driver", &entries);
+
+        assert_eq!(stats.starts, 1);
+        assert_eq!(stats.wins, 0);
+        assert_eq!(stats.podiums, 0);
+        assert_eq!(stats.dnfs, 0);
+        assert_eq!(stats.average_finish, None);
+        assert!(teammates.is_empty());
+    }
+}
@@ -0,0 +1,154 @@
+use entities::{DamageComponent, EngineWear};
+use structs::{DriverEngineUsage, EngineAllocationWarning, EngineComponentUsage};
+
+// Constants
+/// Wear of the last allowed component from which it's expected to be replaced
+const AT_RISK_WEAR: i16 = 75;
+/// Drop in wear between two sessions read as a new component, ignoring rounding noise
+const REPLACEMENT_DROP: i16 = 5;
+
+/// Components of the power unit with the number of them allowed in a season
+const ALLOCATIONS: [(DamageComponent, i16); 6] = [
+    (DamageComponent::EngineIce, 4),
+    (DamageComponent::EngineMguh, 4),
+    (DamageComponent::EngineMguk, 4),
+    (DamageComponent::EngineEs, 2),
+    (DamageComponent::EngineCe, 2),
+    (DamageComponent::EngineTc, 4),
+];
+
+#[inline]
+fn component_wear(component: DamageComponent, wear: &EngineWear) -> i16 {
+    match component {
+        DamageComponent::EngineIce => wear.ice,
+        DamageComponent::EngineMguh => wear.mguh,
+        DamageComponent::EngineMguk => wear.mguk,
+        DamageComponent::EngineEs => wear.es,
+        DamageComponent::EngineCe => wear.ce,
+        _ => wear.tc,
+    }
+}
+
+/// Counts the components every driver used in a season from the wear stored at the end of
+/// each session, in the order the sessions were driven. A component is counted as replaced
+/// when its wear drops from one session to the next
+pub fn season_usage(wear: &[EngineWear]) -> Vec<DriverEngineUsage> {
+    let mut drivers: Vec<(&str, Vec<&EngineWear>)> = Vec::new();
+
+    for session in wear {
+        match drivers
+            .iter_mut()
+            .find(|(steam_name, _)| *steam_name == session.steam_name.as_ref())
+        {
+            Some((_, sessions)) => sessions.push(session),
+            None => drivers.push((&session.steam_name, vec![session])),
+        }
+    }
+
+    drivers
+        .into_iter()
+        .map(|(steam_name, sessions)| DriverEngineUsage {
+            steam_name: steam_name.into(),
+            components: ALLOCATIONS
+                .iter()
+                .map(|&(component, allocation)| {
+                    let mut used = 1;
+                    let mut last = 0;
+
+                    for session in &sessions {
+                        let wear = component_wear(component, session);
+
+                        if wear + REPLACEMENT_DROP < last {
+                            used += 1;
+                        }
+
+                        last = wear;
+                    }
+
+                    let warning = if used > allocation {
+                        Some(EngineAllocationWarning::Exceeded)
+                    } else if used == allocation && last >= AT_RISK_WEAR {
+                        Some(EngineAllocationWarning::AtRisk)
+                    } else {
+                        None
+                    };
+
+                    EngineComponentUsage {
+                        component,
+                        allocation,
+                        used,
+                        wear: last,
+                        warning,
+                    }
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn wear(steam_name: &str, ice: i16, es: i16) -> EngineWear {
+        EngineWear {
+            race_id: 800000000,
+            session_type: 15,
+            steam_name: steam_name.into(),
+            ice,
+            mguh: 10,
+            mguk: 10,
+            es,
+            ce: 10,
+            tc: 10,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn counts_replaced_components() {
+        let usage = season_usage(&[
+            wear("Driver", 30, 40),
+            wear("Other", 20, 20),
+            wear("Driver", 60, 80),
+            // Rounding noise is not a new component
+            wear("Driver", 58, 5),
+            wear("Driver", 10, 78),
+        ]);
+
+        assert_eq!(usage.len(), 2);
+        assert_eq!(usage[0].steam_name.as_ref(), "Driver");
+
+        let ice = &usage[0].components[0];
+        assert_eq!(ice.component, DamageComponent::EngineIce);
+        assert_eq!(ice.used, 2);
+        assert_eq!(ice.wear, 10);
+        assert_eq!(ice.warning, None);
+
+        let es = &usage[0].components[3];
+        assert_eq!(es.component, DamageComponent::EngineEs);
+        assert_eq!(es.used, 2);
+        assert_eq!(es.warning, Some(EngineAllocationWarning::AtRisk));
+
+        assert!(usage[1]
+            .components
+            .iter()
+            .all(|component| component.used == 1 && component.warning.is_none()));
+    }
+
+    #[test]
+    fn warns_when_allocation_is_exceeded() {
+        let usage = season_usage(&[
+            wear("Driver", 0, 50),
+            wear("Driver", 0, 10),
+            wear("Driver", 0, 60),
+            wear("Driver", 0, 20),
+        ]);
+
+        let es = &usage[0].components[3];
+        assert_eq!(es.used, 3);
+        assert_eq!(es.warning, Some(EngineAllocationWarning::Exceeded));
+    }
+}
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use entities::{Race, RaceEntry};
+use error::RaceError;
+
+// Constants
+/// Hours around its date a race is matched with a session
+const RACE_MATCH_HOURS: i64 = 24;
+
+/// Finds the race of the championship closest to `now` inside the match window. Lobbies
+/// don't know the track yet, so only sessions filter by it
+pub fn scheduled_race(
+    races: &[Arc<Race>],
+    track_id: Option<i16>,
+    now: DateTime<Utc>,
+) -> Option<&Race> {
+    races
+        .iter()
+        .filter(|race| track_id.is_none_or(|track_id| race.track_id == track_id))
+        .filter(|race| (race.date - now).num_hours().abs() < RACE_MATCH_HOURS)
+        .min_by_key(|race| (race.date - now).num_seconds().abs())
+        .map(Arc::as_ref)
+}
+
+/// Reserves entered in the race in place of a regular driver, they race without joining
+/// the championship roster
+pub fn substitutes(entries: &[RaceEntry]) -> Vec<Box<str>> {
+    entries
+        .iter()
+        .filter(|entry| entry.replaces.is_some())
+        .map(|entry| entry.steam_name.clone())
+        .collect()
+}
+
+/// Checks that a reserve can take the car of a regular driver in a race
+pub(crate) fn check_substitution(
+    entries: &[RaceEntry],
+    steam_name: &str,
+    replaces: &str,
+) -> Result<(), RaceError> {
+    if entries.iter().any(|entry| {
+        entry.steam_name.as_ref() == steam_name || entry.replaces.as_deref() == Some(steam_name)
+    }) {
+        return Err(RaceError::AlreadyEntered);
+    }
+
+    if !entries
+        .iter()
+        .any(|entry| entry.replaces.is_none() && entry.steam_name.as_ref() == replaces)
+    {
+        return Err(RaceError::NotRegular);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    fn race(id: i32, track_id: i16, date: DateTime<Utc>) -> Arc<Race> {
+        Arc::new(Race {
+            id,
+            championship_id: 1,
+            track_id,
+            date,
+            created_at: date,
+            updated_at: None,
+            season: 1,
+            division_id: None,
+        })
+    }
+
+    fn entry(steam_name: &str, number: i16, replaces: Option<&str>) -> RaceEntry {
+        RaceEntry {
+            steam_name: steam_name.into(),
+            team_id: 1,
+            number,
+            replaces: replaces.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn matches_the_closest_scheduled_race() {
+        let now = Utc::now();
+        let races = [
+            race(1, 10, now - Duration::days(7)),
+            race(2, 10, now + Duration::hours(20)),
+            race(3, 11, now + Duration::hours(2)),
+        ];
+
+        assert_eq!(scheduled_race(&races, Some(10), now).map(|r| r.id), Some(2));
+        assert_eq!(scheduled_race(&races, None, now).map(|r| r.id), Some(3));
+        assert!(scheduled_race(&races, Some(12), now).is_none());
+        assert!(scheduled_race(&races[..1], None, now).is_none());
+    }
+
+    #[test]
+    fn reserve_takes_a_regular_seat() {
+        let mut entries = vec![entry("a", 44, None), entry("b", 7, None)];
+
+        assert!(matches!(
+            check_substitution(&entries, "reserve", "c"),
+            Err(RaceError::NotRegular)
+        ));
+        assert!(matches!(
+            check_substitution(&entries, "b", "a"),
+            Err(RaceError::AlreadyEntered)
+        ));
+        assert!(check_substitution(&entries, "reserve", "a").is_ok());
+
+        // The entry list now shows the reserve in the car of the regular driver
+        entries[0] = entry("reserve", 44, Some("a"));
+
+        assert_eq!(substitutes(&entries), vec![Box::from("reserve")]);
+        assert!(matches!(
+            check_substitution(&entries, "reserve", "b"),
+            Err(RaceError::AlreadyEntered)
+        ));
+        assert!(matches!(
+            check_substitution(&entries, "other", "reserve"),
+            Err(RaceError::NotRegular)
+        ));
+    }
+}
@@ -1,2 +1,11 @@
+mod career;
+mod engine_usage;
+mod entry_list;
+mod lap_comparison;
+mod parc_ferme;
 pub mod repositories;
 pub mod services;
+mod standings;
+
+pub use entry_list::{scheduled_race, substitutes};
+pub use parc_ferme::QUALIFYING_SESSION_TYPES;
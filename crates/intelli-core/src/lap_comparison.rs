@@ -0,0 +1,79 @@
+use entities::LapTrace;
+use structs::{LapChannels, LapComparison};
+
+/// Channels of a lap cut to the samples both laps share
+#[inline]
+fn channels(trace: &LapTrace, len: usize) -> LapChannels {
+    LapChannels {
+        steam_name: trace.steam_name.clone(),
+        lap_number: trace.lap_number,
+        lap_time: trace.lap_time,
+        valid: trace.valid,
+        speed: trace.speed[..len].to_vec(),
+        throttle: trace.throttle[..len].to_vec(),
+        brake: trace.brake[..len].to_vec(),
+        gear: trace.gear[..len].to_vec(),
+        steer: trace.steer[..len].to_vec(),
+    }
+}
+
+/// Aligns two laps by lap distance, adding the time lost or gained against the reference
+/// lap at every sample
+pub fn compare(lap: &LapTrace, reference: &LapTrace) -> LapComparison {
+    let len = lap.time.len().min(reference.time.len());
+
+    let delta = lap.time[..len]
+        .iter()
+        .zip(&reference.time[..len])
+        .map(|(time, reference_time)| time - reference_time)
+        .collect();
+
+    LapComparison {
+        step: lap.step,
+        lap: channels(lap, len),
+        reference: channels(reference, len),
+        delta,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn trace(steam_name: &str, time: Vec<i32>, speed: Vec<i16>) -> LapTrace {
+        let len = time.len();
+
+        LapTrace {
+            session_type: 15,
+            steam_name: steam_name.into(),
+            lap_number: 3,
+            lap_time: *time.last().unwrap(),
+            valid: true,
+            step: 10,
+            time,
+            speed,
+            throttle: vec![100; len],
+            brake: vec![0; len],
+            gear: vec![7; len],
+            steer: vec![0; len],
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn aligns_laps_and_computes_delta() {
+        let lap = trace("a", vec![120, 250, 390, 520], vec![300, 290, 280, 270]);
+        let reference = trace("b", vec![110, 240, 400], vec![305, 295, 275]);
+
+        let comparison = compare(&lap, &reference);
+
+        assert_eq!(comparison.step, 10);
+        assert_eq!(comparison.delta, vec![10, 10, -10]);
+        assert_eq!(comparison.lap.speed, vec![300, 290, 280]);
+        assert_eq!(comparison.reference.speed, vec![305, 295, 275]);
+        assert_eq!(comparison.lap.gear.len(), 3);
+        assert_eq!(&*comparison.reference.steam_name, "b");
+    }
+}
@@ -0,0 +1,162 @@
+use entities::{CarSetup, SetupSnapshot};
+use structs::{ParcFermeBreach, ParcFermeChange};
+
+use crate::standings::RACE_SESSION_TYPES;
+
+// Constants
+const TOLERANCE: f32 = 0.001;
+
+/// Session types that set the grid, after which the setup is locked (Q1 to one shot shootout)
+pub const QUALIFYING_SESSION_TYPES: [i16; 10] = [5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+
+/// Values of the settings that can't change between qualifying and the race. The front wing,
+/// differential, brake bias, engine braking and fuel load are still allowed to change
+#[inline]
+fn locked_settings(setup: &CarSetup) -> [(&'static str, f32); 16] {
+    [
+        ("rear_wing", setup.rear_wing as f32),
+        ("front_camber", setup.front_camber),
+        ("rear_camber", setup.rear_camber),
+        ("front_toe", setup.front_toe),
+        ("rear_toe", setup.rear_toe),
+        ("front_suspension", setup.front_suspension as f32),
+        ("rear_suspension", setup.rear_suspension as f32),
+        ("front_anti_roll_bar", setup.front_anti_roll_bar as f32),
+        ("rear_anti_roll_bar", setup.rear_anti_roll_bar as f32),
+        (
+            "front_suspension_height",
+            setup.front_suspension_height as f32,
+        ),
+        (
+            "rear_suspension_height",
+            setup.rear_suspension_height as f32,
+        ),
+        ("brake_pressure", setup.brake_pressure as f32),
+        ("rear_left_tyre_pressure", setup.rear_left_tyre_pressure),
+        ("rear_right_tyre_pressure", setup.rear_right_tyre_pressure),
+        ("front_left_tyre_pressure", setup.front_left_tyre_pressure),
+        ("front_right_tyre_pressure", setup.front_right_tyre_pressure),
+    ]
+}
+
+/// Compares every race setup with the last qualifying setup the same driver used before it,
+/// returning the drivers that changed a setting locked by parc fermé
+pub fn breaches(snapshots: &[SetupSnapshot]) -> Vec<ParcFermeBreach> {
+    let mut breaches = Vec::new();
+
+    for race in snapshots
+        .iter()
+        .filter(|snapshot| RACE_SESSION_TYPES.contains(&snapshot.session_type))
+    {
+        let Some(qualifying) = snapshots
+            .iter()
+            .filter(|snapshot| {
+                snapshot.steam_name == race.steam_name
+                    && QUALIFYING_SESSION_TYPES.contains(&snapshot.session_type)
+                    && snapshot.created_at < race.created_at
+            })
+            .max_by_key(|snapshot| snapshot.created_at)
+        else {
+            continue;
+        };
+
+        let changes: Vec<_> = locked_settings(&qualifying.setup)
+            .into_iter()
+            .zip(locked_settings(&race.setup))
+            .filter(|((_, qualifying), (_, race))| (qualifying - race).abs() > TOLERANCE)
+            .map(|((setting, qualifying), (_, race))| ParcFermeChange {
+                setting,
+                qualifying,
+                race,
+            })
+            .collect();
+
+        if !changes.is_empty() {
+            breaches.push(ParcFermeBreach {
+                steam_name: race.steam_name.clone(),
+                qualifying_session: qualifying.session_type,
+                race_session: race.session_type,
+                changes,
+            });
+        }
+    }
+
+    breaches
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    fn snapshot(
+        steam_name: &str,
+        session_type: i16,
+        minute: u32,
+        setup: CarSetup,
+    ) -> SetupSnapshot {
+        SetupSnapshot {
+            session_type,
+            steam_name: steam_name.into(),
+            setup,
+            created_at: Utc.with_ymd_and_hms(2024, 5, 12, 14, minute, 0).unwrap(),
+        }
+    }
+
+    fn setup() -> CarSetup {
+        CarSetup {
+            front_wing: 30,
+            rear_wing: 25,
+            brake_pressure: 100,
+            brake_bias: 56,
+            front_left_tyre_pressure: 22.5,
+            fuel_load: 10.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn allowed_changes_are_not_breaches() {
+        let mut race_setup = setup();
+        race_setup.front_wing = 33;
+        race_setup.brake_bias = 54;
+        race_setup.fuel_load = 105.0;
+
+        let snapshots = [
+            snapshot("a", 8, 0, setup()),
+            snapshot("a", 15, 30, race_setup),
+        ];
+
+        assert!(breaches(&snapshots).is_empty());
+    }
+
+    #[test]
+    fn detects_locked_changes_against_last_qualifying() {
+        let mut q3_setup = setup();
+        q3_setup.rear_wing = 20;
+
+        let mut race_setup = q3_setup;
+        race_setup.front_left_tyre_pressure = 23.1;
+
+        let snapshots = [
+            snapshot("a", 6, 0, setup()),
+            snapshot("a", 8, 10, q3_setup),
+            snapshot("a", 15, 30, race_setup),
+            snapshot("b", 15, 30, race_setup),
+        ];
+
+        let breaches = breaches(&snapshots);
+
+        assert_eq!(breaches.len(), 1);
+        assert_eq!(breaches[0].qualifying_session, 8);
+        assert_eq!(
+            breaches[0].changes,
+            vec![ParcFermeChange {
+                setting: "front_left_tyre_pressure",
+                qualifying: 22.5,
+                race: 23.1,
+            }]
+        );
+    }
+}
@@ -3,7 +3,10 @@ use std::sync::Arc;
 use tokio_stream::StreamExt;
 
 use db::{Database, EntityCache};
-use entities::{Championship, ChampionshipRelation, Race};
+use entities::{
+    Championship, ChampionshipDriver, ChampionshipRelation, ChampionshipRule, ChampionshipSeason,
+    DamageThreshold, Division, EngineWear, LeagueTeam, Race, TeamLineup, TimeTrialLap,
+};
 use error::AppResult;
 use utils::slice_iter;
 
@@ -201,6 +204,42 @@ impl ChampionshipRepository {
         }
     }
 
+    /// Retrieves the teams a user was granted telemetry access to.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the championship.
+    /// - `user_id`: The ID of the user.
+    ///
+    /// # Returns
+    /// A vector of the granted team IDs.
+    pub async fn telemetry_grants(&self, id: i32, user_id: i32) -> AppResult<Vec<i16>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let telemetry_grants_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT team_id FROM telemetry_grants
+                        WHERE championship_id = $1 AND user_id = $2
+                        ORDER BY team_id
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&telemetry_grants_stmt, &[&id, &user_id])
+                .await?
+        };
+
+        tokio::pin!(stream);
+        let mut team_ids = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            team_ids.push(row.get(0));
+        }
+
+        Ok(team_ids)
+    }
+
     pub async fn drivers_linked(&self, id: i32) -> AppResult<Vec<Box<str>>> {
         let stream = {
             let conn = self.db.pg.get().await?;
@@ -228,6 +267,394 @@ impl ChampionshipRepository {
         Ok(drivers)
     }
 
+    /// Retrieves the drivers registered in a championship with their team and number.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the championship.
+    ///
+    /// # Returns
+    /// A vector of the championship drivers.
+    pub async fn drivers(&self, id: i32) -> AppResult<Vec<ChampionshipDriver>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let drivers_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT steam_name, team_id, number, division_id
+                        FROM championship_drivers
+                        WHERE championship_id = $1
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&drivers_stmt, &[&id]).await?
+        };
+
+        tokio::pin!(stream);
+        let mut drivers = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            drivers.push(ChampionshipDriver::from_row(&row));
+        }
+
+        Ok(drivers)
+    }
+
+    /// Retrieves the session rules required by a championship.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the championship.
+    ///
+    /// # Returns
+    /// A vector of the required rules, empty if the championship has no rule profile.
+    pub async fn rules(&self, id: i32) -> AppResult<Vec<ChampionshipRule>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let rules_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT rule, value
+                        FROM championship_rules
+                        WHERE championship_id = $1
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&rules_stmt, &[&id]).await?
+        };
+
+        tokio::pin!(stream);
+        let mut rules = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            rules.push(ChampionshipRule::from_row(&row));
+        }
+
+        Ok(rules)
+    }
+
+    /// Retrieves the damage thresholds at which the engineers of a championship get alerted.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the championship.
+    ///
+    /// # Returns
+    /// A vector of the thresholds, empty if the championship has no damage alerts.
+    pub async fn damage_thresholds(&self, id: i32) -> AppResult<Vec<DamageThreshold>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let damage_thresholds_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT component, value
+                        FROM damage_thresholds
+                        WHERE championship_id = $1
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&damage_thresholds_stmt, &[&id]).await?
+        };
+
+        tokio::pin!(stream);
+        let mut thresholds = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            thresholds.push(DamageThreshold::from_row(&row));
+        }
+
+        Ok(thresholds)
+    }
+
+    /// Retrieves the seasons of a championship.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the championship.
+    ///
+    /// # Returns
+    /// A vector of the seasons, the current one last.
+    pub async fn seasons(&self, id: i32) -> AppResult<Vec<ChampionshipSeason>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let seasons_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT number, started_at, ended_at
+                        FROM championship_seasons
+                        WHERE championship_id = $1
+                        ORDER BY number
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&seasons_stmt, &[&id]).await?
+        };
+
+        tokio::pin!(stream);
+        let mut seasons = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            seasons.push(ChampionshipSeason::from_row(&row));
+        }
+
+        Ok(seasons)
+    }
+
+    /// Retrieves the number of the current season of a championship.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the championship.
+    ///
+    /// # Returns
+    /// The number of the season, none if the championship doesn't exist.
+    pub async fn current_season(&self, id: i32) -> AppResult<Option<i16>> {
+        let row = {
+            let conn = self.db.pg.get().await?;
+
+            let current_season_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT number FROM championship_seasons
+                        WHERE championship_id = $1 AND ended_at IS NULL
+                    "#,
+                )
+                .await?;
+
+            conn.query_opt(&current_season_stmt, &[&id]).await?
+        };
+
+        Ok(row.map(|row| row.get(0)))
+    }
+
+    /// Retrieves the roster a championship had in a past season.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the championship.
+    /// - `season`: The number of the season.
+    ///
+    /// # Returns
+    /// A vector of the drivers of the season.
+    pub async fn season_drivers(&self, id: i32, season: i16) -> AppResult<Vec<ChampionshipDriver>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let season_drivers_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT steam_name, team_id, number, division_id
+                        FROM season_drivers
+                        WHERE championship_id = $1 AND season = $2
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&season_drivers_stmt, slice_iter(&[&id, &season]))
+                .await?
+        };
+
+        tokio::pin!(stream);
+        let mut drivers = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            drivers.push(ChampionshipDriver::from_row(&row));
+        }
+
+        Ok(drivers)
+    }
+
+    /// Retrieves the divisions of a championship.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the championship.
+    ///
+    /// # Returns
+    /// A vector of the divisions, ordered by tier.
+    pub async fn divisions(&self, id: i32) -> AppResult<Vec<Division>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let divisions_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT id, name, tier
+                        FROM championship_divisions
+                        WHERE championship_id = $1
+                        ORDER BY tier, id
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&divisions_stmt, &[&id]).await?
+        };
+
+        tokio::pin!(stream);
+        let mut divisions = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            divisions.push(Division::from_row(&row));
+        }
+
+        Ok(divisions)
+    }
+
+    /// Retrieves the league teams of a championship.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the championship.
+    ///
+    /// # Returns
+    /// A vector of the teams, ordered by ID.
+    pub async fn teams(&self, id: i32) -> AppResult<Vec<LeagueTeam>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let teams_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT id, game_team_id, name, color, logo
+                        FROM league_teams
+                        WHERE championship_id = $1
+                        ORDER BY id
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&teams_stmt, &[&id]).await?
+        };
+
+        tokio::pin!(stream);
+        let mut teams = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            teams.push(LeagueTeam::from_row(&row));
+        }
+
+        Ok(teams)
+    }
+
+    /// Retrieves the lineup history of the league teams of a championship, including the
+    /// drivers that already left their team.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the championship.
+    ///
+    /// # Returns
+    /// A vector of the lineups, in the order the drivers joined.
+    pub async fn lineups(&self, id: i32) -> AppResult<Vec<TeamLineup>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let lineups_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT team_id, steam_name, joined_at, left_at
+                        FROM team_lineups
+                        WHERE championship_id = $1
+                        ORDER BY joined_at
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&lineups_stmt, &[&id]).await?
+        };
+
+        tokio::pin!(stream);
+        let mut lineups = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            lineups.push(TeamLineup::from_row(&row));
+        }
+
+        Ok(lineups)
+    }
+
+    /// Retrieves the engine wear stored at the end of every session of a championship.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the championship.
+    ///
+    /// # Returns
+    /// A vector of the engine wear, in the order the sessions were driven.
+    pub async fn engine_wear(&self, id: i32) -> AppResult<Vec<EngineWear>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let engine_wear_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT w.race_id, w.session_type, w.steam_name, w.ice, w.mguh, w.mguk,
+                            w.es, w.ce, w.tc, w.created_at
+                        FROM engine_wear w
+                        JOIN races r ON r.id = w.race_id
+                        JOIN championship_seasons s ON s.championship_id = r.championship_id
+                            AND s.number = r.season
+                        WHERE r.championship_id = $1 AND s.ended_at IS NULL
+                        ORDER BY r.date, w.created_at
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&engine_wear_stmt, &[&id]).await?
+        };
+
+        tokio::pin!(stream);
+        let mut wear = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            wear.push(EngineWear::from_row(&row));
+        }
+
+        Ok(wear)
+    }
+
+    /// Retrieves the hotlap leaderboard of a track, with the fastest valid
+    /// time trial lap of every driver of the championship.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the championship.
+    /// - `track_id`: The ID of the track.
+    ///
+    /// # Returns
+    /// A vector of laps ordered from the fastest.
+    pub async fn hotlap_leaderboard(&self, id: i32, track_id: i16) -> AppResult<Vec<TimeTrialLap>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let hotlap_leaderboard_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT * FROM (
+                            SELECT DISTINCT ON (steam_name) steam_name, track_id, kind, team_id,
+                                lap_time, sector1_time, sector2_time, sector3_time,
+                                traction_control, gearbox_assist, anti_lock_brakes,
+                                equal_car_performance, custom_setup, valid, recorded_at
+                            FROM time_trial_laps
+                            WHERE championship_id = $1 AND track_id = $2 AND valid AND lap_time > 0
+                            ORDER BY steam_name, lap_time
+                        ) best_laps
+                        ORDER BY lap_time
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&hotlap_leaderboard_stmt, slice_iter(&[&id, &track_id]))
+                .await?
+        };
+
+        tokio::pin!(stream);
+        let mut laps = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            laps.push(TimeTrialLap::from_row(&row));
+        }
+
+        Ok(laps)
+    }
+
     /// Retrieves all used championship IDs.
     ///
     /// This method should only be called once.
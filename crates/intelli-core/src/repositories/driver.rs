@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use db::Database;
-use entities::Driver;
+use entities::{Driver, DriverClaim};
 use error::AppResult;
+use tokio_stream::StreamExt;
 
 pub struct DriverRepository {
     db: &'static Database,
@@ -44,4 +45,79 @@ impl DriverRepository {
             None => Ok(None),
         }
     }
+
+    pub async fn find_by_user(&self, user_id: i32) -> AppResult<Option<Arc<Driver>>> {
+        let row = {
+            let conn = self.db.pg.get().await?;
+
+            let find_driver_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT * FROM drivers
+                        WHERE user_id = $1
+                    "#,
+                )
+                .await?;
+
+            conn.query_opt(&find_driver_stmt, &[&user_id]).await?
+        };
+
+        match row {
+            Some(ref row) => {
+                let driver = Driver::from_row_arc(row);
+                self.db.cache.driver.set(driver.clone());
+                Ok(Some(driver))
+            }
+
+            None => Ok(None),
+        }
+    }
+
+    pub async fn claim(&self, steam_name: &str, user_id: i32) -> AppResult<Option<DriverClaim>> {
+        let row = {
+            let conn = self.db.pg.get().await?;
+
+            let find_claim_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT * FROM driver_claims
+                        WHERE steam_name = $1 AND user_id = $2
+                    "#,
+                )
+                .await?;
+
+            conn.query_opt(&find_claim_stmt, &[&steam_name, &user_id])
+                .await?
+        };
+
+        Ok(row.as_ref().map(DriverClaim::from_row))
+    }
+
+    pub async fn pending_claims(&self, championship_id: i32) -> AppResult<Vec<DriverClaim>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let pending_claims_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT * FROM driver_claims
+                        WHERE championship_id = $1 AND status = 'Pending'
+                        ORDER BY created_at
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&pending_claims_stmt, &[&championship_id])
+                .await?
+        };
+
+        tokio::pin!(stream);
+        let mut claims = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            claims.push(DriverClaim::from_row(&row));
+        }
+
+        Ok(claims)
+    }
 }
@@ -1,11 +1,17 @@
 mod championship;
 mod discord;
 mod driver;
+mod race;
+mod result;
 mod server;
+mod steward;
 mod user;
 
 pub use championship::*;
 pub use discord::*;
 pub use driver::*;
+pub use race::*;
+pub use result::*;
 pub use server::*;
+pub use steward::*;
 pub use user::*;
@@ -0,0 +1,266 @@
+use std::sync::Arc;
+
+use tokio_stream::StreamExt;
+
+use db::Database;
+use entities::{LapTrace, Race, RaceEntry, TrackOutline, WeatherSample};
+use error::AppResult;
+use utils::slice_iter;
+
+/// Repository for managing race data.
+pub struct RaceRepository {
+    db: &'static Database,
+}
+
+impl RaceRepository {
+    /// Creates a new RaceRepository instance.
+    ///
+    /// # Arguments
+    /// - `db`: Database connection.
+    ///
+    /// # Returns
+    /// A new RaceRepository instance.
+    pub fn new(db: &'static Database) -> Self {
+        Self { db }
+    }
+
+    /// Finds a race by its ID.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the race to find.
+    ///
+    /// # Returns
+    /// An Option containing the Race if found.
+    pub async fn find(&self, id: i32) -> AppResult<Option<Arc<Race>>> {
+        let row = {
+            let conn = self.db.pg.get().await?;
+
+            let find_race_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT * FROM races
+                        WHERE id = $1
+                    "#,
+                )
+                .await?;
+
+            conn.query_opt(&find_race_stmt, &[&id]).await?
+        };
+
+        Ok(row.as_ref().map(Race::from_row_arc))
+    }
+
+    /// Retrieves the weather recorded during a race, ordered by time.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the race.
+    ///
+    /// # Returns
+    /// A vector of weather samples.
+    pub async fn weather_history(&self, id: i32) -> AppResult<Vec<WeatherSample>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let weather_history_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT session_type, weather, track_temperature, air_temperature,
+                            rain_percentage, recorded_at
+                        FROM weather_samples
+                        WHERE race_id = $1
+                        ORDER BY recorded_at
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&weather_history_stmt, &[&id]).await?
+        };
+
+        tokio::pin!(stream);
+        let mut samples = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            samples.push(WeatherSample::from_row(&row));
+        }
+
+        Ok(samples)
+    }
+
+    /// Finds the recorded outline of a track.
+    ///
+    /// # Arguments
+    /// - `track_id`: The ID of the track.
+    ///
+    /// # Returns
+    /// An Option containing the TrackOutline if it was recorded.
+    pub async fn track_outline(&self, track_id: i16) -> AppResult<Option<TrackOutline>> {
+        let row = {
+            let conn = self.db.pg.get().await?;
+
+            let track_outline_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT track_id, track_length, origin_x, origin_z, scale, points,
+                            pit_lane, sector2_index, sector3_index, updated_at
+                        FROM track_outlines
+                        WHERE track_id = $1
+                    "#,
+                )
+                .await?;
+
+            conn.query_opt(&track_outline_stmt, &[&track_id]).await?
+        };
+
+        Ok(row.as_ref().map(TrackOutline::from_row))
+    }
+
+    /// Finds the trace of a lap driven in a race session.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the race.
+    /// - `session_type`: The session the lap was driven in.
+    /// - `steam_name`: The driver of the lap.
+    /// - `lap_number`: The number of the lap.
+    ///
+    /// # Returns
+    /// An Option containing the LapTrace if found.
+    pub async fn lap_trace(
+        &self,
+        id: i32,
+        session_type: i16,
+        steam_name: &str,
+        lap_number: i16,
+    ) -> AppResult<Option<LapTrace>> {
+        let row = {
+            let conn = self.db.pg.get().await?;
+
+            let lap_trace_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT session_type, steam_name, lap_number, lap_time, valid, step, time,
+                            speed, throttle, brake, gear, steer, created_at
+                        FROM lap_traces
+                        WHERE race_id = $1 AND session_type = $2 AND steam_name = $3
+                            AND lap_number = $4
+                    "#,
+                )
+                .await?;
+
+            conn.query_opt(
+                &lap_trace_stmt,
+                &[&id, &session_type, &steam_name, &lap_number],
+            )
+            .await?
+        };
+
+        Ok(row.as_ref().map(LapTrace::from_row))
+    }
+
+    /// Finds the trace of the fastest valid lap of a race session.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the race.
+    /// - `session_type`: The session the lap was driven in.
+    /// - `steam_name`: Only looks at the laps of this driver when given.
+    ///
+    /// # Returns
+    /// An Option containing the LapTrace if any valid lap was recorded.
+    pub async fn fastest_lap_trace(
+        &self,
+        id: i32,
+        session_type: i16,
+        steam_name: Option<&str>,
+    ) -> AppResult<Option<LapTrace>> {
+        let row = {
+            let conn = self.db.pg.get().await?;
+
+            let fastest_lap_trace_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT session_type, steam_name, lap_number, lap_time, valid, step, time,
+                            speed, throttle, brake, gear, steer, created_at
+                        FROM lap_traces
+                        WHERE race_id = $1 AND session_type = $2 AND valid
+                            AND ($3::VARCHAR IS NULL OR steam_name = $3)
+                        ORDER BY lap_time
+                        LIMIT 1
+                    "#,
+                )
+                .await?;
+
+            conn.query_opt(&fastest_lap_trace_stmt, &[&id, &session_type, &steam_name])
+                .await?
+        };
+
+        Ok(row.as_ref().map(LapTrace::from_row))
+    }
+
+    /// Retrieves the entry list of a race, the regular drivers of the championship with
+    /// the reserves that substitute them.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the race.
+    ///
+    /// # Returns
+    /// A vector of race entries, ordered by team and number.
+    pub async fn entries(&self, id: i32) -> AppResult<Vec<RaceEntry>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let entries_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT COALESCE(s.steam_name, d.steam_name), d.team_id, d.number,
+                            s.replaces
+                        FROM races r
+                        JOIN championship_drivers d ON d.championship_id = r.championship_id
+                        LEFT JOIN race_substitutions s ON s.race_id = r.id
+                            AND s.replaces = d.steam_name
+                        WHERE r.id = $1
+                        ORDER BY d.team_id, d.number
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&entries_stmt, &[&id]).await?
+        };
+
+        tokio::pin!(stream);
+        let mut entries = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            entries.push(RaceEntry::from_row(&row));
+        }
+
+        Ok(entries)
+    }
+
+    /// Retrieves all used race IDs.
+    ///
+    /// This method should only be called once.
+    ///
+    /// # Returns
+    /// A vector of all used race IDs.
+    pub async fn _used_ids(&self) -> AppResult<Vec<i32>> {
+        let conn = self.db.pg.get().await?;
+
+        let race_ids_stmt = conn
+            .prepare_cached(
+                r#"
+                    SELECT id FROM races
+                "#,
+            )
+            .await?;
+
+        let stream = conn.query_raw(&race_ids_stmt, slice_iter(&[])).await?;
+
+        tokio::pin!(stream);
+        let mut races = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            races.push(row.get(0));
+        }
+
+        Ok(races)
+    }
+}
@@ -0,0 +1,325 @@
+use tokio_stream::StreamExt;
+
+use db::Database;
+use entities::{
+    CareerEntry, DriverStanding, ResultEntry, ResultPenalty, TeamStanding, TrackBestLap,
+};
+use error::AppResult;
+use utils::slice_iter;
+
+use crate::standings::RACE_SESSION_TYPES;
+
+/// Repository for the classifications of session results.
+pub struct ResultRepository {
+    db: &'static Database,
+}
+
+impl ResultRepository {
+    /// Creates a new ResultRepository instance.
+    ///
+    /// # Arguments
+    /// - `db`: Database connection.
+    ///
+    /// # Returns
+    /// A new ResultRepository instance.
+    pub fn new(db: &'static Database) -> Self {
+        Self { db }
+    }
+
+    /// Retrieves the classification of a session, ordered by position.
+    ///
+    /// # Arguments
+    /// - `race_id`: The ID of the race.
+    /// - `session_type`: The type of the session.
+    ///
+    /// # Returns
+    /// A vector of result entries, empty if the session has no result.
+    pub async fn entries(&self, race_id: i32, session_type: i16) -> AppResult<Vec<ResultEntry>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let entries_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT steam_name, team_id, grid_position, laps, race_time,
+                            penalties_time, best_lap_time, result_status, original_position,
+                            original_points, position, points, steward_time, disqualified
+                        FROM result_entries
+                        WHERE race_id = $1 AND session_type = $2
+                        ORDER BY position
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&entries_stmt, slice_iter(&[&race_id, &session_type]))
+                .await?
+        };
+
+        tokio::pin!(stream);
+        let mut entries = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            entries.push(ResultEntry::from_row(&row));
+        }
+
+        Ok(entries)
+    }
+
+    /// Retrieves the penalties applied to a session result, ordered by time.
+    ///
+    /// # Arguments
+    /// - `race_id`: The ID of the race.
+    /// - `session_type`: The type of the session.
+    ///
+    /// # Returns
+    /// A vector of result penalties.
+    pub async fn penalties(
+        &self,
+        race_id: i32,
+        session_type: i16,
+    ) -> AppResult<Vec<ResultPenalty>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let penalties_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT steam_name, kind, seconds, reason, incident_id, steward_id,
+                            previous_position, new_position, created_at
+                        FROM result_penalties
+                        WHERE race_id = $1 AND session_type = $2
+                        ORDER BY created_at
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&penalties_stmt, slice_iter(&[&race_id, &session_type]))
+                .await?
+        };
+
+        tokio::pin!(stream);
+        let mut penalties = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            penalties.push(ResultPenalty::from_row(&row));
+        }
+
+        Ok(penalties)
+    }
+
+    /// Computes the constructors standings of a championship from its race results. The
+    /// points of a reserve go to the team of the regular driver they substituted, to the
+    /// league team the regular raced for on the race date when the league runs teams.
+    ///
+    /// # Arguments
+    /// - `championship_id`: The ID of the championship.
+    /// - `season`: The number of the season.
+    /// - `division_id`: The division to restrict the standings to, all races if none.
+    ///
+    /// # Returns
+    /// A vector of team standings, ordered by points.
+    pub async fn team_standings(
+        &self,
+        championship_id: i32,
+        season: i16,
+        division_id: Option<i16>,
+    ) -> AppResult<Vec<TeamStanding>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let team_standings_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT MIN(e.team_id), t.id, t.name, SUM(e.points)::BIGINT,
+                            COUNT(*) FILTER (WHERE e.position = 1 AND NOT e.disqualified)
+                        FROM result_entries e
+                        JOIN races r ON r.id = e.race_id
+                        LEFT JOIN race_substitutions s ON s.race_id = e.race_id
+                            AND s.steam_name = e.steam_name
+                        LEFT JOIN team_lineups l ON l.championship_id = r.championship_id
+                            AND l.steam_name = COALESCE(s.replaces, e.steam_name)
+                            AND l.joined_at <= r.date
+                            AND (l.left_at IS NULL OR l.left_at > r.date)
+                        LEFT JOIN league_teams t ON t.championship_id = l.championship_id
+                            AND t.id = l.team_id
+                        WHERE r.championship_id = $1 AND e.session_type = ANY($2)
+                            AND r.season = $3 AND ($4::SMALLINT IS NULL OR r.division_id = $4)
+                        GROUP BY t.id, t.name, CASE WHEN t.id IS NULL THEN e.team_id END
+                        ORDER BY 4 DESC, 5 DESC
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(
+                &team_standings_stmt,
+                slice_iter(&[
+                    &championship_id,
+                    &RACE_SESSION_TYPES.as_slice(),
+                    &season,
+                    &division_id,
+                ]),
+            )
+            .await?
+        };
+
+        tokio::pin!(stream);
+        let mut standings = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            standings.push(TeamStanding::from_row(&row));
+        }
+
+        Ok(standings)
+    }
+
+    /// Retrieves the classifications of every race session a driver started, including
+    /// the other drivers of those sessions, grouped by session.
+    ///
+    /// # Arguments
+    /// - `steam_name`: The steam name of the driver.
+    ///
+    /// # Returns
+    /// A vector of career entries, ordered by race date and session.
+    pub async fn career_entries(&self, steam_name: &str) -> AppResult<Vec<CareerEntry>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let career_entries_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT e.race_id, e.session_type, e.steam_name, e.team_id,
+                            e.grid_position, e.position, e.best_lap_time, e.result_status,
+                            e.disqualified
+                        FROM result_entries e
+                        JOIN races r ON r.id = e.race_id
+                        WHERE (e.race_id, e.session_type) IN (
+                            SELECT race_id, session_type FROM result_entries
+                            WHERE steam_name = $1 AND session_type = ANY($2)
+                        )
+                        ORDER BY r.date, e.race_id, e.session_type, e.position
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(
+                &career_entries_stmt,
+                slice_iter(&[&steam_name, &RACE_SESSION_TYPES.as_slice()]),
+            )
+            .await?
+        };
+
+        tokio::pin!(stream);
+        let mut entries = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            entries.push(CareerEntry::from_row(&row));
+        }
+
+        Ok(entries)
+    }
+
+    /// Retrieves the best lap of a driver on every track, from the session results and the
+    /// archived laps of all championships.
+    ///
+    /// # Arguments
+    /// - `steam_name`: The steam name of the driver.
+    ///
+    /// # Returns
+    /// A vector of best laps, ordered by track.
+    pub async fn best_laps(&self, steam_name: &str) -> AppResult<Vec<TrackBestLap>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let best_laps_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT r.track_id, MIN(laps.lap_time)
+                        FROM (
+                            SELECT race_id, best_lap_time AS lap_time FROM result_entries
+                            WHERE steam_name = $1 AND best_lap_time > 0
+                            UNION ALL
+                            SELECT race_id, lap_time FROM lap_traces
+                            WHERE steam_name = $1 AND valid
+                        ) laps
+                        JOIN races r ON r.id = laps.race_id
+                        GROUP BY r.track_id
+                        ORDER BY r.track_id
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&best_laps_stmt, slice_iter(&[&steam_name]))
+                .await?
+        };
+
+        tokio::pin!(stream);
+        let mut best_laps = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            best_laps.push(TrackBestLap::from_row(&row));
+        }
+
+        Ok(best_laps)
+    }
+
+    /// Computes the drivers standings of a season of a championship from its race results.
+    ///
+    /// # Arguments
+    /// - `championship_id`: The ID of the championship.
+    /// - `season`: The number of the season.
+    /// - `division_id`: The division to restrict the standings to, all races if none.
+    ///
+    /// # Returns
+    /// A vector of driver standings, ordered by points.
+    pub async fn standings(
+        &self,
+        championship_id: i32,
+        season: i16,
+        division_id: Option<i16>,
+    ) -> AppResult<Vec<DriverStanding>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let standings_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT e.steam_name, SUM(e.points)::BIGINT,
+                            COUNT(*) FILTER (WHERE e.position = 1 AND NOT e.disqualified),
+                            COUNT(*) FILTER (WHERE e.position <= 3 AND NOT e.disqualified),
+                            COUNT(*), t.name
+                        FROM result_entries e
+                        JOIN races r ON r.id = e.race_id
+                        LEFT JOIN team_lineups l ON l.championship_id = r.championship_id
+                            AND l.steam_name = e.steam_name AND l.left_at IS NULL
+                        LEFT JOIN league_teams t ON t.championship_id = l.championship_id
+                            AND t.id = l.team_id
+                        WHERE r.championship_id = $1 AND e.session_type = ANY($2)
+                            AND r.season = $3 AND ($4::SMALLINT IS NULL OR r.division_id = $4)
+                        GROUP BY e.steam_name, t.name
+                        ORDER BY 2 DESC, 3 DESC, 4 DESC
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(
+                &standings_stmt,
+                slice_iter(&[
+                    &championship_id,
+                    &RACE_SESSION_TYPES.as_slice(),
+                    &season,
+                    &division_id,
+                ]),
+            )
+            .await?
+        };
+
+        tokio::pin!(stream);
+        let mut standings = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            standings.push(DriverStanding::from_row(&row));
+        }
+
+        Ok(standings)
+    }
+}
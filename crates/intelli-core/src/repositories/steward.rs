@@ -0,0 +1,304 @@
+use tokio_stream::StreamExt;
+
+use db::Database;
+use entities::{AssistEvidence, Incident, RuleDeviation, SetupSnapshot, YellowFlag};
+use error::AppResult;
+use utils::slice_iter;
+
+/// Repository for the stewarding records of races.
+pub struct StewardRepository {
+    db: &'static Database,
+}
+
+impl StewardRepository {
+    /// Creates a new StewardRepository instance.
+    ///
+    /// # Arguments
+    /// - `db`: Database connection.
+    ///
+    /// # Returns
+    /// A new StewardRepository instance.
+    pub fn new(db: &'static Database) -> Self {
+        Self { db }
+    }
+
+    /// Retrieves the yellow flags shown during a race, ordered by session and time.
+    ///
+    /// # Arguments
+    /// - `race_id`: The ID of the race.
+    ///
+    /// # Returns
+    /// A vector of yellow flags.
+    pub async fn yellow_flags(&self, race_id: i32) -> AppResult<Vec<YellowFlag>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let yellow_flags_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT session_type, zone_index, zone_start, started_session_time,
+                            ended_session_time, created_at
+                        FROM yellow_flags
+                        WHERE race_id = $1
+                        ORDER BY session_type, started_session_time
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&yellow_flags_stmt, &[&race_id]).await?
+        };
+
+        tokio::pin!(stream);
+        let mut yellow_flags = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            yellow_flags.push(YellowFlag::from_row(&row));
+        }
+
+        Ok(yellow_flags)
+    }
+
+    /// Retrieves the incidents of a race with their rulings, ordered by session and time.
+    ///
+    /// # Arguments
+    /// - `race_id`: The ID of the race.
+    ///
+    /// # Returns
+    /// A vector of incidents.
+    pub async fn incidents(&self, race_id: i32) -> AppResult<Vec<Incident>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let incidents_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT i.id, i.race_id, i.session_type, i.kind, i.session_time, i.lap,
+                            i.steam_name, i.other_steam_name, i.penalty_type, i.infringement_type,
+                            i.penalty_time, i.safety_car_type, i.safety_car_event, i.created_at,
+                            r.steward_id, r.decision, r.penalty_time, r.notes, r.created_at,
+                            r.updated_at
+                        FROM incidents i
+                        LEFT JOIN incident_rulings r ON r.incident_id = i.id
+                        WHERE i.race_id = $1
+                        ORDER BY i.session_type, i.session_time
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&incidents_stmt, &[&race_id]).await?
+        };
+
+        tokio::pin!(stream);
+        let mut incidents = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            incidents.push(Incident::from_row(&row));
+        }
+
+        Ok(incidents)
+    }
+
+    /// Retrieves the rule deviations detected in a session.
+    ///
+    /// # Arguments
+    /// - `race_id`: The ID of the race.
+    /// - `session_type`: The type of the session.
+    ///
+    /// # Returns
+    /// A vector of rule deviations.
+    pub async fn rule_deviations(
+        &self,
+        race_id: i32,
+        session_type: i16,
+    ) -> AppResult<Vec<RuleDeviation>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let rule_deviations_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT session_type, rule, expected, actual, created_at
+                        FROM rule_deviations
+                        WHERE race_id = $1 AND session_type = $2
+                        ORDER BY rule
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(
+                &rule_deviations_stmt,
+                slice_iter(&[&race_id, &session_type]),
+            )
+            .await?
+        };
+
+        tokio::pin!(stream);
+        let mut deviations = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            deviations.push(RuleDeviation::from_row(&row));
+        }
+
+        Ok(deviations)
+    }
+
+    /// Retrieves the assist evidence recorded during a race, ordered by session and driver.
+    ///
+    /// # Arguments
+    /// - `race_id`: The ID of the race.
+    ///
+    /// # Returns
+    /// A vector of assist evidence.
+    pub async fn assist_evidence(&self, race_id: i32) -> AppResult<Vec<AssistEvidence>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let assist_evidence_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT session_type, steam_name, assist, inferred, samples,
+                            total_samples, level, created_at
+                        FROM assist_evidence
+                        WHERE race_id = $1
+                        ORDER BY session_type, steam_name, assist
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&assist_evidence_stmt, &[&race_id]).await?
+        };
+
+        tokio::pin!(stream);
+        let mut evidence = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            evidence.push(AssistEvidence::from_row(&row));
+        }
+
+        Ok(evidence)
+    }
+
+    /// Retrieves the setup snapshots stored during a race, ordered by session and driver.
+    ///
+    /// # Arguments
+    /// - `race_id`: The ID of the race.
+    ///
+    /// # Returns
+    /// A vector of setup snapshots.
+    pub async fn setup_snapshots(&self, race_id: i32) -> AppResult<Vec<SetupSnapshot>> {
+        let stream = {
+            let conn = self.db.pg.get().await?;
+
+            let setup_snapshots_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT session_type, steam_name, front_wing, rear_wing, on_throttle,
+                            off_throttle, front_camber, rear_camber, front_toe, rear_toe,
+                            front_suspension, rear_suspension, front_anti_roll_bar,
+                            rear_anti_roll_bar, front_suspension_height, rear_suspension_height,
+                            brake_pressure, brake_bias, engine_braking, rear_left_tyre_pressure,
+                            rear_right_tyre_pressure, front_left_tyre_pressure,
+                            front_right_tyre_pressure, ballast, fuel_load, created_at
+                        FROM setup_snapshots
+                        WHERE race_id = $1
+                        ORDER BY session_type, steam_name
+                    "#,
+                )
+                .await?;
+
+            conn.query_raw(&setup_snapshots_stmt, &[&race_id]).await?
+        };
+
+        tokio::pin!(stream);
+        let mut snapshots = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            snapshots.push(SetupSnapshot::from_row(&row));
+        }
+
+        Ok(snapshots)
+    }
+
+    /// Retrieves the championship an incident belongs to.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the incident.
+    ///
+    /// # Returns
+    /// An Option containing the championship ID if the incident exists.
+    pub async fn incident_championship(&self, id: i32) -> AppResult<Option<i32>> {
+        let row = {
+            let conn = self.db.pg.get().await?;
+
+            let incident_championship_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT r.championship_id
+                        FROM incidents i
+                        JOIN races r ON r.id = i.race_id
+                        WHERE i.id = $1
+                    "#,
+                )
+                .await?;
+
+            conn.query_opt(&incident_championship_stmt, &[&id]).await?
+        };
+
+        Ok(row.map(|row| row.get(0)))
+    }
+
+    /// Retrieves the race an incident belongs to.
+    ///
+    /// # Arguments
+    /// - `id`: The ID of the incident.
+    ///
+    /// # Returns
+    /// An Option containing the race ID if the incident exists.
+    pub async fn incident_race(&self, id: i32) -> AppResult<Option<i32>> {
+        let row = {
+            let conn = self.db.pg.get().await?;
+
+            let incident_race_stmt = conn
+                .prepare_cached(
+                    r#"
+                        SELECT race_id FROM incidents
+                        WHERE id = $1
+                    "#,
+                )
+                .await?;
+
+            conn.query_opt(&incident_race_stmt, &[&id]).await?
+        };
+
+        Ok(row.map(|row| row.get(0)))
+    }
+
+    /// Retrieves all used incident IDs.
+    ///
+    /// This method should only be called once.
+    ///
+    /// # Returns
+    /// A vector of all used incident IDs.
+    pub async fn _used_incident_ids(&self) -> AppResult<Vec<i32>> {
+        let conn = self.db.pg.get().await?;
+
+        let incident_ids_stmt = conn
+            .prepare_cached(
+                r#"
+                    SELECT id FROM incidents
+                "#,
+            )
+            .await?;
+
+        let stream = conn.query_raw(&incident_ids_stmt, slice_iter(&[])).await?;
+
+        tokio::pin!(stream);
+        let mut incidents = Vec::new();
+
+        while let Some(row) = stream.try_next().await? {
+            incidents.push(row.get(0));
+        }
+
+        Ok(incidents)
+    }
+}
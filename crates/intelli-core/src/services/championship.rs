@@ -4,12 +4,29 @@ use chrono::{DateTime, Duration, Utc};
 use postgres_types::ToSql;
 
 use db::Database;
-use error::{AppResult, ChampionshipError, CommonError, UserError};
+use entities::ChampionshipRole;
+use error::{AppResult, ChampionshipError, CommonError, DriverError, UserError};
 use id_generator::IdsGenerator;
-use structs::{ChampionshipCreationData, ChampionshipUpdateData, ChampionshipUserAddForm};
+use structs::{
+    ChampionshipCreationData, ChampionshipRulesForm, ChampionshipUpdateData,
+    ChampionshipUserAddForm, DamageThresholdsForm, DivisionForm, DriverEngineUsage, LeagueTeamForm,
+    RaceScheduleForm, ResultEntryData, SeasonRolloverForm, TeamTransferForm, TelemetryGrantsForm,
+    TimeTrialLapData,
+};
 use utils::MachinePorts;
 
-use crate::repositories::{ChampionshipRepository, UserRepository};
+use crate::{
+    engine_usage,
+    repositories::{ChampionshipRepository, RaceRepository, UserRepository},
+};
+
+// Constants
+/// League teams a championship can have, leagues may run more teams than the game
+const MAX_LEAGUE_TEAMS: usize = 100;
+/// Seasons a championship can run
+const MAX_SEASONS: i16 = 100;
+/// Divisions a championship can have
+const MAX_DIVISIONS: usize = 10;
 
 /// Defines the core operations for managing championships.
 pub trait ChampionshipServiceOperations {
@@ -52,6 +69,30 @@ pub trait ChampionshipServiceOperations {
         date: DateTime<Utc>,
     ) -> impl Future<Output = AppResult<i32>> + Send;
 
+    /// Schedules a race of the championship ahead of time, so reserves can be entered
+    /// before its sessions start.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the championship.
+    /// * `user_id` - The ID of the user scheduling the race.
+    /// * `form` - The track and date of the race.
+    ///
+    /// # Returns
+    ///
+    /// Returns the ID of the scheduled race as an `i32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user is not an admin of the championship or the championship
+    /// has no open season.
+    fn schedule_race(
+        &self,
+        id: i32,
+        user_id: i32,
+        form: &RaceScheduleForm,
+    ) -> impl Future<Output = AppResult<i32>> + Send;
+
     /// Updates an existing championship.
     ///
     /// # Arguments
@@ -71,6 +112,197 @@ pub trait ChampionshipServiceOperations {
         form: &ChampionshipUpdateData,
     ) -> impl Future<Output = AppResult<()>> + Send;
 
+    /// Replaces the session rules required by a championship.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the championship.
+    /// * `user_id` - The ID of the user updating the rules.
+    /// * `form` - The complete rule profile of the championship.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the championship is not found, the user is not the owner,
+    /// or if there's a database error.
+    fn update_rules(
+        &self,
+        id: i32,
+        user_id: i32,
+        form: &ChampionshipRulesForm,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Replaces the damage thresholds at which the engineers of a championship get alerted.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the championship.
+    /// * `user_id` - The ID of the user updating the thresholds.
+    /// * `form` - The complete list of thresholds of the championship.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the championship is not found, the user is not the owner,
+    /// or if there's a database error.
+    fn update_damage_thresholds(
+        &self,
+        id: i32,
+        user_id: i32,
+        form: &DamageThresholdsForm,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Creates a league team in a championship, mapped to a game team.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the championship.
+    /// * `user_id` - The ID of the user creating the team.
+    /// * `form` - The game team and metadata of the league team.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the championship is not found, the user is not the owner,
+    /// the team name is already used, or the championship reached the team limit.
+    ///
+    /// # Returns
+    ///
+    /// The ID of the new team.
+    fn create_team(
+        &self,
+        id: i32,
+        user_id: i32,
+        form: &LeagueTeamForm,
+    ) -> impl Future<Output = AppResult<i16>> + Send;
+
+    /// Updates the game team and metadata of a league team.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the championship.
+    /// * `user_id` - The ID of the user updating the team.
+    /// * `team_id` - The ID of the league team.
+    /// * `form` - The new game team and metadata of the team.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the championship or team is not found, the user is not the owner,
+    /// or the team name is already used.
+    fn update_team(
+        &self,
+        id: i32,
+        user_id: i32,
+        team_id: i16,
+        form: &LeagueTeamForm,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Deletes a league team, only allowed while no driver has raced in its lineup.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the championship.
+    /// * `user_id` - The ID of the user deleting the team.
+    /// * `team_id` - The ID of the league team.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the championship or team is not found, the user is not the owner or
+    /// the team has lineup history.
+    fn delete_team(
+        &self,
+        id: i32,
+        user_id: i32,
+        team_id: i16,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Moves a driver of the championship to a league team, closing their spell in the
+    /// previous team so the lineup history keeps mid-season transfers.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the championship.
+    /// * `user_id` - The ID of the user transferring the driver.
+    /// * `team_id` - The ID of the league team the driver joins.
+    /// * `form` - The driver to transfer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the championship or team is not found, the user is not the owner,
+    /// or the driver is not registered in the championship.
+    fn transfer_driver(
+        &self,
+        id: i32,
+        user_id: i32,
+        team_id: i16,
+        form: &TeamTransferForm,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Ends the current season of a championship and starts the next one. The roster of the
+    /// ending season is archived with its races and results, which stay queryable.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the championship.
+    /// * `user_id` - The ID of the user rolling over the season.
+    /// * `form` - Whether the roster carries over to the new season.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the championship is not found, the user is not the owner,
+    /// or the championship reached the season limit.
+    ///
+    /// # Returns
+    ///
+    /// The number of the new season.
+    fn rollover_season(
+        &self,
+        id: i32,
+        user_id: i32,
+        form: &SeasonRolloverForm,
+    ) -> impl Future<Output = AppResult<i16>> + Send;
+
+    /// Creates a division in a championship.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the championship.
+    /// * `user_id` - The ID of the user creating the division.
+    /// * `form` - The name and tier of the division.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the championship is not found, the user is not the owner,
+    /// the division name is already used, or the championship reached the division limit.
+    ///
+    /// # Returns
+    ///
+    /// The ID of the new division.
+    fn create_division(
+        &self,
+        id: i32,
+        user_id: i32,
+        form: &DivisionForm,
+    ) -> impl Future<Output = AppResult<i16>> + Send;
+
+    /// Moves a driver of the championship to a division.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the championship.
+    /// * `user_id` - The ID of the user moving the driver.
+    /// * `division_id` - The ID of the division.
+    /// * `form` - The driver to move.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the championship or division is not found, the user is not the
+    /// owner, or the driver is not registered in the championship.
+    fn assign_division(
+        &self,
+        id: i32,
+        user_id: i32,
+        division_id: i16,
+        form: &TeamTransferForm,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
     /// Adds a user to a championship.
     ///
     /// # Arguments
@@ -117,19 +349,56 @@ pub trait ChampionshipServiceOperations {
     /// * `race_id` - The ID of the race to which the result belongs.
     /// * `session_type` - The type of session (e.g., practice, qualifying, race).
     /// * `data` - The raw data of the race result.
+    /// * `entries` - The final classification of the drivers in the session.
     ///
     /// # Errors
     ///
     /// Returns an error if the race is not found, the session type is invalid,
     /// or if there's a database error while storing the result.
-    #[allow(unused)]
     fn add_race_result(
         &self,
         race_id: i32,
         session_type: i16,
         data: &[u8],
+        entries: &[ResultEntryData],
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Stores a time trial lap of a championship driver, replacing the previous lap of
+    /// the same kind on the track. Personal bests are only replaced by faster laps.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the championship.
+    /// * `lap` - The driver, the track and the times of the lap.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the championship is not found or if there's a database error.
+    fn add_time_trial_lap(
+        &self,
+        id: i32,
+        lap: &TimeTrialLapData,
     ) -> impl Future<Output = AppResult<()>> + Send;
 
+    /// Counts the power unit components every driver used in the season.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the championship.
+    ///
+    /// # Returns
+    ///
+    /// Returns the components used by every driver against the allocation, with a warning
+    /// when the allocation is exceeded or about to be.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the championship is not found or if there's a database error.
+    fn engine_usage(
+        &self,
+        id: i32,
+    ) -> impl Future<Output = AppResult<Vec<DriverEngineUsage>>> + Send;
+
     /// Removes a user from a championship.
     ///
     /// # Arguments
@@ -149,6 +418,27 @@ pub trait ChampionshipServiceOperations {
         remove_user_id: i32,
     ) -> impl Future<Output = AppResult<()>> + Send;
 
+    /// Replaces the teams a user of a championship can stream telemetry from.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the championship.
+    /// * `user_id` - The ID of the admin granting the access.
+    /// * `grant_user_id` - The ID of the user receiving the access.
+    /// * `form` - The complete list of granted teams.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user is not an admin of the championship, the user
+    /// receiving the access is not part of it, or if there's a database error.
+    fn update_telemetry_grants(
+        &self,
+        id: i32,
+        user_id: i32,
+        grant_user_id: i32,
+        form: &TelemetryGrantsForm,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
     /// Removes a driver from a championship.
     ///
     /// # Arguments
@@ -201,6 +491,7 @@ pub struct ChampionshipService {
     user_repo: &'static UserRepository,
     championship_repo: &'static ChampionshipRepository,
     ids_generator: IdsGenerator,
+    race_ids_generator: IdsGenerator,
 }
 
 impl ChampionshipService {
@@ -211,6 +502,7 @@ impl ChampionshipService {
     /// * `db` - The database connection.
     /// * `user_repo` - The user repository.
     /// * `championship_repo` - The championship repository.
+    /// * `race_repo` - The race repository.
     ///
     /// # Errors
     ///
@@ -219,6 +511,7 @@ impl ChampionshipService {
         db: &'static Database,
         user_repo: &'static UserRepository,
         championship_repo: &'static ChampionshipRepository,
+        race_repo: &'static RaceRepository,
     ) -> AppResult<Self> {
         let machine_ports = {
             let used_ports = championship_repo._ports_in_use().await?;
@@ -230,12 +523,18 @@ impl ChampionshipService {
             IdsGenerator::new(700000000..799999999, used_ids)
         };
 
+        let race_ids_generator = {
+            let used_ids = race_repo._used_ids().await?;
+            IdsGenerator::new(800000000..899999999, used_ids)
+        };
+
         Ok(Self {
             db,
             user_repo,
             championship_repo,
             machine_ports,
             ids_generator,
+            race_ids_generator,
         })
     }
 
@@ -251,25 +550,34 @@ impl ChampionshipService {
             Err(ChampionshipError::AlreadyExists)?
         };
 
-        let conn = self.db.pg.get().await?;
+        let mut conn = self.db.pg.get().await?;
+        let tx = conn.transaction().await?;
 
-        let create_championship_stmt_fut = conn.prepare_cached(
+        let create_championship_stmt_fut = tx.prepare_cached(
             r#"
                 INSERT INTO championships (id, port, name, category, owner_id)
                 VALUES ($1,$2,$3,$4,$5)
             "#,
         );
 
-        let relate_user_with_championship_stmt_fut = conn.prepare_cached(
+        let relate_user_with_championship_stmt_fut = tx.prepare_cached(
             r#"
                 INSERT INTO championship_users (user_id, championship_id, role)
                 VALUES ($1,$2, 'Admin')
             "#,
         );
 
-        let (create_championship_stmt, relate_user_with_championship_stmt) = tokio::try_join!(
+        let create_season_stmt_fut = tx.prepare_cached(
+            r#"
+                INSERT INTO championship_seasons (championship_id, number)
+                VALUES ($1, 1)
+            "#,
+        );
+
+        let (create_championship_stmt, relate_user_with_championship_stmt, create_season_stmt) = tokio::try_join!(
             create_championship_stmt_fut,
-            relate_user_with_championship_stmt_fut
+            relate_user_with_championship_stmt_fut,
+            create_season_stmt_fut
         )?;
 
         let id = self.ids_generator.next();
@@ -279,21 +587,29 @@ impl ChampionshipService {
             .next()
             .ok_or(ChampionshipError::NoPortsAvailable)?;
 
-        let result = conn
-            .execute(
+        // The championship, its admin and its first season are stored together, otherwise
+        // the port goes back to the pool
+        let result = async move {
+            tx.execute(
                 &create_championship_stmt,
                 &[&id, &port, &payload.name, &payload.category, &user_id],
             )
-            .await;
+            .await?;
+
+            tx.execute_raw(&relate_user_with_championship_stmt, &[&user_id, &id])
+                .await?;
+
+            tx.execute_raw(&create_season_stmt, &[&id]).await?;
+
+            tx.commit().await
+        }
+        .await;
 
         if let Err(e) = result {
             self.machine_ports.return_port(port);
             return Err(e)?;
         }
 
-        conn.execute_raw(&relate_user_with_championship_stmt, &[&user_id, &id])
-            .await?;
-
         self.db.cache.championship.delete_by_user(&user_id);
         Ok(())
     }
@@ -305,21 +621,28 @@ impl ChampionshipService {
         let create_race_stmt = conn
             .prepare_cached(
                 r#"
-                    INSERT INTO races (championship_id, track_id, date)
-                    VALUES ($1, $2, $3)
-                    RETURNING id
+                    INSERT INTO races (id, championship_id, track_id, date, season)
+                    SELECT $1, $2, $3, $4, number FROM championship_seasons
+                    WHERE championship_id = $2 AND ended_at IS NULL
+                    FOR SHARE
                 "#,
             )
             .await?;
 
-        let id = conn
-            .query_one(&create_race_stmt, &[&id, &track_id, &date])
+        let race_id = self.race_ids_generator.next();
+
+        // Nothing is inserted if the championship has no open season
+        if conn
+            .execute(&create_race_stmt, &[&race_id, &id, &track_id, &date])
             .await?
-            .get(0);
+            == 0
+        {
+            Err(ChampionshipError::SeasonNotFound)?
+        }
 
         self.db.cache.championship.delete_races(&id);
 
-        Ok(id)
+        Ok(race_id)
     }
 
     /// Internal method to update a championship.
@@ -370,83 +693,604 @@ impl ChampionshipService {
         Ok(())
     }
 
-    /// Internal method to add a user to a championship.
+    /// Internal method to replace the rule profile of a championship.
     #[inline]
-    async fn _add_user(&self, id: i32, form: ChampionshipUserAddForm) -> AppResult<()> {
-        let bind_user_id = {
-            let Some(bind_user) = self.user_repo.find_by_email(&form.email).await? else {
-                Err(UserError::NotFound)?
-            };
-
-            bind_user.id
-        };
+    async fn _update_rules(&self, id: i32, form: &ChampionshipRulesForm) -> AppResult<()> {
+        let mut conn = self.db.pg.get().await?;
+        let tx = conn.transaction().await?;
 
-        let conn = self.db.pg.get().await?;
-
-        let add_user_stmt = conn
-            .prepare_cached(
+        let (delete_rules_stmt, add_rule_stmt) = tokio::try_join!(
+            tx.prepare_cached(
                 r#"
-                    INSERT INTO championship_users (user_id, championship_id, role, team_id)
-                    VALUES ($1,$2,$3,$4)
+                    DELETE FROM championship_rules
+                    WHERE championship_id = $1
+                "#,
+            ),
+            tx.prepare_cached(
+                r#"
+                    INSERT INTO championship_rules (championship_id, rule, value)
+                    VALUES ($1, $2, $3)
+                    ON CONFLICT (championship_id, rule) DO UPDATE
+                    SET value = EXCLUDED.value
                 "#,
             )
-            .await?;
+        )?;
 
-        conn.execute(
-            &add_user_stmt,
-            &[&bind_user_id, &id, &form.role, &form.team_id],
-        )
-        .await?;
+        tx.execute(&delete_rules_stmt, &[&id]).await?;
 
-        self.db.cache.championship.delete_by_user(&bind_user_id);
+        for rule in &form.rules {
+            tx.execute(&add_rule_stmt, &[&id, &rule.rule, &rule.value])
+                .await?;
+        }
 
+        tx.commit().await?;
         Ok(())
     }
 
-    async fn _add_driver(
+    /// Internal method to replace the damage thresholds of a championship.
+    #[inline]
+    async fn _update_damage_thresholds(
         &self,
         id: i32,
-        steam_name: &str,
-        team_id: i16,
-        number: i16,
+        form: &DamageThresholdsForm,
     ) -> AppResult<()> {
-        let conn = self.db.pg.get().await?;
+        let mut conn = self.db.pg.get().await?;
+        let tx = conn.transaction().await?;
 
-        let add_driver_stmt = conn
-            .prepare_cached(
+        let (delete_thresholds_stmt, add_threshold_stmt) = tokio::try_join!(
+            tx.prepare_cached(
                 r#"
-                    INSERT INTO championship_drivers (steam_name, championship_id, team_id, number)
-                    VALUES ($1, $2, $3, $4)
+                    DELETE FROM damage_thresholds
+                    WHERE championship_id = $1
+                "#,
+            ),
+            tx.prepare_cached(
+                r#"
+                    INSERT INTO damage_thresholds (championship_id, component, value)
+                    VALUES ($1, $2, $3)
+                    ON CONFLICT (championship_id, component) DO UPDATE
+                    SET value = EXCLUDED.value
                 "#,
             )
-            .await?;
+        )?;
 
-        conn.execute(&add_driver_stmt, &[&steam_name, &id, &team_id, &number])
+        tx.execute(&delete_thresholds_stmt, &[&id]).await?;
+
+        for threshold in &form.thresholds {
+            tx.execute(
+                &add_threshold_stmt,
+                &[&id, &threshold.component, &threshold.value],
+            )
             .await?;
+        }
 
+        tx.commit().await?;
         Ok(())
     }
 
-    async fn _add_race_result(
-        &self,
-        race_id: i32,
-        session_type: i16,
-        data: &[u8],
-    ) -> AppResult<()> {
-        let conn = self.db.pg.get().await?;
+    /// Internal method to create a league team with the next free ID of the championship.
+    #[inline]
+    async fn _create_team(&self, id: i32, form: &LeagueTeamForm) -> AppResult<i16> {
+        let mut conn = self.db.pg.get().await?;
+        let tx = conn.transaction().await?;
 
-        let add_result_stmt = conn
-            .prepare_cached(
+        // Serializes team creation per championship so two requests can't pick the same ID
+        let (lock_championship_stmt, create_team_stmt) = tokio::try_join!(
+            tx.prepare_cached(
                 r#"
-                    INSERT INTO results (race_id, session_type, data)
-                    VALUES ($1, $2, $3)
+                    SELECT 1 FROM championships
+                    WHERE id = $1
+                    FOR NO KEY UPDATE
+                "#,
+            ),
+            tx.prepare_cached(
+                r#"
+                    INSERT INTO league_teams (championship_id, id, game_team_id, name, color, logo)
+                    SELECT $1, COALESCE(MAX(id), 0) + 1, $2, $3, $4, $5
+                    FROM league_teams
+                    WHERE championship_id = $1
+                    ON CONFLICT (championship_id, name) DO NOTHING
+                    RETURNING id
+                "#,
+            )
+        )?;
+
+        tx.execute(&lock_championship_stmt, &[&id]).await?;
+
+        let Some(row) = tx
+            .query_opt(
+                &create_team_stmt,
+                &[&id, &form.game_team_id, &form.name, &form.color, &form.logo],
+            )
+            .await?
+        else {
+            Err(ChampionshipError::TeamAlreadyExists)?
+        };
+
+        tx.commit().await?;
+        Ok(row.get(0))
+    }
+
+    /// Internal method to update a league team.
+    #[inline]
+    async fn _update_team(&self, id: i32, team_id: i16, form: &LeagueTeamForm) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let update_team_stmt = conn
+            .prepare_cached(
+                r#"
+                    UPDATE league_teams
+                    SET game_team_id = $3, name = $4, color = $5, logo = $6,
+                        updated_at = CURRENT_TIMESTAMP
+                    WHERE championship_id = $1 AND id = $2
                 "#,
             )
             .await?;
 
-        conn.execute(&add_result_stmt, &[&race_id, &session_type, &data])
+        conn.execute(
+            &update_team_stmt,
+            &[
+                &id,
+                &team_id,
+                &form.game_team_id,
+                &form.name,
+                &form.color,
+                &form.logo,
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Internal method to delete a league team that never had a driver in its lineup.
+    #[inline]
+    async fn _delete_team(&self, id: i32, team_id: i16) -> AppResult<()> {
+        let mut conn = self.db.pg.get().await?;
+        let tx = conn.transaction().await?;
+
+        let (lock_team_stmt, has_lineups_stmt, delete_team_stmt) = tokio::try_join!(
+            tx.prepare_cached(
+                r#"
+                    SELECT 1 FROM league_teams
+                    WHERE championship_id = $1 AND id = $2
+                    FOR UPDATE
+                "#,
+            ),
+            tx.prepare_cached(
+                r#"
+                    SELECT EXISTS (
+                        SELECT 1 FROM team_lineups
+                        WHERE championship_id = $1 AND team_id = $2
+                    )
+                "#,
+            ),
+            tx.prepare_cached(
+                r#"
+                    DELETE FROM league_teams
+                    WHERE championship_id = $1 AND id = $2
+                "#,
+            )
+        )?;
+
+        // Locking the team first keeps a concurrent transfer from adding a lineup the
+        // delete would cascade away
+        if tx
+            .query_opt(&lock_team_stmt, &[&id, &team_id])
+            .await?
+            .is_none()
+        {
+            Err(ChampionshipError::TeamNotFound)?
+        }
+
+        let has_lineups: bool = tx
+            .query_one(&has_lineups_stmt, &[&id, &team_id])
+            .await?
+            .get(0);
+
+        if has_lineups {
+            Err(ChampionshipError::TeamHasHistory)?
+        }
+
+        tx.execute(&delete_team_stmt, &[&id, &team_id]).await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Internal method to move a driver to a league team, also updating the game team the
+    /// driver is expected to race with.
+    #[inline]
+    async fn _transfer_driver(
+        &self,
+        id: i32,
+        team_id: i16,
+        game_team_id: i16,
+        steam_name: &str,
+    ) -> AppResult<()> {
+        let mut conn = self.db.pg.get().await?;
+        let tx = conn.transaction().await?;
+
+        let (close_lineup_stmt, open_lineup_stmt, update_driver_stmt) = tokio::try_join!(
+            tx.prepare_cached(
+                r#"
+                    UPDATE team_lineups
+                    SET left_at = CURRENT_TIMESTAMP
+                    WHERE championship_id = $1 AND steam_name = $2 AND left_at IS NULL
+                        AND team_id <> $3
+                "#,
+            ),
+            tx.prepare_cached(
+                r#"
+                    INSERT INTO team_lineups (championship_id, team_id, steam_name)
+                    VALUES ($1, $3, $2)
+                    ON CONFLICT (championship_id, steam_name) WHERE left_at IS NULL DO NOTHING
+                "#,
+            ),
+            tx.prepare_cached(
+                r#"
+                    UPDATE championship_drivers
+                    SET team_id = $3, updated_at = CURRENT_TIMESTAMP
+                    WHERE championship_id = $1 AND steam_name = $2
+                "#,
+            )
+        )?;
+
+        tx.execute(&close_lineup_stmt, &[&id, &steam_name, &team_id])
+            .await?;
+
+        tx.execute(&open_lineup_stmt, &[&id, &steam_name, &team_id])
+            .await?;
+
+        tx.execute(&update_driver_stmt, &[&id, &steam_name, &game_team_id])
             .await?;
 
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Internal method to archive the roster of the current season and start the next one.
+    #[inline]
+    async fn _rollover_season(&self, id: i32, keep_drivers: bool) -> AppResult<i16> {
+        let mut conn = self.db.pg.get().await?;
+        let tx = conn.transaction().await?;
+
+        let (current_season_stmt, archive_drivers_stmt, end_season_stmt, start_season_stmt) = tokio::try_join!(
+            tx.prepare_cached(
+                r#"
+                    SELECT number FROM championship_seasons
+                    WHERE championship_id = $1 AND ended_at IS NULL
+                    FOR UPDATE
+                "#,
+            ),
+            tx.prepare_cached(
+                r#"
+                    INSERT INTO season_drivers
+                        (championship_id, season, steam_name, team_id, number, division_id)
+                    SELECT championship_id, $2, steam_name, team_id, number, division_id
+                    FROM championship_drivers
+                    WHERE championship_id = $1
+                "#,
+            ),
+            tx.prepare_cached(
+                r#"
+                    UPDATE championship_seasons
+                    SET ended_at = CURRENT_TIMESTAMP
+                    WHERE championship_id = $1 AND number = $2
+                "#,
+            ),
+            tx.prepare_cached(
+                r#"
+                    INSERT INTO championship_seasons (championship_id, number)
+                    VALUES ($1, $2 + 1)
+                "#,
+            )
+        )?;
+
+        // Locking the open season makes a concurrent rollover wait and then find no open season
+        let Some(row) = tx.query_opt(&current_season_stmt, &[&id]).await? else {
+            Err(ChampionshipError::SeasonNotFound)?
+        };
+
+        let season: i16 = row.get(0);
+
+        if season >= MAX_SEASONS {
+            Err(ChampionshipError::LimitReached)?
+        }
+
+        tx.execute(&archive_drivers_stmt, &[&id, &season]).await?;
+
+        tx.execute(&end_season_stmt, &[&id, &season]).await?;
+
+        tx.execute(&start_season_stmt, &[&id, &season]).await?;
+
+        if !keep_drivers {
+            let (remove_drivers_stmt, close_lineups_stmt) = tokio::try_join!(
+                tx.prepare_cached(
+                    r#"
+                        DELETE FROM championship_drivers
+                        WHERE championship_id = $1
+                    "#,
+                ),
+                tx.prepare_cached(
+                    r#"
+                        UPDATE team_lineups
+                        SET left_at = CURRENT_TIMESTAMP
+                        WHERE championship_id = $1 AND left_at IS NULL
+                    "#,
+                )
+            )?;
+
+            tx.execute(&remove_drivers_stmt, &[&id]).await?;
+
+            tx.execute(&close_lineups_stmt, &[&id]).await?;
+        }
+
+        tx.commit().await?;
+        Ok(season + 1)
+    }
+
+    /// Internal method to create a division with the next free ID of the championship.
+    #[inline]
+    async fn _create_division(&self, id: i32, form: &DivisionForm) -> AppResult<i16> {
+        let conn = self.db.pg.get().await?;
+
+        let create_division_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO championship_divisions (championship_id, id, name, tier)
+                    SELECT $1, COALESCE(MAX(id), 0) + 1, $2, $3
+                    FROM championship_divisions
+                    WHERE championship_id = $1
+                    RETURNING id
+                "#,
+            )
+            .await?;
+
+        let row = conn
+            .query_one(&create_division_stmt, &[&id, &form.name, &form.tier])
+            .await?;
+
+        Ok(row.get(0))
+    }
+
+    /// Internal method to move a driver to a division.
+    #[inline]
+    async fn _assign_division(&self, id: i32, division_id: i16, steam_name: &str) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let assign_division_stmt = conn
+            .prepare_cached(
+                r#"
+                    UPDATE championship_drivers
+                    SET division_id = $3, updated_at = CURRENT_TIMESTAMP
+                    WHERE championship_id = $1 AND steam_name = $2
+                "#,
+            )
+            .await?;
+
+        conn.execute(&assign_division_stmt, &[&id, &steam_name, &division_id])
+            .await?;
+
+        Ok(())
+    }
+
+    /// Internal method to replace the telemetry grants of a user.
+    #[inline]
+    async fn _update_telemetry_grants(
+        &self,
+        id: i32,
+        grant_user_id: i32,
+        form: &TelemetryGrantsForm,
+    ) -> AppResult<()> {
+        let mut conn = self.db.pg.get().await?;
+        let tx = conn.transaction().await?;
+
+        let (delete_grants_stmt, add_grant_stmt) = tokio::try_join!(
+            tx.prepare_cached(
+                r#"
+                    DELETE FROM telemetry_grants
+                    WHERE championship_id = $1 AND user_id = $2
+                "#,
+            ),
+            tx.prepare_cached(
+                r#"
+                    INSERT INTO telemetry_grants (championship_id, user_id, team_id)
+                    VALUES ($1, $2, $3)
+                    ON CONFLICT DO NOTHING
+                "#,
+            )
+        )?;
+
+        tx.execute(&delete_grants_stmt, &[&id, &grant_user_id])
+            .await?;
+
+        for team_id in &form.team_ids {
+            tx.execute(&add_grant_stmt, &[&id, &grant_user_id, team_id])
+                .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Internal method to add a user to a championship.
+    #[inline]
+    async fn _add_user(&self, id: i32, form: ChampionshipUserAddForm) -> AppResult<()> {
+        let bind_user_id = {
+            let Some(bind_user) = self.user_repo.find_by_email(&form.email).await? else {
+                Err(UserError::NotFound)?
+            };
+
+            bind_user.id
+        };
+
+        let conn = self.db.pg.get().await?;
+
+        let add_user_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO championship_users (user_id, championship_id, role, team_id)
+                    VALUES ($1,$2,$3,$4)
+                "#,
+            )
+            .await?;
+
+        conn.execute(
+            &add_user_stmt,
+            &[&bind_user_id, &id, &form.role, &form.team_id],
+        )
+        .await?;
+
+        self.db.cache.championship.delete_by_user(&bind_user_id);
+
+        Ok(())
+    }
+
+    async fn _add_driver(
+        &self,
+        id: i32,
+        steam_name: &str,
+        team_id: i16,
+        number: i16,
+    ) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let add_driver_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO championship_drivers (steam_name, championship_id, team_id, number)
+                    VALUES ($1, $2, $3, $4)
+                "#,
+            )
+            .await?;
+
+        conn.execute(&add_driver_stmt, &[&steam_name, &id, &team_id, &number])
+            .await?;
+
+        Ok(())
+    }
+
+    /// Internal method to store a session result with its classification.
+    #[inline]
+    async fn _add_race_result(
+        &self,
+        race_id: i32,
+        session_type: i16,
+        data: &[u8],
+        entries: &[ResultEntryData],
+    ) -> AppResult<()> {
+        let mut conn = self.db.pg.get().await?;
+        let tx = conn.transaction().await?;
+
+        let (add_result_stmt, add_entry_stmt) = tokio::try_join!(
+            tx.prepare_cached(
+                r#"
+                    INSERT INTO results (race_id, session_type, data)
+                    VALUES ($1, $2, $3)
+                    ON CONFLICT DO NOTHING
+                "#,
+            ),
+            tx.prepare_cached(
+                r#"
+                    INSERT INTO result_entries
+                        (race_id, session_type, steam_name, team_id, grid_position, laps,
+                        race_time, penalties_time, best_lap_time, result_status,
+                        original_position, original_points, position, points)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $11, $12)
+                "#,
+            )
+        )?;
+
+        let inserted = tx
+            .execute(&add_result_stmt, &[&race_id, &session_type, &data])
+            .await?;
+
+        // The game can send the final classification more than once, keep the first one
+        if inserted == 0 {
+            return Ok(());
+        }
+
+        for entry in entries {
+            tx.execute(
+                &add_entry_stmt,
+                &[
+                    &race_id,
+                    &session_type,
+                    &entry.steam_name.as_ref(),
+                    &entry.team_id,
+                    &entry.grid_position,
+                    &entry.laps,
+                    &entry.race_time,
+                    &entry.penalties_time,
+                    &entry.best_lap_time,
+                    &entry.result_status,
+                    &entry.position,
+                    &entry.points,
+                ],
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Internal method to store a time trial lap.
+    #[inline]
+    async fn _add_time_trial_lap(&self, id: i32, lap: &TimeTrialLapData) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let add_time_trial_lap_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO time_trial_laps
+                        (championship_id, steam_name, track_id, kind, team_id, lap_time,
+                        sector1_time, sector2_time, sector3_time, traction_control,
+                        gearbox_assist, anti_lock_brakes, equal_car_performance, custom_setup,
+                        valid)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+                    ON CONFLICT (championship_id, steam_name, track_id, kind) DO UPDATE
+                    SET team_id = EXCLUDED.team_id,
+                        lap_time = EXCLUDED.lap_time,
+                        sector1_time = EXCLUDED.sector1_time,
+                        sector2_time = EXCLUDED.sector2_time,
+                        sector3_time = EXCLUDED.sector3_time,
+                        traction_control = EXCLUDED.traction_control,
+                        gearbox_assist = EXCLUDED.gearbox_assist,
+                        anti_lock_brakes = EXCLUDED.anti_lock_brakes,
+                        equal_car_performance = EXCLUDED.equal_car_performance,
+                        custom_setup = EXCLUDED.custom_setup,
+                        valid = EXCLUDED.valid,
+                        recorded_at = CURRENT_TIMESTAMP
+                    WHERE time_trial_laps.kind <> 'PersonalBest'
+                        OR EXCLUDED.lap_time < time_trial_laps.lap_time
+                "#,
+            )
+            .await?;
+
+        conn.execute(
+            &add_time_trial_lap_stmt,
+            &[
+                &id,
+                &lap.steam_name.as_ref(),
+                &lap.track_id,
+                &lap.kind,
+                &lap.team_id,
+                &lap.lap_time,
+                &lap.sector1_time,
+                &lap.sector2_time,
+                &lap.sector3_time,
+                &lap.traction_control,
+                &lap.gearbox_assist,
+                &lap.anti_lock_brakes,
+                &lap.equal_car_performance,
+                &lap.custom_setup,
+                &lap.valid,
+            ],
+        )
+        .await?;
+
         Ok(())
     }
 
@@ -544,6 +1388,21 @@ impl ChampionshipServiceOperations for ChampionshipService {
         self._create_race(id, track_id, date).await
     }
 
+    async fn schedule_race(
+        &self,
+        id: i32,
+        user_id: i32,
+        form: &RaceScheduleForm,
+    ) -> AppResult<i32> {
+        let relation = self.championship_repo.user_relation(id, user_id).await?;
+
+        if !relation.is_some_and(|relation| relation.role == ChampionshipRole::Admin) {
+            Err(ChampionshipError::NotAdmin)?
+        }
+
+        self._create_race(id, form.track_id, form.date).await
+    }
+
     async fn update(&self, id: i32, user_id: i32, form: &ChampionshipUpdateData) -> AppResult<()> {
         {
             let Some(championship) = self.championship_repo.find(id).await? else {
@@ -564,6 +1423,241 @@ impl ChampionshipServiceOperations for ChampionshipService {
         self._update(id, form).await
     }
 
+    async fn update_rules(
+        &self,
+        id: i32,
+        user_id: i32,
+        form: &ChampionshipRulesForm,
+    ) -> AppResult<()> {
+        {
+            let Some(championship) = self.championship_repo.find(id).await? else {
+                Err(ChampionshipError::NotFound)?
+            };
+
+            if championship.owner_id != user_id {
+                Err(ChampionshipError::NotOwner)?
+            }
+        }
+
+        self._update_rules(id, form).await
+    }
+
+    async fn update_damage_thresholds(
+        &self,
+        id: i32,
+        user_id: i32,
+        form: &DamageThresholdsForm,
+    ) -> AppResult<()> {
+        {
+            let Some(championship) = self.championship_repo.find(id).await? else {
+                Err(ChampionshipError::NotFound)?
+            };
+
+            if championship.owner_id != user_id {
+                Err(ChampionshipError::NotOwner)?
+            }
+        }
+
+        self._update_damage_thresholds(id, form).await
+    }
+
+    async fn create_team(&self, id: i32, user_id: i32, form: &LeagueTeamForm) -> AppResult<i16> {
+        {
+            let Some(championship) = self.championship_repo.find(id).await? else {
+                Err(ChampionshipError::NotFound)?
+            };
+
+            if championship.owner_id != user_id {
+                Err(ChampionshipError::NotOwner)?
+            }
+        }
+
+        let teams = self.championship_repo.teams(id).await?;
+
+        if teams.len() >= MAX_LEAGUE_TEAMS {
+            Err(ChampionshipError::LimitReached)?
+        }
+
+        if teams.iter().any(|team| team.name.as_ref() == form.name) {
+            Err(ChampionshipError::TeamAlreadyExists)?
+        }
+
+        self._create_team(id, form).await
+    }
+
+    async fn update_team(
+        &self,
+        id: i32,
+        user_id: i32,
+        team_id: i16,
+        form: &LeagueTeamForm,
+    ) -> AppResult<()> {
+        {
+            let Some(championship) = self.championship_repo.find(id).await? else {
+                Err(ChampionshipError::NotFound)?
+            };
+
+            if championship.owner_id != user_id {
+                Err(ChampionshipError::NotOwner)?
+            }
+        }
+
+        let teams = self.championship_repo.teams(id).await?;
+
+        if !teams.iter().any(|team| team.id == team_id) {
+            Err(ChampionshipError::TeamNotFound)?
+        }
+
+        if teams
+            .iter()
+            .any(|team| team.id != team_id && team.name.as_ref() == form.name)
+        {
+            Err(ChampionshipError::TeamAlreadyExists)?
+        }
+
+        self._update_team(id, team_id, form).await
+    }
+
+    async fn delete_team(&self, id: i32, user_id: i32, team_id: i16) -> AppResult<()> {
+        {
+            let Some(championship) = self.championship_repo.find(id).await? else {
+                Err(ChampionshipError::NotFound)?
+            };
+
+            if championship.owner_id != user_id {
+                Err(ChampionshipError::NotOwner)?
+            }
+        }
+
+        let teams = self.championship_repo.teams(id).await?;
+
+        if !teams.iter().any(|team| team.id == team_id) {
+            Err(ChampionshipError::TeamNotFound)?
+        }
+
+        self._delete_team(id, team_id).await
+    }
+
+    async fn transfer_driver(
+        &self,
+        id: i32,
+        user_id: i32,
+        team_id: i16,
+        form: &TeamTransferForm,
+    ) -> AppResult<()> {
+        {
+            let Some(championship) = self.championship_repo.find(id).await? else {
+                Err(ChampionshipError::NotFound)?
+            };
+
+            if championship.owner_id != user_id {
+                Err(ChampionshipError::NotOwner)?
+            }
+        }
+
+        let (teams, drivers) = tokio::try_join!(
+            self.championship_repo.teams(id),
+            self.championship_repo.drivers(id)
+        )?;
+
+        let Some(team) = teams.iter().find(|team| team.id == team_id) else {
+            Err(ChampionshipError::TeamNotFound)?
+        };
+
+        if !drivers
+            .iter()
+            .any(|driver| driver.steam_name.as_ref() == form.steam_name)
+        {
+            Err(DriverError::NotFound)?
+        }
+
+        self._transfer_driver(id, team_id, team.game_team_id, &form.steam_name)
+            .await
+    }
+
+    async fn rollover_season(
+        &self,
+        id: i32,
+        user_id: i32,
+        form: &SeasonRolloverForm,
+    ) -> AppResult<i16> {
+        {
+            let Some(championship) = self.championship_repo.find(id).await? else {
+                Err(ChampionshipError::NotFound)?
+            };
+
+            if championship.owner_id != user_id {
+                Err(ChampionshipError::NotOwner)?
+            }
+        }
+
+        self._rollover_season(id, form.keep_drivers).await
+    }
+
+    async fn create_division(&self, id: i32, user_id: i32, form: &DivisionForm) -> AppResult<i16> {
+        {
+            let Some(championship) = self.championship_repo.find(id).await? else {
+                Err(ChampionshipError::NotFound)?
+            };
+
+            if championship.owner_id != user_id {
+                Err(ChampionshipError::NotOwner)?
+            }
+        }
+
+        let divisions = self.championship_repo.divisions(id).await?;
+
+        if divisions.len() >= MAX_DIVISIONS {
+            Err(ChampionshipError::LimitReached)?
+        }
+
+        if divisions
+            .iter()
+            .any(|division| division.name.as_ref() == form.name)
+        {
+            Err(ChampionshipError::DivisionAlreadyExists)?
+        }
+
+        self._create_division(id, form).await
+    }
+
+    async fn assign_division(
+        &self,
+        id: i32,
+        user_id: i32,
+        division_id: i16,
+        form: &TeamTransferForm,
+    ) -> AppResult<()> {
+        {
+            let Some(championship) = self.championship_repo.find(id).await? else {
+                Err(ChampionshipError::NotFound)?
+            };
+
+            if championship.owner_id != user_id {
+                Err(ChampionshipError::NotOwner)?
+            }
+        }
+
+        let (divisions, drivers) = tokio::try_join!(
+            self.championship_repo.divisions(id),
+            self.championship_repo.drivers(id)
+        )?;
+
+        if !divisions.iter().any(|division| division.id == division_id) {
+            Err(ChampionshipError::DivisionNotFound)?
+        }
+
+        if !drivers
+            .iter()
+            .any(|driver| driver.steam_name.as_ref() == form.steam_name)
+        {
+            Err(DriverError::NotFound)?
+        }
+
+        self._assign_division(id, division_id, &form.steam_name)
+            .await
+    }
+
     async fn add_user(
         &self,
         id: i32,
@@ -594,9 +1688,33 @@ impl ChampionshipServiceOperations for ChampionshipService {
         self._add_driver(id, steam_name, team_id, number).await
     }
 
-    async fn add_race_result(&self, race_id: i32, session_type: i16, data: &[u8]) -> AppResult<()> {
+    async fn add_race_result(
+        &self,
+        race_id: i32,
+        session_type: i16,
+        data: &[u8],
+        entries: &[ResultEntryData],
+    ) -> AppResult<()> {
         // TODO: Maybe add checks for race_id
-        self._add_race_result(race_id, session_type, data).await
+        self._add_race_result(race_id, session_type, data, entries)
+            .await
+    }
+
+    async fn add_time_trial_lap(&self, id: i32, lap: &TimeTrialLapData) -> AppResult<()> {
+        if self.championship_repo.find(id).await?.is_none() {
+            Err(ChampionshipError::NotFound)?
+        }
+
+        self._add_time_trial_lap(id, lap).await
+    }
+
+    async fn engine_usage(&self, id: i32) -> AppResult<Vec<DriverEngineUsage>> {
+        if self.championship_repo.find(id).await?.is_none() {
+            Err(ChampionshipError::NotFound)?
+        }
+
+        let wear = self.championship_repo.engine_wear(id).await?;
+        Ok(engine_usage::season_usage(&wear))
     }
 
     async fn remove_user(&self, id: i32, user_id: i32, remove_user_id: i32) -> AppResult<()> {
@@ -617,6 +1735,29 @@ impl ChampionshipServiceOperations for ChampionshipService {
         self._remove_user(id, remove_user_id).await
     }
 
+    async fn update_telemetry_grants(
+        &self,
+        id: i32,
+        user_id: i32,
+        grant_user_id: i32,
+        form: &TelemetryGrantsForm,
+    ) -> AppResult<()> {
+        let (relation, grant_relation) = tokio::try_join!(
+            self.championship_repo.user_relation(id, user_id),
+            self.championship_repo.user_relation(id, grant_user_id)
+        )?;
+
+        if !relation.is_some_and(|relation| relation.role == ChampionshipRole::Admin) {
+            Err(ChampionshipError::NotAdmin)?
+        }
+
+        if grant_relation.is_none() {
+            Err(UserError::NotFound)?
+        }
+
+        self._update_telemetry_grants(id, grant_user_id, form).await
+    }
+
     async fn remove_driver(&self, id: i32, steam_name: &str) -> AppResult<()> {
         // Maybe do some checks
         self._remove_driver(id, steam_name).await
@@ -1,9 +1,14 @@
 use std::future::Future;
 
 use db::Database;
-use error::{AppResult, DriverError};
+use entities::{ChampionshipRole, DriverClaimStatus};
+use error::{AppResult, ChampionshipError, DriverError};
+use structs::{DriverClaimReviewForm, DriverProfile};
 
-use crate::repositories::DriverRepository;
+use crate::{
+    career,
+    repositories::{ChampionshipRepository, DriverRepository, ResultRepository},
+};
 
 pub trait DriverServiceOperations {
     fn create(
@@ -13,6 +18,28 @@ pub trait DriverServiceOperations {
         user_id: Option<i32>,
     ) -> impl Future<Output = AppResult<()>> + Send;
 
+    fn profile(&self, steam_name: &str) -> impl Future<Output = AppResult<DriverProfile>> + Send;
+
+    fn claim(
+        &self,
+        steam_name: &str,
+        user_id: i32,
+        championship_id: i32,
+    ) -> impl Future<Output = AppResult<i16>> + Send;
+
+    fn review_claim(
+        &self,
+        championship_id: i32,
+        user_id: i32,
+        form: &DriverClaimReviewForm,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    fn verify_claim(
+        &self,
+        steam_name: &str,
+        user_id: i32,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
     // TODO: Implement driver update
     // async fn update(&self, form: DriverUpdateData) -> AppResult<()>;
 }
@@ -25,11 +52,23 @@ pub trait DriverAdminServiceOperations: DriverServiceOperations {
 pub struct DriverService {
     db: &'static Database,
     driver_repo: &'static DriverRepository,
+    championship_repo: &'static ChampionshipRepository,
+    result_repo: &'static ResultRepository,
 }
 
 impl DriverService {
-    pub async fn new(db: &'static Database, driver_repo: &'static DriverRepository) -> Self {
-        DriverService { db, driver_repo }
+    pub async fn new(
+        db: &'static Database,
+        driver_repo: &'static DriverRepository,
+        championship_repo: &'static ChampionshipRepository,
+        result_repo: &'static ResultRepository,
+    ) -> Self {
+        DriverService {
+            db,
+            driver_repo,
+            championship_repo,
+            result_repo,
+        }
     }
 
     async fn _create(
@@ -55,6 +94,122 @@ impl DriverService {
         Ok(())
     }
 
+    async fn _claim(&self, steam_name: &str, user_id: i32, championship_id: i32) -> AppResult<i16> {
+        let conn = self.db.pg.get().await?;
+
+        // The code is a race number the driver doesn't use yet, so racing with it proves
+        // the claimant controls the game account
+        let claim_driver_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO driver_claims (steam_name, user_id, championship_id, verification_code)
+                    SELECT $1, $2, $3, code::SMALLINT FROM generate_series(2, 99) AS code
+                    WHERE code NOT IN (
+                        SELECT number FROM championship_drivers
+                        WHERE steam_name = $1
+                    ) AND code NOT IN (
+                        SELECT verification_code FROM driver_claims
+                        WHERE steam_name = $1 AND user_id <> $2 AND status = 'Pending'
+                    )
+                    ORDER BY random()
+                    LIMIT 1
+                    ON CONFLICT (steam_name, user_id) DO UPDATE
+                    SET championship_id = EXCLUDED.championship_id,
+                        verification_code = EXCLUDED.verification_code,
+                        status = 'Pending',
+                        updated_at = CURRENT_TIMESTAMP
+                    RETURNING verification_code
+                "#,
+            )
+            .await?;
+
+        let Some(row) = conn
+            .query_opt(
+                &claim_driver_stmt,
+                &[&steam_name, &user_id, &championship_id],
+            )
+            .await?
+        else {
+            Err(DriverError::NoVerificationCode)?
+        };
+
+        Ok(row.get(0))
+    }
+
+    async fn _resolve_claim(
+        &self,
+        steam_name: &str,
+        user_id: i32,
+        approved: bool,
+    ) -> AppResult<()> {
+        let mut conn = self.db.pg.get().await?;
+
+        if !approved {
+            let reject_claim_stmt = conn
+                .prepare_cached(
+                    r#"
+                        UPDATE driver_claims
+                        SET status = 'Rejected', updated_at = CURRENT_TIMESTAMP
+                        WHERE steam_name = $1 AND user_id = $2
+                    "#,
+                )
+                .await?;
+
+            conn.execute(&reject_claim_stmt, &[&steam_name, &user_id])
+                .await?;
+
+            return Ok(());
+        }
+
+        let tx = conn.transaction().await?;
+
+        // Only link a driver nobody owns yet, to a user without a driver, while the claim is
+        // still pending and the driver still races in the claimed championship. A concurrent
+        // approval blocks on the row lock and then finds the driver already linked
+        let link_driver_stmt = tx.prepare_cached(
+            r#"
+                UPDATE drivers
+                SET user_id = $2, updated_at = CURRENT_TIMESTAMP
+                WHERE steam_name = $1 AND user_id IS NULL
+                AND NOT EXISTS (SELECT 1 FROM drivers WHERE user_id = $2)
+                AND EXISTS (
+                    SELECT 1 FROM driver_claims c
+                    JOIN championship_drivers cd
+                        ON cd.championship_id = c.championship_id AND cd.steam_name = c.steam_name
+                    WHERE c.steam_name = $1 AND c.user_id = $2 AND c.status = 'Pending'
+                )
+            "#,
+        );
+
+        // Other pending claims over the same driver or by the same user can't succeed anymore
+        let resolve_claims_stmt = tx.prepare_cached(
+            r#"
+                UPDATE driver_claims
+                SET status = CASE
+                        WHEN steam_name = $1 AND user_id = $2 THEN 'Approved'::driver_claim_status
+                        ELSE 'Rejected'::driver_claim_status
+                    END,
+                    updated_at = CURRENT_TIMESTAMP
+                WHERE status = 'Pending' AND (steam_name = $1 OR user_id = $2)
+            "#,
+        );
+
+        let (link_driver, resolve_claims) =
+            tokio::try_join!(link_driver_stmt, resolve_claims_stmt)?;
+
+        if tx.execute(&link_driver, &[&steam_name, &user_id]).await? == 0 {
+            Err(DriverError::AlreadyLinked)?
+        }
+
+        tx.execute(&resolve_claims, &[&steam_name, &user_id])
+            .await?;
+
+        tx.commit().await?;
+        self.db.cache.driver.delete(steam_name);
+
+        Ok(())
+    }
+
     async fn _delete(&self, steam_name: &str) -> AppResult<()> {
         let conn = self.db.pg.get().await?;
 
@@ -98,6 +253,102 @@ impl DriverServiceOperations for DriverService {
 
         self._create(steam_name, nationality, user_id).await
     }
+
+    async fn profile(&self, steam_name: &str) -> AppResult<DriverProfile> {
+        let (driver, entries, best_laps) = tokio::try_join!(
+            self.driver_repo.find(steam_name),
+            self.result_repo.career_entries(steam_name),
+            self.result_repo.best_laps(steam_name)
+        )?;
+
+        let Some(driver) = driver else {
+            Err(DriverError::NotFound)?
+        };
+
+        let (stats, teammates) = career::career_stats(steam_name, &entries);
+
+        Ok(DriverProfile {
+            steam_name: driver.steam_name.clone(),
+            nationality: driver.nationality,
+            user_id: driver.user_id,
+            stats,
+            teammates,
+            best_laps,
+        })
+    }
+
+    async fn claim(&self, steam_name: &str, user_id: i32, championship_id: i32) -> AppResult<i16> {
+        let (driver, user_driver, championship, drivers) = tokio::try_join!(
+            self.driver_repo.find(steam_name),
+            self.driver_repo.find_by_user(user_id),
+            self.championship_repo.find(championship_id),
+            self.championship_repo.drivers(championship_id)
+        )?;
+
+        let Some(driver) = driver else {
+            Err(DriverError::NotFound)?
+        };
+
+        if driver.user_id.is_some() {
+            Err(DriverError::AlreadyLinked)?
+        }
+
+        if user_driver.is_some() {
+            Err(DriverError::UserAlreadyLinked)?
+        }
+
+        if championship.is_none() {
+            Err(ChampionshipError::NotFound)?
+        }
+
+        if !drivers
+            .iter()
+            .any(|driver| driver.steam_name.as_ref() == steam_name)
+        {
+            Err(DriverError::NotInChampionship)?
+        }
+
+        self._claim(steam_name, user_id, championship_id).await
+    }
+
+    async fn review_claim(
+        &self,
+        championship_id: i32,
+        user_id: i32,
+        form: &DriverClaimReviewForm,
+    ) -> AppResult<()> {
+        let (relation, claim, drivers) = tokio::try_join!(
+            self.championship_repo
+                .user_relation(championship_id, user_id),
+            self.driver_repo.claim(&form.steam_name, form.user_id),
+            self.championship_repo.drivers(championship_id)
+        )?;
+
+        if !relation.is_some_and(|relation| relation.role == ChampionshipRole::Admin) {
+            Err(ChampionshipError::NotAdmin)?
+        }
+
+        if !claim.is_some_and(|claim| {
+            claim.championship_id == championship_id && claim.status == DriverClaimStatus::Pending
+        }) {
+            Err(DriverError::ClaimNotFound)?
+        }
+
+        if form.approved
+            && !drivers
+                .iter()
+                .any(|driver| driver.steam_name.as_ref() == form.steam_name)
+        {
+            Err(DriverError::NotInChampionship)?
+        }
+
+        self._resolve_claim(&form.steam_name, form.user_id, form.approved)
+            .await
+    }
+
+    async fn verify_claim(&self, steam_name: &str, user_id: i32) -> AppResult<()> {
+        self._resolve_claim(steam_name, user_id, true).await
+    }
 }
 
 impl DriverAdminServiceOperations for DriverService {
@@ -1,9 +1,13 @@
 mod championship;
 mod driver;
 mod email;
+mod race;
+mod steward;
 mod user;
 
 pub use championship::*;
 pub use driver::*;
 pub use email::*;
+pub use race::*;
+pub use steward::*;
 pub use user::*;
@@ -0,0 +1,568 @@
+use std::future::Future;
+
+use db::Database;
+use entities::ChampionshipRole;
+use error::{AppResult, ChampionshipError, RaceError};
+use structs::{
+    EngineWearData, LapComparison, LapComparisonQuery, LapTraceData, RaceDivisionForm,
+    SubstitutionForm, TrackOutlineData, WeatherSampleData,
+};
+
+use crate::{
+    entry_list, lap_comparison,
+    repositories::{ChampionshipRepository, RaceRepository},
+};
+
+/// Defines the core operations for managing race data.
+pub trait RaceServiceOperations {
+    /// Records the current weather conditions of a race session.
+    ///
+    /// # Arguments
+    ///
+    /// * `race_id` - The ID of the race.
+    /// * `sample` - The weather conditions to record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the race is not found or if there's a database error.
+    fn add_weather_sample(
+        &self,
+        race_id: i32,
+        sample: &WeatherSampleData,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Stores the outline of a track, replacing the one recorded before.
+    ///
+    /// # Arguments
+    ///
+    /// * `outline` - The normalized outline of the track.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there's a database error.
+    fn save_track_outline(
+        &self,
+        outline: &TrackOutlineData,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Stores the telemetry trace of a lap driven in a race session.
+    ///
+    /// # Arguments
+    ///
+    /// * `race_id` - The ID of the race.
+    /// * `trace` - The trace of the lap.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the race is not found or if there's a database error.
+    fn add_lap_trace(
+        &self,
+        race_id: i32,
+        trace: &LapTraceData,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Stores the wear of the power unit of a driver at the end of a race session.
+    ///
+    /// # Arguments
+    ///
+    /// * `race_id` - The ID of the race.
+    /// * `wear` - The wear of every component of the power unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the race is not found or if there's a database error.
+    fn add_engine_wear(
+        &self,
+        race_id: i32,
+        wear: &EngineWearData,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Compares the telemetry of a lap with a reference lap of the same session.
+    ///
+    /// # Arguments
+    ///
+    /// * `race_id` - The ID of the race.
+    /// * `session_type` - The session the laps were driven in.
+    /// * `query` - The lap to compare and the reference lap. Without a reference lap number
+    ///   the fastest valid lap of the session, or of the reference driver, is used.
+    ///
+    /// # Returns
+    ///
+    /// Returns the channels of both laps aligned by lap distance and the time delta.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the race or any of the laps is not found or if there's a
+    /// database error.
+    fn compare_laps(
+        &self,
+        race_id: i32,
+        session_type: i16,
+        query: &LapComparisonQuery,
+    ) -> impl Future<Output = AppResult<LapComparison>> + Send;
+
+    /// Enters a reserve in a race in place of a regular driver, keeping the roster of the
+    /// championship untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `race_id` - The ID of the race.
+    /// * `user_id` - The ID of the user marking the substitution.
+    /// * `form` - The reserve and the regular driver they replace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the race is not found, the user is not an admin of the
+    /// championship, the replaced driver is not a regular or the reserve is already entered.
+    fn add_substitution(
+        &self,
+        race_id: i32,
+        user_id: i32,
+        form: &SubstitutionForm,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Removes a substitution, giving the car back to the regular driver.
+    ///
+    /// # Arguments
+    ///
+    /// * `race_id` - The ID of the race.
+    /// * `user_id` - The ID of the user removing the substitution.
+    /// * `steam_name` - The reserve of the substitution.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the race or the substitution is not found or the user is not an
+    /// admin of the championship.
+    fn remove_substitution(
+        &self,
+        race_id: i32,
+        user_id: i32,
+        steam_name: &str,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Moves a race to a division of its championship.
+    ///
+    /// # Arguments
+    ///
+    /// * `race_id` - The ID of the race.
+    /// * `user_id` - The ID of the user moving the race.
+    /// * `form` - The division of the race, none to share it with the whole championship.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the race or the division is not found or the user is not an
+    /// admin of the championship.
+    fn update_division(
+        &self,
+        race_id: i32,
+        user_id: i32,
+        form: &RaceDivisionForm,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+}
+
+/// Implements the race service logic.
+pub struct RaceService {
+    db: &'static Database,
+    race_repo: &'static RaceRepository,
+    championship_repo: &'static ChampionshipRepository,
+}
+
+impl RaceService {
+    /// Creates a new RaceService instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The database connection.
+    /// * `race_repo` - The race repository.
+    /// * `championship_repo` - The championship repository.
+    pub fn new(
+        db: &'static Database,
+        race_repo: &'static RaceRepository,
+        championship_repo: &'static ChampionshipRepository,
+    ) -> Self {
+        Self {
+            db,
+            race_repo,
+            championship_repo,
+        }
+    }
+
+    /// Ensures that the race exists and the user is an admin of its championship,
+    /// returning the ID of the championship.
+    #[inline]
+    async fn ensure_admin(&self, race_id: i32, user_id: i32) -> AppResult<i32> {
+        let Some(race) = self.race_repo.find(race_id).await? else {
+            Err(RaceError::NotFound)?
+        };
+
+        let relation = self
+            .championship_repo
+            .user_relation(race.championship_id, user_id)
+            .await?;
+
+        if !relation.is_some_and(|relation| relation.role == ChampionshipRole::Admin) {
+            Err(ChampionshipError::NotAdmin)?
+        }
+
+        Ok(race.championship_id)
+    }
+
+    /// Internal method to enter a reserve in place of a regular driver.
+    #[inline]
+    async fn _add_substitution(&self, race_id: i32, form: &SubstitutionForm) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let add_substitution_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO race_substitutions (race_id, steam_name, replaces)
+                    VALUES ($1, $2, $3)
+                "#,
+            )
+            .await?;
+
+        conn.execute(
+            &add_substitution_stmt,
+            &[&race_id, &form.steam_name, &form.replaces],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Internal method to remove a substitution.
+    #[inline]
+    async fn _remove_substitution(&self, race_id: i32, steam_name: &str) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let remove_substitution_stmt = conn
+            .prepare_cached(
+                r#"
+                    DELETE FROM race_substitutions
+                    WHERE race_id = $1 AND steam_name = $2
+                "#,
+            )
+            .await?;
+
+        conn.execute(&remove_substitution_stmt, &[&race_id, &steam_name])
+            .await?;
+
+        Ok(())
+    }
+
+    /// Internal method to move a race to a division.
+    #[inline]
+    async fn _update_division(&self, race_id: i32, division_id: Option<i16>) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let update_division_stmt = conn
+            .prepare_cached(
+                r#"
+                    UPDATE races
+                    SET division_id = $2, updated_at = CURRENT_TIMESTAMP
+                    WHERE id = $1
+                "#,
+            )
+            .await?;
+
+        conn.execute(&update_division_stmt, &[&race_id, &division_id])
+            .await?;
+
+        Ok(())
+    }
+
+    /// Internal method to record a weather sample.
+    #[inline]
+    async fn _add_weather_sample(&self, race_id: i32, sample: &WeatherSampleData) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let add_weather_sample_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO weather_samples
+                        (race_id, session_type, weather, track_temperature, air_temperature,
+                        rain_percentage)
+                    VALUES ($1, $2, $3, $4, $5, $6)
+                "#,
+            )
+            .await?;
+
+        conn.execute(
+            &add_weather_sample_stmt,
+            &[
+                &race_id,
+                &sample.session_type,
+                &sample.weather,
+                &sample.track_temperature,
+                &sample.air_temperature,
+                &sample.rain_percentage,
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Internal method to upsert a track outline.
+    #[inline]
+    async fn _save_track_outline(&self, outline: &TrackOutlineData) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let save_track_outline_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO track_outlines
+                        (track_id, track_length, origin_x, origin_z, scale, points, pit_lane,
+                        sector2_index, sector3_index)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                    ON CONFLICT (track_id) DO UPDATE SET
+                        track_length = EXCLUDED.track_length,
+                        origin_x = EXCLUDED.origin_x,
+                        origin_z = EXCLUDED.origin_z,
+                        scale = EXCLUDED.scale,
+                        points = EXCLUDED.points,
+                        pit_lane = EXCLUDED.pit_lane,
+                        sector2_index = EXCLUDED.sector2_index,
+                        sector3_index = EXCLUDED.sector3_index,
+                        updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .await?;
+
+        conn.execute(
+            &save_track_outline_stmt,
+            &[
+                &outline.track_id,
+                &outline.track_length,
+                &outline.origin_x,
+                &outline.origin_z,
+                &outline.scale,
+                &outline.points,
+                &outline.pit_lane,
+                &outline.sector2_index,
+                &outline.sector3_index,
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Internal method to store a lap trace, replacing the lap if it was driven again.
+    #[inline]
+    async fn _add_lap_trace(&self, race_id: i32, trace: &LapTraceData) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let add_lap_trace_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO lap_traces
+                        (race_id, session_type, steam_name, lap_number, lap_time, valid, step,
+                        time, speed, throttle, brake, gear, steer)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+                    ON CONFLICT (race_id, session_type, steam_name, lap_number) DO UPDATE SET
+                        lap_time = EXCLUDED.lap_time,
+                        valid = EXCLUDED.valid,
+                        step = EXCLUDED.step,
+                        time = EXCLUDED.time,
+                        speed = EXCLUDED.speed,
+                        throttle = EXCLUDED.throttle,
+                        brake = EXCLUDED.brake,
+                        gear = EXCLUDED.gear,
+                        steer = EXCLUDED.steer,
+                        created_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .await?;
+
+        conn.execute(
+            &add_lap_trace_stmt,
+            &[
+                &race_id,
+                &trace.session_type,
+                &trace.steam_name.as_ref(),
+                &trace.lap_number,
+                &trace.lap_time,
+                &trace.valid,
+                &trace.step,
+                &trace.time,
+                &trace.speed,
+                &trace.throttle,
+                &trace.brake,
+                &trace.gear,
+                &trace.steer,
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Internal method to store the engine wear of a driver, replacing it if the session
+    /// ended again.
+    #[inline]
+    async fn _add_engine_wear(&self, race_id: i32, wear: &EngineWearData) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let add_engine_wear_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO engine_wear
+                        (race_id, session_type, steam_name, ice, mguh, mguk, es, ce, tc)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                    ON CONFLICT (race_id, session_type, steam_name) DO UPDATE SET
+                        ice = EXCLUDED.ice,
+                        mguh = EXCLUDED.mguh,
+                        mguk = EXCLUDED.mguk,
+                        es = EXCLUDED.es,
+                        ce = EXCLUDED.ce,
+                        tc = EXCLUDED.tc,
+                        created_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .await?;
+
+        conn.execute(
+            &add_engine_wear_stmt,
+            &[
+                &race_id,
+                &wear.session_type,
+                &wear.steam_name.as_ref(),
+                &wear.ice,
+                &wear.mguh,
+                &wear.mguk,
+                &wear.es,
+                &wear.ce,
+                &wear.tc,
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl RaceServiceOperations for RaceService {
+    async fn add_weather_sample(&self, race_id: i32, sample: &WeatherSampleData) -> AppResult<()> {
+        if self.race_repo.find(race_id).await?.is_none() {
+            Err(RaceError::NotFound)?
+        }
+
+        self._add_weather_sample(race_id, sample).await
+    }
+
+    async fn save_track_outline(&self, outline: &TrackOutlineData) -> AppResult<()> {
+        self._save_track_outline(outline).await
+    }
+
+    async fn add_lap_trace(&self, race_id: i32, trace: &LapTraceData) -> AppResult<()> {
+        if self.race_repo.find(race_id).await?.is_none() {
+            Err(RaceError::NotFound)?
+        }
+
+        self._add_lap_trace(race_id, trace).await
+    }
+
+    async fn add_engine_wear(&self, race_id: i32, wear: &EngineWearData) -> AppResult<()> {
+        if self.race_repo.find(race_id).await?.is_none() {
+            Err(RaceError::NotFound)?
+        }
+
+        self._add_engine_wear(race_id, wear).await
+    }
+
+    async fn compare_laps(
+        &self,
+        race_id: i32,
+        session_type: i16,
+        query: &LapComparisonQuery,
+    ) -> AppResult<LapComparison> {
+        if self.race_repo.find(race_id).await?.is_none() {
+            Err(RaceError::NotFound)?
+        }
+
+        let reference_steam_name = query.reference_steam_name.as_deref();
+
+        let (lap, reference) = tokio::try_join!(
+            self.race_repo
+                .lap_trace(race_id, session_type, &query.steam_name, query.lap),
+            async {
+                match query.reference_lap {
+                    Some(lap) => {
+                        let steam_name = reference_steam_name.unwrap_or(&query.steam_name);
+
+                        self.race_repo
+                            .lap_trace(race_id, session_type, steam_name, lap)
+                            .await
+                    }
+                    None => {
+                        self.race_repo
+                            .fastest_lap_trace(race_id, session_type, reference_steam_name)
+                            .await
+                    }
+                }
+            }
+        )?;
+
+        let (Some(lap), Some(reference)) = (lap, reference) else {
+            Err(RaceError::LapTraceNotFound)?
+        };
+
+        Ok(lap_comparison::compare(&lap, &reference))
+    }
+
+    async fn add_substitution(
+        &self,
+        race_id: i32,
+        user_id: i32,
+        form: &SubstitutionForm,
+    ) -> AppResult<()> {
+        self.ensure_admin(race_id, user_id).await?;
+
+        let entries = self.race_repo.entries(race_id).await?;
+        entry_list::check_substitution(&entries, &form.steam_name, &form.replaces)?;
+
+        self._add_substitution(race_id, form).await
+    }
+
+    async fn remove_substitution(
+        &self,
+        race_id: i32,
+        user_id: i32,
+        steam_name: &str,
+    ) -> AppResult<()> {
+        self.ensure_admin(race_id, user_id).await?;
+
+        let entries = self.race_repo.entries(race_id).await?;
+
+        if !entries
+            .iter()
+            .any(|entry| entry.replaces.is_some() && entry.steam_name.as_ref() == steam_name)
+        {
+            Err(RaceError::SubstitutionNotFound)?
+        }
+
+        self._remove_substitution(race_id, steam_name).await
+    }
+
+    async fn update_division(
+        &self,
+        race_id: i32,
+        user_id: i32,
+        form: &RaceDivisionForm,
+    ) -> AppResult<()> {
+        let championship_id = self.ensure_admin(race_id, user_id).await?;
+
+        if let Some(division_id) = form.division_id {
+            let divisions = self.championship_repo.divisions(championship_id).await?;
+
+            if !divisions.iter().any(|division| division.id == division_id) {
+                Err(ChampionshipError::DivisionNotFound)?
+            }
+        }
+
+        self._update_division(race_id, form.division_id).await?;
+        self.db.cache.championship.delete_races(&championship_id);
+        Ok(())
+    }
+}
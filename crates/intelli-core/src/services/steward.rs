@@ -0,0 +1,721 @@
+use std::future::Future;
+
+use db::Database;
+use entities::{ChampionshipRole, ResultEntry, ResultPenaltyKind};
+use error::{AppResult, ChampionshipError, RaceError, StewardError};
+use id_generator::IdsGenerator;
+use structs::{
+    AssistEvidenceData, IncidentData, IncidentRulingForm, ParcFermeBreach, ResultPenaltyForm,
+    RuleDeviationData, SetupSnapshotData, YellowFlagData,
+};
+
+use crate::{
+    parc_ferme,
+    repositories::{ChampionshipRepository, RaceRepository, StewardRepository},
+    standings,
+};
+
+/// Defines the core operations for recording stewarding data.
+pub trait StewardServiceOperations {
+    /// Records a yellow flag period of a marshal zone.
+    ///
+    /// # Arguments
+    ///
+    /// * `race_id` - The ID of the race.
+    /// * `yellow_flag` - The zone and session times of the yellow flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the race doesn't exist or if there's a database error.
+    fn add_yellow_flag(
+        &self,
+        race_id: i32,
+        yellow_flag: &YellowFlagData,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Records a session setting that doesn't match the championship rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `race_id` - The ID of the race.
+    /// * `deviation` - The rule and the values expected and found in the session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the race doesn't exist or if there's a database error.
+    fn add_rule_deviation(
+        &self,
+        race_id: i32,
+        deviation: &RuleDeviationData,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Records the evidence of a driver using an assist during a session,
+    /// replacing the previous evidence of the same assist.
+    ///
+    /// # Arguments
+    ///
+    /// * `race_id` - The ID of the race.
+    /// * `evidence` - The driver, the assist and the samples supporting it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the race doesn't exist or if there's a database error.
+    fn add_assist_evidence(
+        &self,
+        race_id: i32,
+        evidence: &AssistEvidenceData,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Stores the setup a driver used in a session, replacing the previous snapshot
+    /// of the same session.
+    ///
+    /// # Arguments
+    ///
+    /// * `race_id` - The ID of the race.
+    /// * `snapshot` - The driver and the setup of the car.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the race doesn't exist or if there's a database error.
+    fn add_setup_snapshot(
+        &self,
+        race_id: i32,
+        snapshot: &SetupSnapshotData,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Compares the race setups with the qualifying ones, listing the drivers that
+    /// changed a setting locked by parc fermé.
+    ///
+    /// # Arguments
+    ///
+    /// * `race_id` - The ID of the race.
+    ///
+    /// # Returns
+    ///
+    /// Returns the drivers that broke parc fermé and the settings they changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the race is not found or if there's a database error.
+    fn parc_ferme_breaches(
+        &self,
+        race_id: i32,
+    ) -> impl Future<Output = AppResult<Vec<ParcFermeBreach>>> + Send;
+
+    /// Records an incident of a race session in the stewarding log.
+    ///
+    /// # Arguments
+    ///
+    /// * `race_id` - The ID of the race.
+    /// * `incident` - The details of the incident.
+    ///
+    /// # Returns
+    ///
+    /// Returns the ID of the newly recorded incident as an `i32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the race doesn't exist or if there's a database error.
+    fn add_incident(
+        &self,
+        race_id: i32,
+        incident: &IncidentData,
+    ) -> impl Future<Output = AppResult<i32>> + Send;
+
+    /// Sets the stewards ruling on an incident, replacing any previous ruling.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the incident.
+    /// * `user_id` - The ID of the steward ruling on the incident.
+    /// * `form` - The decision taken by the steward.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the incident is not found, the user is not an admin
+    /// of the championship, or if there's a database error.
+    fn rule_incident(
+        &self,
+        id: i32,
+        user_id: i32,
+        form: &IncidentRulingForm,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+
+    /// Applies a post-race penalty to a driver of a stored session result,
+    /// recomputing the positions and points of the classification.
+    ///
+    /// # Arguments
+    ///
+    /// * `race_id` - The ID of the race.
+    /// * `session_type` - The type of the session.
+    /// * `user_id` - The ID of the steward applying the penalty.
+    /// * `form` - The driver and the penalty to apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the race or the result is not found, the driver is not
+    /// classified, the user is not an admin of the championship, the incident belongs to
+    /// another race, a time penalty targets a session other than a race, or if there's a
+    /// database error.
+    fn apply_result_penalty(
+        &self,
+        race_id: i32,
+        session_type: i16,
+        user_id: i32,
+        form: &ResultPenaltyForm,
+    ) -> impl Future<Output = AppResult<()>> + Send;
+}
+
+/// Implements the stewarding service logic.
+pub struct StewardService {
+    db: &'static Database,
+    steward_repo: &'static StewardRepository,
+    championship_repo: &'static ChampionshipRepository,
+    race_repo: &'static RaceRepository,
+    incident_ids_generator: IdsGenerator,
+}
+
+impl StewardService {
+    /// Creates a new StewardService instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The database connection.
+    /// * `steward_repo` - The steward repository.
+    /// * `championship_repo` - The championship repository.
+    /// * `race_repo` - The race repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there's an issue initializing the service components.
+    pub async fn new(
+        db: &'static Database,
+        steward_repo: &'static StewardRepository,
+        championship_repo: &'static ChampionshipRepository,
+        race_repo: &'static RaceRepository,
+    ) -> AppResult<Self> {
+        let incident_ids_generator = {
+            let used_ids = steward_repo._used_incident_ids().await?;
+            IdsGenerator::new(900000000..999999999, used_ids)
+        };
+
+        Ok(Self {
+            db,
+            steward_repo,
+            championship_repo,
+            race_repo,
+            incident_ids_generator,
+        })
+    }
+
+    /// Ensures that the user is an admin of the championship.
+    #[inline]
+    async fn ensure_admin(&self, championship_id: i32, user_id: i32) -> AppResult<()> {
+        let relation = self
+            .championship_repo
+            .user_relation(championship_id, user_id)
+            .await?;
+
+        if !relation.is_some_and(|relation| relation.role == ChampionshipRole::Admin) {
+            Err(ChampionshipError::NotAdmin)?
+        }
+
+        Ok(())
+    }
+
+    /// Internal method to record a yellow flag.
+    #[inline]
+    async fn _add_yellow_flag(&self, race_id: i32, yellow_flag: &YellowFlagData) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let add_yellow_flag_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO yellow_flags
+                        (race_id, session_type, zone_index, zone_start, started_session_time,
+                        ended_session_time)
+                    VALUES ($1, $2, $3, $4, $5, $6)
+                    ON CONFLICT DO NOTHING
+                "#,
+            )
+            .await?;
+
+        conn.execute(
+            &add_yellow_flag_stmt,
+            &[
+                &race_id,
+                &yellow_flag.session_type,
+                &yellow_flag.zone_index,
+                &yellow_flag.zone_start,
+                &yellow_flag.started_session_time,
+                &yellow_flag.ended_session_time,
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Internal method to record a rule deviation.
+    #[inline]
+    async fn _add_rule_deviation(
+        &self,
+        race_id: i32,
+        deviation: &RuleDeviationData,
+    ) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let add_rule_deviation_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO rule_deviations (race_id, session_type, rule, expected, actual)
+                    VALUES ($1, $2, $3, $4, $5)
+                    ON CONFLICT (race_id, session_type, rule) DO UPDATE
+                    SET expected = EXCLUDED.expected,
+                        actual = EXCLUDED.actual
+                "#,
+            )
+            .await?;
+
+        conn.execute(
+            &add_rule_deviation_stmt,
+            &[
+                &race_id,
+                &deviation.session_type,
+                &deviation.rule,
+                &deviation.expected,
+                &deviation.actual,
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Internal method to record assist evidence.
+    #[inline]
+    async fn _add_assist_evidence(
+        &self,
+        race_id: i32,
+        evidence: &AssistEvidenceData,
+    ) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let add_assist_evidence_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO assist_evidence
+                        (race_id, session_type, steam_name, assist, inferred, samples,
+                        total_samples, level)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                    ON CONFLICT (race_id, session_type, steam_name, assist) DO UPDATE
+                    SET inferred = EXCLUDED.inferred,
+                        samples = EXCLUDED.samples,
+                        total_samples = EXCLUDED.total_samples,
+                        level = EXCLUDED.level,
+                        created_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .await?;
+
+        conn.execute(
+            &add_assist_evidence_stmt,
+            &[
+                &race_id,
+                &evidence.session_type,
+                &evidence.steam_name.as_ref(),
+                &evidence.assist,
+                &evidence.inferred,
+                &evidence.samples,
+                &evidence.total_samples,
+                &evidence.level,
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Internal method to store a setup snapshot.
+    #[inline]
+    async fn _add_setup_snapshot(
+        &self,
+        race_id: i32,
+        snapshot: &SetupSnapshotData,
+    ) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let add_setup_snapshot_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO setup_snapshots
+                        (race_id, session_type, steam_name, front_wing, rear_wing, on_throttle,
+                        off_throttle, front_camber, rear_camber, front_toe, rear_toe,
+                        front_suspension, rear_suspension, front_anti_roll_bar,
+                        rear_anti_roll_bar, front_suspension_height, rear_suspension_height,
+                        brake_pressure, brake_bias, engine_braking, rear_left_tyre_pressure,
+                        rear_right_tyre_pressure, front_left_tyre_pressure,
+                        front_right_tyre_pressure, ballast, fuel_load)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15,
+                        $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26)
+                    ON CONFLICT (race_id, session_type, steam_name) DO UPDATE
+                    SET front_wing = EXCLUDED.front_wing,
+                        rear_wing = EXCLUDED.rear_wing,
+                        on_throttle = EXCLUDED.on_throttle,
+                        off_throttle = EXCLUDED.off_throttle,
+                        front_camber = EXCLUDED.front_camber,
+                        rear_camber = EXCLUDED.rear_camber,
+                        front_toe = EXCLUDED.front_toe,
+                        rear_toe = EXCLUDED.rear_toe,
+                        front_suspension = EXCLUDED.front_suspension,
+                        rear_suspension = EXCLUDED.rear_suspension,
+                        front_anti_roll_bar = EXCLUDED.front_anti_roll_bar,
+                        rear_anti_roll_bar = EXCLUDED.rear_anti_roll_bar,
+                        front_suspension_height = EXCLUDED.front_suspension_height,
+                        rear_suspension_height = EXCLUDED.rear_suspension_height,
+                        brake_pressure = EXCLUDED.brake_pressure,
+                        brake_bias = EXCLUDED.brake_bias,
+                        engine_braking = EXCLUDED.engine_braking,
+                        rear_left_tyre_pressure = EXCLUDED.rear_left_tyre_pressure,
+                        rear_right_tyre_pressure = EXCLUDED.rear_right_tyre_pressure,
+                        front_left_tyre_pressure = EXCLUDED.front_left_tyre_pressure,
+                        front_right_tyre_pressure = EXCLUDED.front_right_tyre_pressure,
+                        ballast = EXCLUDED.ballast,
+                        fuel_load = EXCLUDED.fuel_load,
+                        created_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .await?;
+
+        let setup = &snapshot.setup;
+
+        conn.execute(
+            &add_setup_snapshot_stmt,
+            &[
+                &race_id,
+                &snapshot.session_type,
+                &snapshot.steam_name.as_ref(),
+                &setup.front_wing,
+                &setup.rear_wing,
+                &setup.on_throttle,
+                &setup.off_throttle,
+                &setup.front_camber,
+                &setup.rear_camber,
+                &setup.front_toe,
+                &setup.rear_toe,
+                &setup.front_suspension,
+                &setup.rear_suspension,
+                &setup.front_anti_roll_bar,
+                &setup.rear_anti_roll_bar,
+                &setup.front_suspension_height,
+                &setup.rear_suspension_height,
+                &setup.brake_pressure,
+                &setup.brake_bias,
+                &setup.engine_braking,
+                &setup.rear_left_tyre_pressure,
+                &setup.rear_right_tyre_pressure,
+                &setup.front_left_tyre_pressure,
+                &setup.front_right_tyre_pressure,
+                &setup.ballast,
+                &setup.fuel_load,
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Internal method to record an incident.
+    #[inline]
+    async fn _add_incident(&self, race_id: i32, incident: &IncidentData) -> AppResult<i32> {
+        let conn = self.db.pg.get().await?;
+
+        let add_incident_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO incidents
+                        (id, race_id, session_type, kind, session_time, lap, steam_name,
+                        other_steam_name, penalty_type, infringement_type, penalty_time,
+                        safety_car_type, safety_car_event)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+                "#,
+            )
+            .await?;
+
+        let id = self.incident_ids_generator.next();
+
+        conn.execute(
+            &add_incident_stmt,
+            &[
+                &id,
+                &race_id,
+                &incident.session_type,
+                &incident.kind,
+                &incident.session_time,
+                &incident.lap,
+                &incident.steam_name.as_deref(),
+                &incident.other_steam_name.as_deref(),
+                &incident.penalty_type,
+                &incident.infringement_type,
+                &incident.penalty_time,
+                &incident.safety_car_type,
+                &incident.safety_car_event,
+            ],
+        )
+        .await?;
+
+        Ok(id)
+    }
+
+    /// Internal method to set the ruling of an incident.
+    #[inline]
+    async fn _rule_incident(
+        &self,
+        id: i32,
+        user_id: i32,
+        form: &IncidentRulingForm,
+    ) -> AppResult<()> {
+        let conn = self.db.pg.get().await?;
+
+        let rule_incident_stmt = conn
+            .prepare_cached(
+                r#"
+                    INSERT INTO incident_rulings
+                        (incident_id, steward_id, decision, penalty_time, notes)
+                    VALUES ($1, $2, $3, $4, $5)
+                    ON CONFLICT (incident_id) DO UPDATE
+                    SET steward_id = EXCLUDED.steward_id,
+                        decision = EXCLUDED.decision,
+                        penalty_time = EXCLUDED.penalty_time,
+                        notes = EXCLUDED.notes,
+                        updated_at = CURRENT_TIMESTAMP
+                "#,
+            )
+            .await?;
+
+        conn.execute(
+            &rule_incident_stmt,
+            &[
+                &id,
+                &user_id,
+                &form.decision,
+                &form.penalty_time,
+                &form.notes,
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Internal method to apply a penalty to a session result, reclassifying it and
+    /// recording the penalty in a single transaction. The entries are locked while
+    /// reclassifying so concurrent penalties are applied one after the other.
+    #[inline]
+    async fn _apply_result_penalty(
+        &self,
+        race_id: i32,
+        session_type: i16,
+        user_id: i32,
+        form: &ResultPenaltyForm,
+    ) -> AppResult<()> {
+        let mut conn = self.db.pg.get().await?;
+        let tx = conn.transaction().await?;
+
+        let (entries_stmt, update_entry_stmt, add_penalty_stmt) = tokio::try_join!(
+            tx.prepare_cached(
+                r#"
+                    SELECT steam_name, team_id, grid_position, laps, race_time,
+                        penalties_time, best_lap_time, result_status, original_position,
+                        original_points, position, points, steward_time, disqualified
+                    FROM result_entries
+                    WHERE race_id = $1 AND session_type = $2
+                    ORDER BY position
+                    FOR UPDATE
+                "#,
+            ),
+            tx.prepare_cached(
+                r#"
+                    UPDATE result_entries
+                    SET position = $4, points = $5, steward_time = $6, disqualified = $7,
+                        updated_at = CURRENT_TIMESTAMP
+                    WHERE race_id = $1 AND session_type = $2 AND steam_name = $3
+                "#,
+            ),
+            tx.prepare_cached(
+                r#"
+                    INSERT INTO result_penalties
+                        (race_id, session_type, steam_name, kind, seconds, reason, incident_id,
+                        steward_id, previous_position, new_position)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                "#,
+            )
+        )?;
+
+        let mut entries = tx
+            .query(&entries_stmt, &[&race_id, &session_type])
+            .await?
+            .iter()
+            .map(ResultEntry::from_row)
+            .collect::<Vec<_>>();
+
+        if entries.is_empty() {
+            Err(StewardError::ResultNotFound)?
+        }
+
+        let Some(entry) = entries
+            .iter_mut()
+            .find(|entry| *entry.steam_name == form.steam_name)
+        else {
+            Err(StewardError::NotClassified)?
+        };
+
+        let previous_position = entry.position;
+
+        match form.kind {
+            ResultPenaltyKind::TimePenalty => {
+                let Some(seconds) = form.seconds else {
+                    Err(StewardError::MissingPenaltyTime)?
+                };
+
+                entry.steward_time = entry.steward_time.saturating_add(seconds);
+            }
+
+            ResultPenaltyKind::Disqualification => entry.disqualified = true,
+        }
+
+        standings::reclassify(&mut entries, session_type);
+
+        let mut new_position = previous_position;
+
+        for entry in &entries {
+            if *entry.steam_name == form.steam_name {
+                new_position = entry.position;
+            }
+
+            tx.execute(
+                &update_entry_stmt,
+                &[
+                    &race_id,
+                    &session_type,
+                    &entry.steam_name.as_ref(),
+                    &entry.position,
+                    &entry.points,
+                    &entry.steward_time,
+                    &entry.disqualified,
+                ],
+            )
+            .await?;
+        }
+
+        tx.execute(
+            &add_penalty_stmt,
+            &[
+                &race_id,
+                &session_type,
+                &form.steam_name,
+                &form.kind,
+                &form.seconds,
+                &form.reason,
+                &form.incident_id,
+                &user_id,
+                &previous_position,
+                &new_position,
+            ],
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+}
+
+impl StewardServiceOperations for StewardService {
+    async fn add_yellow_flag(&self, race_id: i32, yellow_flag: &YellowFlagData) -> AppResult<()> {
+        self._add_yellow_flag(race_id, yellow_flag).await
+    }
+
+    async fn add_rule_deviation(
+        &self,
+        race_id: i32,
+        deviation: &RuleDeviationData,
+    ) -> AppResult<()> {
+        self._add_rule_deviation(race_id, deviation).await
+    }
+
+    async fn add_assist_evidence(
+        &self,
+        race_id: i32,
+        evidence: &AssistEvidenceData,
+    ) -> AppResult<()> {
+        self._add_assist_evidence(race_id, evidence).await
+    }
+
+    async fn add_setup_snapshot(
+        &self,
+        race_id: i32,
+        snapshot: &SetupSnapshotData,
+    ) -> AppResult<()> {
+        self._add_setup_snapshot(race_id, snapshot).await
+    }
+
+    async fn parc_ferme_breaches(&self, race_id: i32) -> AppResult<Vec<ParcFermeBreach>> {
+        if self.race_repo.find(race_id).await?.is_none() {
+            Err(RaceError::NotFound)?
+        }
+
+        let snapshots = self.steward_repo.setup_snapshots(race_id).await?;
+        Ok(parc_ferme::breaches(&snapshots))
+    }
+
+    async fn add_incident(&self, race_id: i32, incident: &IncidentData) -> AppResult<i32> {
+        self._add_incident(race_id, incident).await
+    }
+
+    async fn rule_incident(
+        &self,
+        id: i32,
+        user_id: i32,
+        form: &IncidentRulingForm,
+    ) -> AppResult<()> {
+        {
+            let Some(championship_id) = self.steward_repo.incident_championship(id).await? else {
+                Err(StewardError::IncidentNotFound)?
+            };
+
+            self.ensure_admin(championship_id, user_id).await?;
+        }
+
+        self._rule_incident(id, user_id, form).await
+    }
+
+    async fn apply_result_penalty(
+        &self,
+        race_id: i32,
+        session_type: i16,
+        user_id: i32,
+        form: &ResultPenaltyForm,
+    ) -> AppResult<()> {
+        let Some(race) = self.race_repo.find(race_id).await? else {
+            Err(RaceError::NotFound)?
+        };
+
+        self.ensure_admin(race.championship_id, user_id).await?;
+
+        // Qualifying order only depends on lap times, extra seconds wouldn't change it
+        if form.kind == ResultPenaltyKind::TimePenalty
+            && !standings::RACE_SESSION_TYPES.contains(&session_type)
+        {
+            Err(StewardError::NotRaceSession)?
+        }
+
+        if let Some(incident_id) = form.incident_id {
+            if self.steward_repo.incident_race(incident_id).await? != Some(race_id) {
+                Err(StewardError::IncidentNotFound)?
+            }
+        }
+
+        self._apply_result_penalty(race_id, session_type, user_id, form)
+            .await
+    }
+}
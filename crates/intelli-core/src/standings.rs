@@ -0,0 +1,260 @@
+use std::cmp::Ordering;
+
+use entities::ResultEntry;
+
+// Constants
+const RACE_POINTS: [i16; 10] = [25, 18, 15, 12, 10, 8, 6, 4, 2, 1];
+const SPRINT_POINTS: [i16; 8] = [8, 7, 6, 5, 4, 3, 2, 1];
+pub(crate) const FINISHED_STATUS: i16 = 3;
+
+/// Session types that award championship points (R, R2, R3)
+pub const RACE_SESSION_TYPES: [i16; 3] = [15, 16, 17];
+
+/// Points tables of the game, used to tell the fastest lap point apart from the points
+/// of the position it was scored in
+const POINTS_TABLES: [&[i16]; 2] = [&RACE_POINTS, &SPRINT_POINTS];
+
+/// Points a table awards for finishing in the given position
+#[inline]
+fn table_points(table: &[i16], position: i16) -> i16 {
+    (position as usize)
+        .checked_sub(1)
+        .and_then(|idx| table.get(idx))
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Recomputes the positions and points of a session result after stewards penalties.
+///
+/// Finishers of a race are ordered by laps completed and total time, including the time
+/// penalties. Other sessions keep their original order. Non-finishers follow in their
+/// original order and disqualified drivers are moved to the back without points.
+///
+/// Points are not recomputed from a fixed table, each position keeps the points the game
+/// awarded for it so sprint scoring carries over. The fastest lap point stays with its
+/// driver while they finish in the points.
+pub fn reclassify(entries: &mut [ResultEntry], session_type: i16) {
+    let is_race = RACE_SESSION_TYPES.contains(&session_type);
+    let (position_points, fastest_lap) = awarded_points(entries);
+
+    entries.sort_by(|a, b| {
+        let (group_a, group_b) = (classification_group(a), classification_group(b));
+
+        group_a
+            .cmp(&group_b)
+            .then_with(|| {
+                if is_race && group_a == 0 {
+                    b.laps
+                        .cmp(&a.laps)
+                        .then_with(|| total_time(a).total_cmp(&total_time(b)))
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .then_with(|| a.original_position.cmp(&b.original_position))
+    });
+
+    for (idx, entry) in entries.iter_mut().enumerate() {
+        entry.position = idx as i16 + 1;
+        entry.points = if is_race && classification_group(entry) == 0 {
+            let points = position_points.get(idx).copied().unwrap_or(0);
+
+            match fastest_lap {
+                Some((original_position, bonus))
+                    if points > 0 && original_position == entry.original_position =>
+                {
+                    points + bonus
+                }
+                _ => points,
+            }
+        } else {
+            0
+        };
+    }
+}
+
+/// Splits the points the game awarded into the points of each position and the fastest
+/// lap point, keyed by the original position of the driver that scored it.
+///
+/// The split needs the points table of the session, if the finishers don't match a table
+/// of the game the stored points are kept per position as they are.
+fn awarded_points(entries: &[ResultEntry]) -> (Vec<i16>, Option<(i16, i16)>) {
+    let fastest_lap = entries
+        .iter()
+        .filter(|entry| entry.best_lap_time > 0 && entry.result_status == FINISHED_STATUS)
+        .min_by_key(|entry| entry.best_lap_time);
+
+    let table = POINTS_TABLES.iter().find(|table| {
+        entries
+            .iter()
+            .filter(|entry| entry.result_status == FINISHED_STATUS)
+            .filter(|entry| {
+                fastest_lap
+                    .is_none_or(|fastest| fastest.original_position != entry.original_position)
+            })
+            .all(|entry| entry.original_points == table_points(table, entry.original_position))
+    });
+
+    let Some(table) = table else {
+        let mut position_points = vec![0; entries.len()];
+
+        for entry in entries {
+            if let Some(points) = (entry.original_position as usize)
+                .checked_sub(1)
+                .and_then(|idx| position_points.get_mut(idx))
+            {
+                *points = entry.original_points;
+            }
+        }
+
+        return (position_points, None);
+    };
+
+    let position_points = (1..=entries.len() as i16)
+        .map(|position| table_points(table, position))
+        .collect();
+
+    let fastest_lap = fastest_lap.map(|entry| {
+        let bonus = entry.original_points - table_points(table, entry.original_position);
+        (entry.original_position, bonus.max(0))
+    });
+
+    (position_points, fastest_lap)
+}
+
+/// Total time of a driver including in-game and stewards penalties
+#[inline]
+fn total_time(entry: &ResultEntry) -> f64 {
+    entry.race_time + entry.penalties_time as f64 + entry.steward_time as f64
+}
+
+/// Finishers first, then non-finishers and finally disqualified drivers
+#[inline]
+fn classification_group(entry: &ResultEntry) -> u8 {
+    if entry.disqualified {
+        2
+    } else if entry.result_status == FINISHED_STATUS {
+        0
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(steam_name: &str, position: i16, laps: i16, race_time: f64) -> ResultEntry {
+        ResultEntry {
+            steam_name: steam_name.into(),
+            team_id: 0,
+            grid_position: position,
+            laps,
+            race_time,
+            penalties_time: 0,
+            best_lap_time: 0,
+            result_status: FINISHED_STATUS,
+            original_position: position,
+            original_points: table_points(&RACE_POINTS, position),
+            position,
+            points: table_points(&RACE_POINTS, position),
+            steward_time: 0,
+            disqualified: false,
+        }
+    }
+
+    fn names(entries: &[ResultEntry]) -> Vec<&str> {
+        entries
+            .iter()
+            .map(|entry| entry.steam_name.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn time_penalty_drops_positions() {
+        let mut entries = vec![
+            entry("a", 1, 50, 5000.0),
+            entry("b", 2, 50, 5003.0),
+            entry("c", 3, 50, 5008.0),
+            entry("d", 4, 49, 5001.0),
+        ];
+
+        entries[0].steward_time = 5;
+        reclassify(&mut entries, 15);
+
+        assert_eq!(names(&entries), ["b", "a", "c", "d"]);
+        assert_eq!(entries[0].points, 25);
+        assert_eq!(entries[1].points, 18);
+        assert_eq!(entries[3].position, 4);
+    }
+
+    #[test]
+    fn disqualification_moves_driver_to_the_back() {
+        let mut entries = vec![
+            entry("a", 1, 50, 5000.0),
+            entry("b", 2, 50, 5003.0),
+            entry("c", 3, 32, 3100.0),
+        ];
+
+        entries[2].result_status = 4;
+        entries[0].disqualified = true;
+        reclassify(&mut entries, 15);
+
+        assert_eq!(names(&entries), ["b", "c", "a"]);
+        assert_eq!(entries[0].points, 25);
+        assert_eq!(entries[1].points, 0);
+        assert_eq!(entries[2].points, 0);
+        assert_eq!(entries[2].position, 3);
+    }
+
+    #[test]
+    fn keeps_sprint_and_fastest_lap_points() {
+        let mut sprint = vec![
+            entry("a", 1, 20, 2000.0),
+            entry("b", 2, 20, 2001.0),
+            entry("c", 3, 20, 2009.0),
+        ];
+
+        for entry in &mut sprint {
+            entry.original_points = table_points(&SPRINT_POINTS, entry.original_position);
+        }
+
+        sprint[0].steward_time = 5;
+        reclassify(&mut sprint, 15);
+
+        assert_eq!(names(&sprint), ["b", "a", "c"]);
+        assert_eq!(sprint[0].points, 8);
+        assert_eq!(sprint[1].points, 7);
+
+        let mut race = vec![
+            entry("a", 1, 50, 5000.0),
+            entry("b", 2, 50, 5001.0),
+            entry("c", 3, 50, 5009.0),
+        ];
+
+        // Fastest lap of the race winner
+        race[0].best_lap_time = 80000;
+        race[0].original_points = 26;
+        race[1].best_lap_time = 80500;
+        race[2].best_lap_time = 81000;
+
+        race[0].steward_time = 5;
+        reclassify(&mut race, 15);
+
+        assert_eq!(names(&race), ["b", "a", "c"]);
+        assert_eq!(race[0].points, 25);
+        assert_eq!(race[1].points, 19);
+        assert_eq!(race[2].points, 15);
+    }
+
+    #[test]
+    fn qualifying_keeps_original_order() {
+        let mut entries = vec![entry("a", 1, 3, 0.0), entry("b", 2, 3, 0.0)];
+
+        entries[0].steward_time = 10;
+        reclassify(&mut entries, 5);
+
+        assert_eq!(names(&entries), ["a", "b"]);
+        assert_eq!(entries[0].points, 0);
+    }
+}
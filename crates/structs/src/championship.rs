@@ -2,7 +2,9 @@ use garde::Validate;
 use serde::{Deserialize, Serialize};
 use serde_trim::{option_string_trim, string_trim};
 
-use entities::{Category, ChampionshipRole, SharedChampionship, SharedRace};
+use entities::{
+    Category, ChampionshipRole, DamageComponent, SessionRule, SharedChampionship, SharedRace,
+};
 
 // Championship Management
 #[derive(Debug, Deserialize, Validate)]
@@ -35,21 +37,175 @@ pub struct ChampionshipUpdateData {
     pub category: Option<Category>,
 }
 
+#[derive(Debug, Deserialize, Validate)]
+pub struct TelemetryGrantsForm {
+    /// Teams the user can stream telemetry from, besides the team of an engineer
+    #[garde(length(max = 22), inner(range(min = 0, max = 255)))]
+    pub team_ids: Vec<i16>,
+}
+
+// League Teams
+#[derive(Debug, Deserialize, Validate)]
+pub struct LeagueTeamForm {
+    /// Game team whose cars the drivers of the league team race with
+    #[garde(range(min = 0, max = 255))]
+    pub game_team_id: i16,
+    #[garde(length(min = 2, max = 30))]
+    #[serde(deserialize_with = "string_trim")]
+    pub name: String,
+    /// Livery color as a hex code, e.g. `#27F4D2`
+    #[garde(inner(ascii, length(min = 7, max = 7)))]
+    #[serde(default, deserialize_with = "option_string_trim")]
+    pub color: Option<String>,
+    #[garde(inner(ascii, length(min = 10, max = 100)))]
+    #[serde(default, deserialize_with = "option_string_trim")]
+    pub logo: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct TeamTransferForm {
+    #[garde(length(min = 1, max = 100))]
+    #[serde(deserialize_with = "string_trim")]
+    pub steam_name: String,
+}
+
+// Seasons & Divisions
+#[derive(Debug, Deserialize, Validate)]
+pub struct SeasonRolloverForm {
+    /// Keeps the roster of the ending season for the new one, otherwise drivers are
+    /// registered again as they race
+    #[garde(skip)]
+    pub keep_drivers: bool,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct DivisionForm {
+    #[garde(length(min = 2, max = 30))]
+    #[serde(deserialize_with = "string_trim")]
+    pub name: String,
+    #[garde(range(min = 1, max = 10))]
+    pub tier: i16,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct StandingsQuery {
+    /// Season of the standings, the current one if not set
+    #[garde(range(min = 1, max = 100))]
+    pub season: Option<i16>,
+    #[garde(range(min = 1, max = 10))]
+    pub division_id: Option<i16>,
+}
+
+// Rule Profile
+#[derive(Debug, Deserialize, Validate)]
+pub struct ChampionshipRuleData {
+    #[garde(skip)]
+    pub rule: SessionRule,
+    #[garde(range(min = 0, max = 7))]
+    pub value: i16,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ChampionshipRulesForm {
+    #[garde(length(max = 32), dive)]
+    pub rules: Vec<ChampionshipRuleData>,
+}
+
+// Damage Alerts
+#[derive(Debug, Deserialize, Validate)]
+pub struct DamageThresholdData {
+    #[garde(skip)]
+    pub component: DamageComponent,
+    /// Percentage that triggers the alert, the increase between two samples for tyre damage
+    /// spikes. Ignored for faults
+    #[garde(range(min = 1, max = 100))]
+    pub value: i16,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct DamageThresholdsForm {
+    #[garde(length(max = 18), dive)]
+    pub thresholds: Vec<DamageThresholdData>,
+}
+
+// Engine Usage
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub enum EngineAllocationWarning {
+    /// The last allowed component is worn out and will have to be replaced
+    AtRisk,
+    Exceeded,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct EngineComponentUsage {
+    pub component: DamageComponent,
+    pub allocation: i16,
+    /// Components used so far in the season, including the one fitted
+    pub used: i16,
+    /// Wear of the component fitted at the end of the last session
+    pub wear: i16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<EngineAllocationWarning>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct DriverEngineUsage {
+    pub steam_name: Box<str>,
+    pub components: Vec<EngineComponentUsage>,
+}
+
 // Service Status
 #[derive(Default, Debug, Serialize)]
 pub struct ServiceStatus {
     pub active: bool,
     pub general_conn: u32,
     pub engineer_conn: u32,
+    pub driver_conn: u32,
+    pub steward_conn: u32,
+    pub positions_conn: u32,
+    pub director_conn: u32,
 }
 
 // Path Parameters
 #[derive(Deserialize, Validate)]
 pub struct ChampionshipId(#[garde(range(min = 700000000, max = 799999999))] pub i32);
 
+#[derive(Deserialize, Validate)]
+pub struct ChampionshipTeamId {
+    #[garde(range(min = 700000000, max = 799999999))]
+    pub id: i32,
+    #[garde(range(min = 1, max = 100))]
+    pub team_id: i16,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct ChampionshipSeasonId {
+    #[garde(range(min = 700000000, max = 799999999))]
+    pub id: i32,
+    #[garde(range(min = 1, max = 100))]
+    pub season: i16,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct ChampionshipDivisionId {
+    #[garde(range(min = 700000000, max = 799999999))]
+    pub id: i32,
+    #[garde(range(min = 1, max = 10))]
+    pub division_id: i16,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct ChampionshipTrack {
+    #[garde(range(min = 700000000, max = 799999999))]
+    pub id: i32,
+    #[garde(range(min = 0, max = 32))]
+    pub track_id: i16,
+}
+
 #[derive(Deserialize, Validate)]
 pub struct ChampionshipAndUserId {
     #[garde(range(min = 700000000, max = 799999999))]
+    #[serde(alias = "id")]
     pub championship_id: i32,
     #[garde(range(min = 600000000, max = 699999999))]
     pub user_id: i32,
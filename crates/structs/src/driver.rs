@@ -0,0 +1,68 @@
+use std::num::NonZeroI32;
+
+use entities::TrackBestLap;
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+use serde_trim::string_trim;
+
+// Driver Profile
+#[derive(Debug, Serialize)]
+pub struct DriverProfile {
+    pub steam_name: Box<str>,
+    pub nationality: i16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<NonZeroI32>,
+    pub stats: DriverCareerStats,
+    pub teammates: Vec<TeammateHeadToHead>,
+    pub best_laps: Vec<TrackBestLap>,
+}
+
+#[derive(Debug, Default, Serialize, PartialEq)]
+pub struct DriverCareerStats {
+    pub starts: u32,
+    pub wins: u32,
+    pub podiums: u32,
+    pub poles: u32,
+    pub fastest_laps: u32,
+    pub dnfs: u32,
+    /// Average position of the races finished, none if the driver never finished one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_finish: Option<f32>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct TeammateHeadToHead {
+    pub steam_name: Box<str>,
+    pub races: u32,
+    pub ahead: u32,
+    pub behind: u32,
+}
+
+// Driver Claims
+#[derive(Debug, Deserialize, Validate)]
+pub struct DriverClaimForm {
+    /// Championship whose admins review the claim and whose sessions verify it
+    #[garde(range(min = 700000000, max = 799999999))]
+    pub championship_id: i32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DriverClaimCode {
+    /// Race number to use in a session of the championship to verify the claim
+    pub verification_code: i16,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct DriverClaimReviewForm {
+    #[garde(length(min = 1, max = 100))]
+    #[serde(deserialize_with = "string_trim")]
+    pub steam_name: String,
+    #[garde(range(min = 600000000, max = 699999999))]
+    pub user_id: i32,
+    #[garde(skip)]
+    pub approved: bool,
+}
+
+// Path Parameters
+#[derive(Deserialize, Validate)]
+pub struct SteamName(#[garde(length(min = 1, max = 100))] pub String);
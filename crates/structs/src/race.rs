@@ -0,0 +1,188 @@
+use chrono::{DateTime, Utc};
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+use serde_trim::{option_string_trim, string_trim};
+
+use entities::{ResultEntry, ResultPenalty, ResultPenaltyKind, RuleDeviation};
+
+// Weather History
+#[derive(Debug)]
+pub struct WeatherSampleData {
+    pub session_type: i16,
+    pub weather: i16,
+    pub track_temperature: i16,
+    pub air_temperature: i16,
+    pub rain_percentage: Option<i16>,
+}
+
+// Track Outlines
+#[derive(Debug)]
+pub struct TrackOutlineData {
+    pub track_id: i16,
+    pub track_length: i16,
+    pub origin_x: f32,
+    pub origin_z: f32,
+    pub scale: f32,
+    pub points: Vec<f32>,
+    pub pit_lane: Vec<f32>,
+    pub sector2_index: i16,
+    pub sector3_index: i16,
+}
+
+// Lap Traces
+#[derive(Debug)]
+pub struct LapTraceData {
+    pub session_type: i16,
+    pub steam_name: Box<str>,
+    pub lap_number: i16,
+    pub lap_time: i32,
+    pub valid: bool,
+    pub step: i16,
+    pub time: Vec<i32>,
+    pub speed: Vec<i16>,
+    pub throttle: Vec<i16>,
+    pub brake: Vec<i16>,
+    pub gear: Vec<i16>,
+    pub steer: Vec<i16>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct LapComparisonQuery {
+    #[garde(length(min = 1, max = 100))]
+    #[serde(deserialize_with = "string_trim")]
+    pub steam_name: String,
+    #[garde(range(min = 1, max = 200))]
+    pub lap: i16,
+    /// Defaults to the driver of the lap when a reference lap is given
+    #[garde(length(min = 1, max = 100))]
+    #[serde(default, deserialize_with = "option_string_trim")]
+    pub reference_steam_name: Option<String>,
+    /// Defaults to the fastest valid lap of the reference driver, or of the session
+    #[garde(range(min = 1, max = 200))]
+    pub reference_lap: Option<i16>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct LapChannels {
+    pub steam_name: Box<str>,
+    pub lap_number: i16,
+    pub lap_time: i32,
+    pub valid: bool,
+    pub speed: Vec<i16>,
+    pub throttle: Vec<i16>,
+    pub brake: Vec<i16>,
+    pub gear: Vec<i16>,
+    pub steer: Vec<i16>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LapComparison {
+    /// Metres of lap distance between two samples
+    pub step: i16,
+    pub lap: LapChannels,
+    pub reference: LapChannels,
+    /// Time lost (positive) or gained against the reference lap at every sample, in milliseconds
+    pub delta: Vec<i32>,
+}
+
+// Engine Wear
+#[derive(Debug)]
+pub struct EngineWearData {
+    pub session_type: i16,
+    pub steam_name: Box<str>,
+    pub ice: i16,
+    pub mguh: i16,
+    pub mguk: i16,
+    pub es: i16,
+    pub ce: i16,
+    pub tc: i16,
+}
+
+// Results
+#[derive(Debug)]
+pub struct ResultEntryData {
+    pub steam_name: Box<str>,
+    pub team_id: i16,
+    pub grid_position: i16,
+    pub laps: i16,
+    pub race_time: f64,
+    pub penalties_time: i16,
+    pub best_lap_time: i32,
+    pub result_status: i16,
+    pub position: i16,
+    pub points: i16,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ResultPenaltyForm {
+    #[garde(length(min = 1, max = 100))]
+    #[serde(deserialize_with = "string_trim")]
+    pub steam_name: String,
+    #[garde(skip)]
+    pub kind: ResultPenaltyKind,
+    #[garde(range(min = 1, max = 600))]
+    pub seconds: Option<i16>,
+    #[garde(length(max = 500))]
+    #[serde(default, deserialize_with = "option_string_trim")]
+    pub reason: Option<String>,
+    #[garde(range(min = 900000000, max = 999999999))]
+    pub incident_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct RaceScheduleForm {
+    #[garde(range(min = 0, max = 32))]
+    pub track_id: i16,
+    /// Sessions starting within a day of it are linked to the race
+    #[garde(skip)]
+    pub date: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SubstitutionForm {
+    /// Reserve driving the car
+    #[garde(length(min = 1, max = 100))]
+    #[serde(deserialize_with = "string_trim")]
+    pub steam_name: String,
+    /// Regular driver of the car
+    #[garde(length(min = 1, max = 100))]
+    #[serde(deserialize_with = "string_trim")]
+    pub replaces: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct RaceDivisionForm {
+    /// Division the race belongs to, none for races shared by the whole championship
+    #[garde(range(min = 1, max = 10))]
+    pub division_id: Option<i16>,
+}
+
+#[derive(Serialize)]
+pub struct RaceResultData {
+    pub entries: Vec<ResultEntry>,
+    pub penalties: Vec<ResultPenalty>,
+    pub deviations: Vec<RuleDeviation>,
+}
+
+// Path Parameters
+#[derive(Deserialize, Validate)]
+pub struct RaceId(#[garde(range(min = 800000000, max = 899999999))] pub i32);
+
+#[derive(Deserialize, Validate)]
+pub struct TrackId(#[garde(range(min = 0, max = 32))] pub i16);
+
+#[derive(Deserialize, Validate)]
+pub struct RaceDriver {
+    #[garde(range(min = 800000000, max = 899999999))]
+    pub id: i32,
+    #[garde(length(min = 1, max = 100))]
+    pub steam_name: String,
+}
+
+#[derive(Deserialize, Validate)]
+pub struct RaceSession {
+    #[garde(range(min = 800000000, max = 899999999))]
+    pub id: i32,
+    #[garde(range(min = 0, max = 18))]
+    pub session_type: i16,
+}
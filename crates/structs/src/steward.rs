@@ -0,0 +1,119 @@
+use garde::Validate;
+use serde::{Deserialize, Serialize};
+use serde_trim::option_string_trim;
+
+use entities::{AssistEvidence, CarSetup, DriverAssist, IncidentKind, RulingDecision, SessionRule};
+
+// Yellow Flags
+#[derive(Debug)]
+pub struct YellowFlagData {
+    pub session_type: i16,
+    pub zone_index: i16,
+    pub zone_start: f32,
+    pub started_session_time: f32,
+    pub ended_session_time: f32,
+}
+
+// Rule Deviations
+#[derive(Debug, PartialEq)]
+pub struct RuleDeviationData {
+    pub session_type: i16,
+    pub rule: SessionRule,
+    pub expected: i16,
+    pub actual: i16,
+}
+
+// Assist Evidence
+#[derive(Debug)]
+pub struct AssistEvidenceData {
+    pub session_type: i16,
+    pub steam_name: Box<str>,
+    pub assist: DriverAssist,
+    pub inferred: bool,
+    pub samples: i32,
+    pub total_samples: i32,
+    pub level: i16,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AssistEvidenceReport {
+    /// Assists the game reported as enabled
+    pub reported: Vec<AssistEvidence>,
+    /// Assists deduced from the driving patterns, drivers without the assist can match them
+    /// so they are only hints for the stewards to review
+    pub hints: Vec<AssistEvidence>,
+}
+
+// Setup Snapshots
+#[derive(Debug)]
+pub struct SetupSnapshotData {
+    pub session_type: i16,
+    pub steam_name: Box<str>,
+    pub setup: CarSetup,
+}
+
+// Parc Fermé
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ParcFermeChange {
+    pub setting: &'static str,
+    pub qualifying: f32,
+    pub race: f32,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ParcFermeBreach {
+    pub steam_name: Box<str>,
+    pub qualifying_session: i16,
+    pub race_session: i16,
+    pub changes: Vec<ParcFermeChange>,
+}
+
+// Incidents
+#[derive(Debug)]
+pub struct IncidentData {
+    pub session_type: i16,
+    pub kind: IncidentKind,
+    pub session_time: f32,
+    pub lap: Option<i16>,
+    pub steam_name: Option<Box<str>>,
+    pub other_steam_name: Option<Box<str>>,
+    pub penalty_type: Option<i16>,
+    pub infringement_type: Option<i16>,
+    pub penalty_time: Option<i16>,
+    pub safety_car_type: Option<i16>,
+    pub safety_car_event: Option<i16>,
+}
+
+impl IncidentData {
+    /// Creates an incident without any driver or penalty details
+    pub fn new(session_type: i16, kind: IncidentKind, session_time: f32) -> Self {
+        Self {
+            session_type,
+            kind,
+            session_time,
+            lap: None,
+            steam_name: None,
+            other_steam_name: None,
+            penalty_type: None,
+            infringement_type: None,
+            penalty_time: None,
+            safety_car_type: None,
+            safety_car_event: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct IncidentRulingForm {
+    #[garde(skip)]
+    pub decision: RulingDecision,
+    #[garde(range(min = 1, max = 600))]
+    pub penalty_time: Option<i16>,
+    #[garde(length(max = 500))]
+    #[serde(default, deserialize_with = "option_string_trim")]
+    pub notes: Option<String>,
+}
+
+// Path Parameters
+#[derive(Deserialize, Validate)]
+pub struct IncidentId(#[garde(range(min = 900000000, max = 999999999))] pub i32);
@@ -1,11 +1,19 @@
 mod auth;
 mod championship;
+mod driver;
+mod race;
 mod server;
+mod steward;
 mod templates;
+mod time_trial;
 mod user;
 
 pub use auth::*;
 pub use championship::*;
+pub use driver::*;
+pub use race::*;
 pub use server::*;
+pub use steward::*;
 pub use templates::*;
+pub use time_trial::*;
 pub use user::*;
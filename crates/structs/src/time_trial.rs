@@ -0,0 +1,20 @@
+use entities::TimeTrialLapKind;
+
+// Time Trial Laps
+#[derive(Debug)]
+pub struct TimeTrialLapData {
+    pub steam_name: Box<str>,
+    pub track_id: i16,
+    pub kind: TimeTrialLapKind,
+    pub team_id: i16,
+    pub lap_time: i32,
+    pub sector1_time: i32,
+    pub sector2_time: i32,
+    pub sector3_time: i32,
+    pub traction_control: i16,
+    pub gearbox_assist: i16,
+    pub anti_lock_brakes: bool,
+    pub equal_car_performance: bool,
+    pub custom_setup: bool,
+    pub valid: bool,
+}